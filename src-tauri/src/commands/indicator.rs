@@ -1,28 +1,75 @@
-use crate::db::models::{CreateIndicatorRequest, Indicator, UpdateIndicatorRequest};
+use crate::db::models::{
+    CreateIndicatorRequest, Indicator, IndicatorSignal, IndicatorSignalQuery, UpdateIndicatorRequest,
+};
 use crate::db::Database;
-use crate::services::{kline, tdx};
+use crate::services::align::{self, FillPolicy};
+use crate::services::kline::{self, AdjustMode};
+use crate::services::tdx::evaluator::UserIndicator;
+use crate::services::{batch, symbol_source, tdx};
+use std::collections::HashMap;
 use std::sync::Arc;
+use std::time::Duration;
 use tauri::State;
 
+/// 手动计算指标时同时处理的股票数
+const EVALUATE_CONCURRENCY: usize = 8;
+/// 单只股票获取 K 线并计算的超时
+const EVALUATE_TIMEOUT: Duration = Duration::from_secs(20);
+
+/// 公式引用了用户指标时从数据库加载全部用户指标，否则返回空表
+fn user_indicators_for(db: &Database, source: &str) -> Result<HashMap<String, UserIndicator>, String> {
+    if tdx::referenced_user_indicators(source).is_empty() {
+        Ok(HashMap::new())
+    } else {
+        db.indicator_formulas()
+    }
+}
+
+/// 验证公式；传入 symbols 时额外检查各股票的历史 K 线是否满足公式回溯需求
 #[tauri::command]
-pub async fn cmd_validate_tdx_formula(source: String) -> Result<serde_json::Value, String> {
-    let result = tdx::validate_formula(&source);
+pub async fn cmd_validate_tdx_formula(
+    db: State<'_, Arc<Database>>,
+    source: String,
+    symbols: Option<Vec<String>>,
+    adjust_mode: Option<String>,
+) -> Result<serde_json::Value, String> {
+    let user_indicators = user_indicators_for(&db, &source)?;
+    let mut result = tdx::validate_formula(&source, &user_indicators);
+    if let (Some(symbols), Some(required)) = (symbols, result.required_bars) {
+        let adjust_mode = match adjust_mode {
+            Some(mode) => AdjustMode::parse(&mode)?,
+            None => AdjustMode::default(),
+        };
+        let warnings = tdx::history_warnings(&symbols, required, adjust_mode).await;
+        result.warnings.extend(warnings);
+    }
     serde_json::to_value(&result).map_err(|e| e.to_string())
 }
 
+/// 公式编辑辅助：返回光标处（字符偏移）的补全候选、悬停说明和参数提示
+#[tauri::command]
+pub async fn cmd_formula_assist(source: String, offset: usize) -> Result<serde_json::Value, String> {
+    Ok(serde_json::json!({
+        "completions": tdx::editor::complete(&source, offset),
+        "hover": tdx::editor::hover(&source, offset),
+        "signature": tdx::editor::signature_help(&source, offset),
+    }))
+}
+
 #[tauri::command]
 pub async fn cmd_create_indicator(
     db: State<'_, Arc<Database>>,
     request: CreateIndicatorRequest,
 ) -> Result<Indicator, String> {
     // 先验证公式
-    let validation = tdx::validate_formula(&request.formula_source);
+    let user_indicators = user_indicators_for(&db, &request.formula_source)?;
+    let validation = tdx::validate_formula(&request.formula_source, &user_indicators);
     if !validation.valid {
         return Err(format!("公式验证失败: {}", validation.errors.join("; ")));
     }
 
-    if request.stock_symbols.is_empty() {
-        return Err("至少需要一个股票代码".to_string());
+    if request.stock_symbols.is_empty() && request.symbol_source.is_none() {
+        return Err("至少需要一个股票代码或股票来源".to_string());
     }
 
     let id = uuid::Uuid::new_v4().to_string();
@@ -30,11 +77,30 @@ pub async fn cmd_create_indicator(
     let symbols_json = serde_json::to_string(&request.stock_symbols).unwrap_or_default();
     let check_interval = request.check_interval_secs.unwrap_or(60);
     let market_hours = request.market_hours_only.unwrap_or(true);
+    let adjust_mode = match &request.adjust_mode {
+        Some(s) => AdjustMode::parse(s)?,
+        None => AdjustMode::default(),
+    };
+    let chip_model_json = request
+        .chip_model
+        .as_ref()
+        .map(|m| serde_json::to_string(m).unwrap_or_default());
+    let fill_policy = match &request.fill_policy {
+        Some(s) => FillPolicy::parse(s)?,
+        None => FillPolicy::default(),
+    };
+    let symbol_source = match request.symbol_source {
+        Some(source) => Some(symbol_source::prepare(source).await?),
+        None => None,
+    };
+    let symbol_source_json = symbol_source
+        .as_ref()
+        .map(|s| serde_json::to_string(s).unwrap_or_default());
 
     let conn = db.conn.lock().map_err(|e| e.to_string())?;
     conn.execute(
-        "INSERT INTO indicator (id, name, formula_source, stock_symbols, task_id, is_active, check_interval_secs, market_hours_only, created_at, updated_at) VALUES (?1, ?2, ?3, ?4, ?5, 1, ?6, ?7, ?8, ?9)",
-        rusqlite::params![id, request.name, request.formula_source, symbols_json, request.task_id, check_interval, market_hours as i64, now, now],
+        "INSERT INTO indicator (id, name, formula_source, stock_symbols, task_id, is_active, check_interval_secs, market_hours_only, adjust_mode, chip_model, fill_policy, symbol_source, created_at, updated_at) VALUES (?1, ?2, ?3, ?4, ?5, 1, ?6, ?7, ?8, ?9, ?10, ?11, ?12, ?13)",
+        rusqlite::params![id, request.name, request.formula_source, symbols_json, request.task_id, check_interval, market_hours as i64, adjust_mode.as_str(), chip_model_json, fill_policy.as_str(), symbol_source_json, now, now],
     ).map_err(|e| format!("创建指标失败: {}", e))?;
 
     Ok(Indicator {
@@ -46,6 +112,10 @@ pub async fn cmd_create_indicator(
         is_active: true,
         check_interval_secs: check_interval,
         market_hours_only: market_hours,
+        adjust_mode: adjust_mode.as_str().to_string(),
+        chip_model: request.chip_model,
+        fill_policy: fill_policy.as_str().to_string(),
+        symbol_source,
         last_checked: None,
         last_signal: None,
         created_at: now.clone(),
@@ -59,7 +129,7 @@ pub async fn cmd_list_indicators(
 ) -> Result<Vec<Indicator>, String> {
     let conn = db.conn.lock().map_err(|e| e.to_string())?;
     let mut stmt = conn
-        .prepare("SELECT id, name, formula_source, stock_symbols, task_id, is_active, check_interval_secs, market_hours_only, last_checked, last_signal, created_at, updated_at FROM indicator ORDER BY created_at DESC")
+        .prepare("SELECT id, name, formula_source, stock_symbols, task_id, is_active, check_interval_secs, market_hours_only, adjust_mode, chip_model, fill_policy, symbol_source, last_checked, last_signal, created_at, updated_at FROM indicator ORDER BY created_at DESC")
         .map_err(|e| e.to_string())?;
 
     let results = stmt
@@ -67,6 +137,8 @@ pub async fn cmd_list_indicators(
             let symbols_json: String = row.get(3)?;
             let symbols: Vec<String> =
                 serde_json::from_str(&symbols_json).unwrap_or_default();
+            let chip_model: Option<String> = row.get(9)?;
+            let symbol_source: Option<String> = row.get(11)?;
             Ok(Indicator {
                 id: row.get(0)?,
                 name: row.get(1)?,
@@ -76,10 +148,14 @@ pub async fn cmd_list_indicators(
                 is_active: row.get::<_, i64>(5)? != 0,
                 check_interval_secs: row.get(6)?,
                 market_hours_only: row.get::<_, i64>(7)? != 0,
-                last_checked: row.get(8)?,
-                last_signal: row.get(9)?,
-                created_at: row.get(10)?,
-                updated_at: row.get(11)?,
+                adjust_mode: row.get(8)?,
+                chip_model: chip_model.and_then(|s| serde_json::from_str(&s).ok()),
+                fill_policy: row.get(10)?,
+                symbol_source: symbol_source.and_then(|s| serde_json::from_str(&s).ok()),
+                last_checked: row.get(12)?,
+                last_signal: row.get(13)?,
+                created_at: row.get(14)?,
+                updated_at: row.get(15)?,
             })
         })
         .map_err(|e| e.to_string())?
@@ -97,11 +173,26 @@ pub async fn cmd_update_indicator(
 ) -> Result<serde_json::Value, String> {
     // 如果更新公式，先验证
     if let Some(ref source) = request.formula_source {
-        let validation = tdx::validate_formula(source);
+        let user_indicators = user_indicators_for(&db, source)?;
+        let validation = tdx::validate_formula(source, &user_indicators);
         if !validation.valid {
             return Err(format!("公式验证失败: {}", validation.errors.join("; ")));
         }
     }
+    let adjust_mode = request
+        .adjust_mode
+        .as_deref()
+        .map(AdjustMode::parse)
+        .transpose()?;
+    let fill_policy = request
+        .fill_policy
+        .as_deref()
+        .map(FillPolicy::parse)
+        .transpose()?;
+    let symbol_source = match request.symbol_source.clone() {
+        Some(Some(source)) => Some(Some(symbol_source::prepare(source).await?)),
+        other => other,
+    };
 
     let conn = db.conn.lock().map_err(|e| e.to_string())?;
     let now = chrono::Utc::now().to_rfc3339();
@@ -141,6 +232,28 @@ pub async fn cmd_update_indicator(
         params.push(Box::new(mho as i64));
         param_idx += 1;
     }
+    if let Some(mode) = adjust_mode {
+        sets.push(format!("adjust_mode = ?{}", param_idx));
+        params.push(Box::new(mode.as_str()));
+        param_idx += 1;
+    }
+    if let Some(model) = &request.chip_model {
+        let json = model.as_ref().map(|m| serde_json::to_string(m).unwrap_or_default());
+        sets.push(format!("chip_model = ?{}", param_idx));
+        params.push(Box::new(json));
+        param_idx += 1;
+    }
+    if let Some(policy) = fill_policy {
+        sets.push(format!("fill_policy = ?{}", param_idx));
+        params.push(Box::new(policy.as_str()));
+        param_idx += 1;
+    }
+    if let Some(source) = &symbol_source {
+        let json = source.as_ref().map(|s| serde_json::to_string(s).unwrap_or_default());
+        sets.push(format!("symbol_source = ?{}", param_idx));
+        params.push(Box::new(json));
+        param_idx += 1;
+    }
 
     // id 参数
     sets.push(format!("id = id")); // no-op to end SET clause cleanly
@@ -173,40 +286,303 @@ pub async fn cmd_delete_indicator(
 pub async fn cmd_evaluate_indicator(
     db: State<'_, Arc<Database>>,
     id: String,
+    trace_bar: Option<i64>,
 ) -> Result<serde_json::Value, String> {
-    let (formula_source, symbols_json) = {
+    let (formula_source, symbols_json, adjust_mode, chip_model, fill_policy, symbol_source) = {
         let conn = db.conn.lock().map_err(|e| e.to_string())?;
         conn.query_row(
-            "SELECT formula_source, stock_symbols FROM indicator WHERE id = ?1",
+            "SELECT formula_source, stock_symbols, adjust_mode, chip_model, fill_policy, symbol_source FROM indicator WHERE id = ?1",
             rusqlite::params![id],
-            |row| Ok((row.get::<_, String>(0)?, row.get::<_, String>(1)?)),
+            |row| {
+                Ok((
+                    row.get::<_, String>(0)?,
+                    row.get::<_, String>(1)?,
+                    row.get::<_, String>(2)?,
+                    row.get::<_, Option<String>>(3)?,
+                    row.get::<_, String>(4)?,
+                    row.get::<_, Option<String>>(5)?,
+                ))
+            },
         )
         .map_err(|e| format!("指标不存在: {}", e))?
     };
 
-    let symbols: Vec<String> = serde_json::from_str(&symbols_json).unwrap_or_default();
+    let fixed: Vec<String> = serde_json::from_str(&symbols_json).unwrap_or_default();
+    let symbol_source = symbol_source.and_then(|s| serde_json::from_str(&s).ok());
+    let (symbols, source_error) = symbol_source::resolve_symbols(&db, &fixed, symbol_source.as_ref()).await;
+    let adjust_mode = AdjustMode::parse(&adjust_mode).unwrap_or_default();
+    let chip_model = chip_model.and_then(|s| serde_json::from_str(&s).ok());
+    let fill_policy = FillPolicy::parse(&fill_policy).unwrap_or_default();
+    let user_indicators = Arc::new(user_indicators_for(&db, &formula_source)?);
+    let bar_count = tdx::bars_to_fetch(&formula_source, &user_indicators);
+
+    // 各股票并发获取 K 线并计算，单只失败只记录在该股票的结果中
+    let evaluations = symbols
+        .iter()
+        .map(|symbol| {
+            let (symbol, formula_source, user_indicators) =
+                (symbol.clone(), formula_source.clone(), Arc::clone(&user_indicators));
+            async move {
+                let raw = kline::fetch_daily_klines(&symbol, bar_count, adjust_mode).await?;
+                let aligned = align::align_to_calendar(&raw, fill_policy);
+                let bars = &aligned.bars;
+                let mut options = tdx::prepare_options(
+                    &symbol,
+                    &formula_source,
+                    bars,
+                    adjust_mode,
+                    chip_model,
+                    (*user_indicators).clone(),
+                )
+                .await;
+                options.stale = aligned.stale.clone();
+                let value = match tdx::evaluate_formula(&formula_source, bars, &options) {
+                    Ok(eval_result) => {
+                        let mut value = serde_json::to_value(&eval_result).unwrap_or_default();
+                        if !aligned.suspended_dates.is_empty() {
+                            value["suspended_dates"] = serde_json::json!(aligned.suspended_dates);
+                        }
+                        // 解释模式：附带指定 K 线上各语句及子表达式的取值
+                        if let Some(bar_index) = trace_bar {
+                            value["trace"] = match tdx::trace_formula(&formula_source, bars, &options, bar_index) {
+                                Ok(trace) => serde_json::to_value(&trace).unwrap_or_default(),
+                                Err(e) => serde_json::json!({ "error": e.message, "limit": e.limit }),
+                            };
+                        }
+                        value
+                    }
+                    Err(e) => serde_json::json!({ "error": e.message, "limit": e.limit }),
+                };
+                Ok(value)
+            }
+        })
+        .collect();
+    let outcomes = batch::run_bounded(evaluations, EVALUATE_CONCURRENCY, EVALUATE_TIMEOUT).await;
+    let mut results = serde_json::Map::new();
+    for (symbol, result) in symbols.iter().zip(outcomes) {
+        let value = result.unwrap_or_else(|e| serde_json::json!({ "error": e }));
+        results.insert(symbol.clone(), value);
+    }
+
+    let mut response = serde_json::json!({
+        "indicator_id": id,
+        "results": results,
+    });
+    if let Some(e) = source_error {
+        response["source_error"] = serde_json::json!(e);
+    }
+    Ok(response)
+}
+
+/// 信号事件研究：统计历史上每次 DRAWTEXT 触发后 1/3/5/10/20 个交易日（按交易日历，停牌日计入持有期）的收益表现。
+/// 传入 id 时使用已保存指标的公式、股票、复权方式和停牌填充策略；也可直接传入 formula + symbols 研究未保存的公式
+#[tauri::command]
+pub async fn cmd_indicator_event_study(
+    db: State<'_, Arc<Database>>,
+    id: Option<String>,
+    formula: Option<String>,
+    symbols: Option<Vec<String>>,
+    adjust_mode: Option<String>,
+    fill_policy: Option<String>,
+    bars: Option<usize>,
+) -> Result<serde_json::Value, String> {
+    let (formula_source, saved_symbols, saved_source, saved_adjust, chip_model, saved_fill) = match &id {
+        Some(id) => {
+            let conn = db.conn.lock().map_err(|e| e.to_string())?;
+            let (formula, symbols_json, adjust, chip_model, source, fill) = conn
+                .query_row(
+                    "SELECT formula_source, stock_symbols, adjust_mode, chip_model, symbol_source, fill_policy FROM indicator WHERE id = ?1",
+                    rusqlite::params![id],
+                    |row| {
+                        Ok((
+                            row.get::<_, String>(0)?,
+                            row.get::<_, String>(1)?,
+                            row.get::<_, String>(2)?,
+                            row.get::<_, Option<String>>(3)?,
+                            row.get::<_, Option<String>>(4)?,
+                            row.get::<_, String>(5)?,
+                        ))
+                    },
+                )
+                .map_err(|e| format!("指标不存在: {}", e))?;
+            let symbols: Vec<String> = serde_json::from_str(&symbols_json).unwrap_or_default();
+            (
+                formula,
+                symbols,
+                source.and_then(|s| serde_json::from_str(&s).ok()),
+                Some(adjust),
+                chip_model.and_then(|s| serde_json::from_str(&s).ok()),
+                Some(fill),
+            )
+        }
+        None => (
+            formula.ok_or("需要指定指标 ID 或公式")?,
+            Vec::new(),
+            None,
+            None,
+            None,
+            None,
+        ),
+    };
+
+    let symbols = match symbols {
+        Some(symbols) => symbols,
+        None => symbol_source::resolve_symbols(&db, &saved_symbols, saved_source.as_ref()).await.0,
+    };
+    if symbols.is_empty() {
+        return Err("需要指定股票代码".to_string());
+    }
+    let adjust_mode = match adjust_mode.or(saved_adjust) {
+        Some(mode) => AdjustMode::parse(&mode)?,
+        None => AdjustMode::default(),
+    };
+    let fill_policy = match fill_policy.or(saved_fill) {
+        Some(policy) => FillPolicy::parse(&policy)?,
+        None => FillPolicy::default(),
+    };
+    let limit = bars.unwrap_or(1000).clamp(60, 5000);
+    let user_indicators = user_indicators_for(&db, &formula_source)?;
     let mut results = serde_json::Map::new();
 
     for symbol in &symbols {
-        let bars = kline::fetch_daily_klines(symbol, 300).await?;
-        match tdx::evaluate_formula(&formula_source, &bars) {
-            Ok(eval_result) => {
-                results.insert(
-                    symbol.clone(),
-                    serde_json::to_value(&eval_result).unwrap_or_default(),
-                );
-            }
+        let raw = match kline::fetch_daily_klines(symbol, limit, adjust_mode).await {
+            Ok(b) => b,
             Err(e) => {
-                results.insert(
-                    symbol.clone(),
-                    serde_json::json!({ "error": e }),
-                );
+                results.insert(symbol.clone(), serde_json::json!({ "error": e }));
+                continue;
             }
-        }
+        };
+        let aligned = align::align_to_calendar(&raw, fill_policy);
+        let bars = &aligned.bars;
+        let mut options =
+            tdx::prepare_options(symbol, &formula_source, bars, adjust_mode, chip_model, user_indicators.clone()).await;
+        options.stale = aligned.stale.clone();
+        let value = match tdx::study::event_study(&formula_source, bars, &options, tdx::study::HORIZONS) {
+            Ok(study) => serde_json::to_value(&study).unwrap_or_default(),
+            Err(e) => serde_json::json!({ "error": e.message, "limit": e.limit }),
+        };
+        results.insert(symbol.clone(), value);
     }
 
     Ok(serde_json::json!({
         "indicator_id": id,
+        "adjust_mode": adjust_mode,
+        "fill_policy": fill_policy,
         "results": results,
     }))
 }
+
+/// 查询指标信号历史（按 K 线日期、触发时间倒序），默认最多 100 条
+#[tauri::command]
+pub async fn cmd_list_indicator_signals(
+    db: State<'_, Arc<Database>>,
+    query: Option<IndicatorSignalQuery>,
+) -> Result<Vec<IndicatorSignal>, String> {
+    let query = query.unwrap_or_default();
+    let mut conditions: Vec<String> = Vec::new();
+    let mut params: Vec<Box<dyn rusqlite::types::ToSql>> = Vec::new();
+
+    if let Some(indicator_id) = &query.indicator_id {
+        params.push(Box::new(indicator_id.clone()));
+        conditions.push(format!("s.indicator_id = ?{}", params.len()));
+    }
+    if let Some(symbol) = &query.symbol {
+        params.push(Box::new(symbol.clone()));
+        conditions.push(format!("s.symbol = ?{}", params.len()));
+    }
+    if let Some(since) = &query.since {
+        params.push(Box::new(since.clone()));
+        conditions.push(format!("s.bar_date >= ?{}", params.len()));
+    }
+    if let Some(until) = &query.until {
+        params.push(Box::new(until.clone()));
+        conditions.push(format!("s.bar_date <= ?{}", params.len()));
+    }
+    if query.unacknowledged_only.unwrap_or(false) {
+        conditions.push("s.acknowledged_at IS NULL".to_string());
+    }
+    let where_clause = if conditions.is_empty() {
+        String::new()
+    } else {
+        format!("WHERE {}", conditions.join(" AND "))
+    };
+    params.push(Box::new(query.limit.unwrap_or(100).min(1000)));
+    let sql = format!(
+        "SELECT s.id, s.indicator_id, COALESCE(i.name, ''), s.symbol, s.signal_text, s.signal_value, s.bar_date, s.fired_at, s.delivered, s.acknowledged_at
+         FROM indicator_signal s LEFT JOIN indicator i ON i.id = s.indicator_id
+         {} ORDER BY s.bar_date DESC, s.fired_at DESC LIMIT ?{}",
+        where_clause,
+        params.len()
+    );
+
+    let conn = db.conn.lock().map_err(|e| e.to_string())?;
+    let mut stmt = conn.prepare(&sql).map_err(|e| e.to_string())?;
+    let param_refs: Vec<&dyn rusqlite::types::ToSql> = params.iter().map(|p| p.as_ref()).collect();
+    let signals = stmt
+        .query_map(param_refs.as_slice(), |row| {
+            Ok(IndicatorSignal {
+                id: row.get(0)?,
+                indicator_id: row.get(1)?,
+                indicator_name: row.get(2)?,
+                symbol: row.get(3)?,
+                signal_text: row.get(4)?,
+                signal_value: row.get(5)?,
+                bar_date: row.get(6)?,
+                fired_at: row.get(7)?,
+                delivered: row.get::<_, i64>(8)? != 0,
+                acknowledged_at: row.get(9)?,
+            })
+        })
+        .map_err(|e| e.to_string())?
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(|e| e.to_string())?;
+
+    Ok(signals)
+}
+
+/// 确认信号：传入 ids 时确认指定信号，否则确认 indicator_id（省略时为全部指标）下所有未确认的信号
+#[tauri::command]
+pub async fn cmd_acknowledge_indicator_signals(
+    db: State<'_, Arc<Database>>,
+    ids: Option<Vec<String>>,
+    indicator_id: Option<String>,
+) -> Result<serde_json::Value, String> {
+    let now = chrono::Utc::now().to_rfc3339();
+    let conn = db.conn.lock().map_err(|e| e.to_string())?;
+    let count = match ids {
+        Some(ids) => {
+            let mut count = 0;
+            for id in &ids {
+                count += conn
+                    .execute(
+                        "UPDATE indicator_signal SET acknowledged_at = ?1 WHERE id = ?2 AND acknowledged_at IS NULL",
+                        rusqlite::params![now, id],
+                    )
+                    .map_err(|e| format!("确认信号失败: {}", e))?;
+            }
+            count
+        }
+        None => conn
+            .execute(
+                "UPDATE indicator_signal SET acknowledged_at = ?1 WHERE acknowledged_at IS NULL AND (?2 IS NULL OR indicator_id = ?2)",
+                rusqlite::params![now, indicator_id],
+            )
+            .map_err(|e| format!("确认信号失败: {}", e))?,
+    };
+    Ok(serde_json::json!({ "success": true, "acknowledged": count }))
+}
+
+/// 前端展示信号后标记为已送达
+#[tauri::command]
+pub async fn cmd_mark_indicator_signal_delivered(
+    db: State<'_, Arc<Database>>,
+    id: String,
+) -> Result<(), String> {
+    let conn = db.conn.lock().map_err(|e| e.to_string())?;
+    conn.execute(
+        "UPDATE indicator_signal SET delivered = 1 WHERE id = ?1",
+        rusqlite::params![id],
+    )
+    .map_err(|e| e.to_string())?;
+    Ok(())
+}
@@ -1,4 +1,9 @@
-use crate::services::market;
+use crate::db::Database;
+use crate::services::kline::{self, AdjustMode};
+use crate::services::provider::{self, ProviderConfig};
+use crate::services::{calendar, limit_ladder, market, order_book, relative, security, timeline};
+use std::sync::Arc;
+use tauri::State;
 
 #[tauri::command]
 pub async fn cmd_fetch_stock_quote(symbol: String) -> Result<market::StockQuote, String> {
@@ -11,7 +16,7 @@ pub async fn cmd_search_stocks(keyword: String) -> Result<Vec<market::StockSearc
 }
 
 #[tauri::command]
-pub async fn cmd_fetch_batch_quotes(symbols: Vec<String>) -> Result<Vec<market::StockQuote>, String> {
+pub async fn cmd_fetch_batch_quotes(symbols: Vec<String>) -> Result<market::BatchQuotes, String> {
     market::fetch_batch_quotes(&symbols).await
 }
 
@@ -19,3 +24,148 @@ pub async fn cmd_fetch_batch_quotes(symbols: Vec<String>) -> Result<Vec<market::
 pub async fn cmd_fetch_limit_stocks(limit_type: String) -> Result<Vec<market::StockQuote>, String> {
     market::fetch_limit_stocks(&limit_type).await
 }
+
+/// 五档盘口（量的单位为手）
+#[tauri::command]
+pub async fn cmd_fetch_order_book(symbol: String) -> Result<order_book::OrderBook, String> {
+    order_book::fetch_order_book(&symbol).await
+}
+
+/// 最近的分笔成交，count 默认 50、最多 1000
+#[tauri::command]
+pub async fn cmd_fetch_trade_ticks(symbol: String, count: Option<usize>) -> Result<Vec<order_book::TradeTick>, String> {
+    order_book::fetch_ticks(&symbol, count.unwrap_or(order_book::DEFAULT_TICK_COUNT)).await
+}
+
+/// 最近 days 个交易日的分时（默认 1，最多 5），每分钟带均价
+#[tauri::command]
+pub async fn cmd_fetch_timeline(symbol: String, days: Option<usize>) -> Result<Vec<timeline::Timeline>, String> {
+    timeline::fetch_timeline(&symbol, days.unwrap_or(1)).await
+}
+
+/// 最近一个交易日的分时指标：均价、偏离均价、连续在均价线下方 / 上方的分钟数
+#[tauri::command]
+pub async fn cmd_fetch_timeline_stats(symbol: String) -> Result<timeline::TimelineStats, String> {
+    timeline::fetch_stats(&symbol).await
+}
+
+/// 当日连板天梯：按连板数分级的涨停股、炸板股和炸板率
+#[tauri::command]
+pub async fn cmd_fetch_limit_ladder() -> Result<limit_ladder::LimitLadder, String> {
+    limit_ladder::fetch_ladder().await
+}
+
+/// 当日涨停股池（含炸板股），每只股票带连板数、封板时间、炸板次数和封单金额
+#[tauri::command]
+pub async fn cmd_fetch_limit_pool() -> Result<Vec<limit_ladder::LimitPoolEntry>, String> {
+    limit_ladder::fetch_pool().await
+}
+
+/// 当前交易时段（集合竞价 / 连续竞价 / 午休 / 休市等）和前后交易日
+#[tauri::command]
+pub fn cmd_get_market_status() -> calendar::MarketStatus {
+    calendar::status()
+}
+
+/// start..=end（YYYY-MM-DD）之间的交易日
+#[tauri::command]
+pub fn cmd_get_trading_days(start: String, end: String) -> Result<Vec<String>, String> {
+    let parse = |s: &str| {
+        chrono::NaiveDate::parse_from_str(s.trim(), "%Y-%m-%d").map_err(|_| format!("日期格式错误: {}，应为 YYYY-MM-DD", s))
+    };
+    let (start, end) = (parse(&start)?, parse(&end)?);
+    if (end - start).num_days() > 3660 {
+        return Err("日期范围不能超过 10 年".to_string());
+    }
+    Ok(calendar::trading_days(start, end)
+        .into_iter()
+        .map(|d| d.format("%Y-%m-%d").to_string())
+        .collect())
+}
+
+/// 某年的节假日休市安排，covered 为 false 时该年没有节假日数据（只排除周末）
+#[tauri::command]
+pub fn cmd_get_trading_holidays(year: i32) -> serde_json::Value {
+    serde_json::json!({
+        "year": year,
+        "covered": calendar::covered_years().contains(&year),
+        "holidays": calendar::holidays(year),
+    })
+}
+
+/// 更新某年的节假日休市安排（整年替换，交易所公布次年安排后调用）
+#[tauri::command]
+pub fn cmd_set_trading_holidays(
+    db: State<Arc<Database>>,
+    year: i32,
+    holidays: Vec<calendar::Holiday>,
+) -> Result<Vec<calendar::Holiday>, String> {
+    calendar::set_year(&db, year, &holidays)?;
+    Ok(calendar::holidays(year))
+}
+
+/// 从东方财富同步证券主表，返回证券数
+#[tauri::command]
+pub async fn cmd_sync_securities(db: State<'_, Arc<Database>>) -> Result<usize, String> {
+    security::sync(&db).await
+}
+
+/// 查询证券主表（交易所、板块、类型、ST、上市日期、拼音首字母）
+#[tauri::command]
+pub fn cmd_get_security(db: State<Arc<Database>>, symbol: String) -> Result<Option<security::Security>, String> {
+    security::get(&db, &symbol)
+}
+
+/// 当前行情数据源配置
+#[tauri::command]
+pub fn cmd_get_market_provider() -> ProviderConfig {
+    provider::current_config()
+}
+
+/// 切换行情数据源（eastmoney / fixture）并保存，设置面板加载和修改时调用
+#[tauri::command]
+pub fn cmd_set_market_provider(db: State<Arc<Database>>, config: ProviderConfig) -> Result<ProviderConfig, String> {
+    provider::select_and_save(&db, config)?;
+    Ok(provider::current_config())
+}
+
+/// 个股相对基准（如 sh000300）的贝塔、相关系数和相对强弱，结果按代码返回
+#[tauri::command]
+pub async fn cmd_relative_strength(
+    symbols: Vec<String>,
+    benchmark: String,
+    window: Option<usize>,
+    bars: Option<usize>,
+    adjust_mode: Option<String>,
+) -> Result<serde_json::Value, String> {
+    let adjust_mode = match adjust_mode {
+        Some(s) => AdjustMode::parse(&s)?,
+        None => AdjustMode::default(),
+    };
+    let window = window.unwrap_or(20).clamp(2, 500);
+    let limit = bars.unwrap_or(250).clamp(window + 1, 5000);
+    let mut results = serde_json::Map::new();
+
+    for symbol in &symbols {
+        let value = match relative_report(symbol, &benchmark, window, limit, adjust_mode).await {
+            Ok(report) => serde_json::to_value(&report).unwrap_or_default(),
+            Err(e) => serde_json::json!({ "error": e }),
+        };
+        results.insert(symbol.clone(), value);
+    }
+
+    Ok(serde_json::Value::Object(results))
+}
+
+async fn relative_report(
+    symbol: &str,
+    benchmark: &str,
+    window: usize,
+    limit: usize,
+    adjust_mode: AdjustMode,
+) -> Result<relative::RelativeReport, String> {
+    let bars = kline::fetch_daily_klines(symbol, limit, adjust_mode).await?;
+    let first = bars.first().ok_or_else(|| format!("{} 没有 K 线数据", symbol))?;
+    let benchmark_bars = kline::fetch_daily_klines_since(benchmark, &first.date, adjust_mode).await?;
+    Ok(relative::analyze(&bars, &benchmark_bars, window))
+}
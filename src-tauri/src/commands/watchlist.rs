@@ -1,5 +1,6 @@
 use crate::db::models::*;
 use crate::db::Database;
+use crate::services::security;
 use std::sync::Arc;
 use tauri::State;
 
@@ -9,19 +10,24 @@ pub fn cmd_add_to_watchlist(
     symbol: String,
     name: Option<String>,
 ) -> Result<WatchlistItem, String> {
+    // 交易所和缺省的名称取自证券主表
+    let listed = security::get(&db, &symbol)?;
+    let exchange = Some(listed.as_ref().map(|s| s.exchange.clone()).unwrap_or_else(|| security::exchange_of(&symbol)));
+    let name = name.or_else(|| listed.map(|s| s.name));
+
     let conn = db.conn.lock().map_err(|e| e.to_string())?;
     let now = chrono::Utc::now().to_rfc3339();
 
     conn.execute(
-        "INSERT OR REPLACE INTO watchlist (symbol, name, added_at) VALUES (?1, ?2, ?3)",
-        rusqlite::params![symbol, name, now],
+        "INSERT OR REPLACE INTO watchlist (symbol, name, exchange, added_at) VALUES (?1, ?2, ?3, ?4)",
+        rusqlite::params![symbol, name, exchange, now],
     )
     .map_err(|e| e.to_string())?;
 
     Ok(WatchlistItem {
         symbol,
         name,
-        exchange: None,
+        exchange,
         added_at: now,
     })
 }
@@ -131,5 +131,161 @@ pub fn run(conn: &Connection) -> Result<(), rusqlite::Error> {
         );"
     )?;
 
+    // Migration: indicator.adjust_mode 列（none / forward / backward）
+    let has_adjust_mode: bool = conn
+        .prepare("SELECT COUNT(*) FROM pragma_table_info('indicator') WHERE name='adjust_mode'")
+        .and_then(|mut s| s.query_row([], |r| r.get::<_, i64>(0)))
+        .map(|c| c > 0)
+        .unwrap_or(false);
+
+    if !has_adjust_mode {
+        conn.execute_batch("ALTER TABLE indicator ADD COLUMN adjust_mode TEXT NOT NULL DEFAULT 'forward';")?;
+    }
+
+    // Migration: indicator.chip_model 列（筹码分布模型 JSON，NULL 表示默认）
+    let has_chip_model: bool = conn
+        .prepare("SELECT COUNT(*) FROM pragma_table_info('indicator') WHERE name='chip_model'")
+        .and_then(|mut s| s.query_row([], |r| r.get::<_, i64>(0)))
+        .map(|c| c > 0)
+        .unwrap_or(false);
+
+    if !has_chip_model {
+        conn.execute_batch("ALTER TABLE indicator ADD COLUMN chip_model TEXT;")?;
+    }
+
+    // Migration: indicator.fill_policy 列（停牌日填充策略 forward_fill / undefined / drop）
+    let has_fill_policy: bool = conn
+        .prepare("SELECT COUNT(*) FROM pragma_table_info('indicator') WHERE name='fill_policy'")
+        .and_then(|mut s| s.query_row([], |r| r.get::<_, i64>(0)))
+        .map(|c| c > 0)
+        .unwrap_or(false);
+
+    if !has_fill_policy {
+        conn.execute_batch("ALTER TABLE indicator ADD COLUMN fill_policy TEXT NOT NULL DEFAULT 'drop';")?;
+    }
+
+    // Migration: indicator.symbol_source 列（动态股票来源 JSON，NULL 表示只用 stock_symbols）
+    let has_symbol_source: bool = conn
+        .prepare("SELECT COUNT(*) FROM pragma_table_info('indicator') WHERE name='symbol_source'")
+        .and_then(|mut s| s.query_row([], |r| r.get::<_, i64>(0)))
+        .map(|c| c > 0)
+        .unwrap_or(false);
+
+    if !has_symbol_source {
+        conn.execute_batch("ALTER TABLE indicator ADD COLUMN symbol_source TEXT;")?;
+    }
+
+    // Migration: indicator_signal 表（指标信号历史，同一根 K 线上的同一信号只记录一次）
+    conn.execute_batch(
+        "CREATE TABLE IF NOT EXISTS indicator_signal (
+            id TEXT PRIMARY KEY,
+            indicator_id TEXT NOT NULL REFERENCES indicator(id) ON DELETE CASCADE,
+            symbol TEXT NOT NULL,
+            signal_text TEXT NOT NULL,
+            signal_value REAL,
+            bar_date TEXT NOT NULL,
+            fired_at TEXT NOT NULL,
+            delivered INTEGER DEFAULT 0,
+            acknowledged_at TEXT,
+            UNIQUE (indicator_id, symbol, signal_text, bar_date)
+        );
+        CREATE INDEX IF NOT EXISTS idx_indicator_signal_bar_date ON indicator_signal(bar_date);",
+    )?;
+
+    // Migration: indicator_signal.signal_value 允许为 NULL（价位为 NaN 时），SQLite 不能修改列约束，需重建表
+    let signal_value_not_null: bool = conn
+        .prepare("SELECT COUNT(*) FROM pragma_table_info('indicator_signal') WHERE name='signal_value' AND \"notnull\"=1")
+        .and_then(|mut s| s.query_row([], |r| r.get::<_, i64>(0)))
+        .map(|c| c > 0)
+        .unwrap_or(false);
+
+    if signal_value_not_null {
+        conn.execute_batch(
+            "BEGIN;
+            CREATE TABLE indicator_signal_new (
+                id TEXT PRIMARY KEY,
+                indicator_id TEXT NOT NULL REFERENCES indicator(id) ON DELETE CASCADE,
+                symbol TEXT NOT NULL,
+                signal_text TEXT NOT NULL,
+                signal_value REAL,
+                bar_date TEXT NOT NULL,
+                fired_at TEXT NOT NULL,
+                delivered INTEGER DEFAULT 0,
+                acknowledged_at TEXT,
+                UNIQUE (indicator_id, symbol, signal_text, bar_date)
+            );
+            INSERT INTO indicator_signal_new SELECT id, indicator_id, symbol, signal_text, signal_value, bar_date, fired_at, delivered, acknowledged_at FROM indicator_signal;
+            DROP TABLE indicator_signal;
+            ALTER TABLE indicator_signal_new RENAME TO indicator_signal;
+            CREATE INDEX IF NOT EXISTS idx_indicator_signal_bar_date ON indicator_signal(bar_date);
+            COMMIT;",
+        )?;
+    }
+
+    // Migration: kline_bar / kline_sync 表（本地 K 线库，按数据源、代码、周期、复权方式存储）
+    conn.execute_batch(
+        "CREATE TABLE IF NOT EXISTS kline_bar (
+            source TEXT NOT NULL,
+            symbol TEXT NOT NULL,
+            period TEXT NOT NULL,
+            adjust TEXT NOT NULL,
+            date TEXT NOT NULL,
+            open REAL NOT NULL,
+            close REAL NOT NULL,
+            high REAL NOT NULL,
+            low REAL NOT NULL,
+            volume REAL NOT NULL,
+            amount REAL NOT NULL,
+            PRIMARY KEY (source, symbol, period, adjust, date)
+        ) WITHOUT ROWID;
+        CREATE TABLE IF NOT EXISTS kline_sync (
+            source TEXT NOT NULL,
+            symbol TEXT NOT NULL,
+            period TEXT NOT NULL,
+            adjust TEXT NOT NULL,
+            complete INTEGER NOT NULL DEFAULT 0,
+            covered_from TEXT,
+            synced_at TEXT NOT NULL,
+            PRIMARY KEY (source, symbol, period, adjust)
+        );",
+    )?;
+
+    // Migration: security 表（证券主表，同一代码可能同时是深市股票和沪市指数）
+    conn.execute_batch(
+        "CREATE TABLE IF NOT EXISTS security (
+            code TEXT NOT NULL,
+            exchange TEXT NOT NULL,
+            name TEXT NOT NULL,
+            board TEXT,
+            sec_type TEXT NOT NULL,
+            is_st INTEGER NOT NULL DEFAULT 0,
+            listing_date TEXT,
+            pinyin TEXT NOT NULL DEFAULT '',
+            updated_at TEXT NOT NULL,
+            PRIMARY KEY (exchange, code)
+        );
+        CREATE INDEX IF NOT EXISTS idx_security_code ON security(code);",
+    )?;
+
+    // Migration: trading_holiday 表（用户更新的节假日，按年整体覆盖内置数据；date 为空表示该年无工作日休市）
+    conn.execute_batch(
+        "CREATE TABLE IF NOT EXISTS trading_holiday (
+            year INTEGER NOT NULL,
+            date TEXT NOT NULL,
+            name TEXT NOT NULL,
+            updated_at TEXT NOT NULL,
+            PRIMARY KEY (year, date)
+        );",
+    )?;
+
+    // Migration: app_setting 表（需要在启动时、前端加载前生效的设置，如行情数据源）
+    conn.execute_batch(
+        "CREATE TABLE IF NOT EXISTS app_setting (
+            key TEXT PRIMARY KEY,
+            value TEXT NOT NULL,
+            updated_at TEXT NOT NULL
+        );",
+    )?;
+
     Ok(())
 }
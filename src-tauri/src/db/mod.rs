@@ -1,7 +1,10 @@
 pub mod migrations;
 pub mod models;
 
+use crate::services::kline::AdjustMode;
+use crate::services::tdx::evaluator::UserIndicator;
 use rusqlite::Connection;
+use std::collections::HashMap;
 use std::path::PathBuf;
 use std::sync::Mutex;
 
@@ -24,6 +27,47 @@ impl Database {
         Ok(db)
     }
 
+    /// 全部用户指标公式及其复权方式、筹码模型（键为大写名称），供 NAME.OUTPUT 引用
+    pub fn indicator_formulas(&self) -> Result<HashMap<String, UserIndicator>, String> {
+        let conn = self.conn.lock().map_err(|e| e.to_string())?;
+        let mut stmt = conn
+            .prepare("SELECT name, formula_source, adjust_mode, chip_model FROM indicator")
+            .map_err(|e| e.to_string())?;
+        let formulas = stmt
+            .query_map([], |row| {
+                let adjust_mode: String = row.get(2)?;
+                let chip_model: Option<String> = row.get(3)?;
+                Ok((
+                    row.get::<_, String>(0)?.to_uppercase(),
+                    UserIndicator {
+                        source: row.get(1)?,
+                        adjust_mode: AdjustMode::parse(&adjust_mode).unwrap_or_default(),
+                        chip_model: chip_model
+                            .and_then(|s| serde_json::from_str(&s).ok())
+                            .unwrap_or_default(),
+                    },
+                ))
+            })
+            .map_err(|e| e.to_string())?
+            .collect::<Result<HashMap<_, _>, _>>()
+            .map_err(|e| e.to_string())?;
+        Ok(formulas)
+    }
+
+    /// 自选股代码（按加入时间倒序）
+    pub fn watchlist_symbols(&self) -> Result<Vec<String>, String> {
+        let conn = self.conn.lock().map_err(|e| e.to_string())?;
+        let mut stmt = conn
+            .prepare("SELECT symbol FROM watchlist ORDER BY added_at DESC")
+            .map_err(|e| e.to_string())?;
+        let symbols = stmt
+            .query_map([], |row| row.get::<_, String>(0))
+            .map_err(|e| e.to_string())?
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(|e| e.to_string())?;
+        Ok(symbols)
+    }
+
     fn run_migrations(&self) -> Result<(), rusqlite::Error> {
         let conn = self.conn.lock().unwrap();
         migrations::run(&conn)
@@ -1,4 +1,6 @@
-use serde::{Deserialize, Serialize};
+use crate::services::symbol_source::SymbolSource;
+use crate::services::tdx::chips::ChipModel;
+use serde::{Deserialize, Deserializer, Serialize};
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
 pub struct Task {
@@ -95,6 +97,11 @@ pub struct Indicator {
     pub is_active: bool,
     pub check_interval_secs: i64,
     pub market_hours_only: bool,
+    pub adjust_mode: String,
+    pub chip_model: Option<ChipModel>,
+    pub fill_policy: String,
+    /// 动态股票来源，每个检查周期与 stock_symbols 合并
+    pub symbol_source: Option<SymbolSource>,
     pub last_checked: Option<String>,
     pub last_signal: Option<String>,
     pub created_at: String,
@@ -109,6 +116,10 @@ pub struct CreateIndicatorRequest {
     pub task_id: Option<String>,
     pub check_interval_secs: Option<i64>,
     pub market_hours_only: Option<bool>,
+    pub adjust_mode: Option<String>,
+    pub chip_model: Option<ChipModel>,
+    pub fill_policy: Option<String>,
+    pub symbol_source: Option<SymbolSource>,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
@@ -120,4 +131,52 @@ pub struct UpdateIndicatorRequest {
     pub is_active: Option<bool>,
     pub check_interval_secs: Option<i64>,
     pub market_hours_only: Option<bool>,
+    pub adjust_mode: Option<String>,
+    /// 传 null 恢复默认筹码模型，省略则不修改
+    #[serde(default, deserialize_with = "present")]
+    pub chip_model: Option<Option<ChipModel>>,
+    pub fill_policy: Option<String>,
+    /// 传 null 清除股票来源，省略则不修改
+    #[serde(default, deserialize_with = "present")]
+    pub symbol_source: Option<Option<SymbolSource>>,
+}
+
+/// 区分字段省略（None）与显式 null（Some(None)）
+fn present<'de, T, D>(deserializer: D) -> Result<Option<Option<T>>, D::Error>
+where
+    T: Deserialize<'de>,
+    D: Deserializer<'de>,
+{
+    Option::<T>::deserialize(deserializer).map(Some)
+}
+
+/// 指标信号历史记录
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct IndicatorSignal {
+    pub id: String,
+    pub indicator_id: String,
+    pub indicator_name: String,
+    pub symbol: String,
+    pub signal_text: String,
+    /// 信号价位，价格表达式不是有限值时为 None
+    pub signal_value: Option<f64>,
+    /// 触发信号的 K 线日期（YYYY-MM-DD）
+    pub bar_date: String,
+    pub fired_at: String,
+    /// 前端已收到并展示
+    pub delivered: bool,
+    pub acknowledged_at: Option<String>,
+}
+
+/// 信号历史查询条件，均可省略
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+pub struct IndicatorSignalQuery {
+    pub indicator_id: Option<String>,
+    pub symbol: Option<String>,
+    /// K 线日期下限（含），YYYY-MM-DD
+    pub since: Option<String>,
+    /// K 线日期上限（含），YYYY-MM-DD
+    pub until: Option<String>,
+    pub unacknowledged_only: Option<bool>,
+    pub limit: Option<u32>,
 }
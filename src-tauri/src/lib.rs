@@ -21,6 +21,32 @@ pub fn run() {
 
             let database = Arc::new(Database::new(app_data_dir.clone()).expect("Failed to initialize database"));
 
+            // 交易日历：用户更新过的节假日覆盖内置数据
+            if let Err(e) = services::calendar::load(&database) {
+                eprintln!("Warning: trading calendar load failed: {}", e);
+            }
+
+            // 行情数据源：恢复保存的设置，之后的证券同步和调度器都使用该数据源
+            if let Err(e) = services::provider::load(&database) {
+                eprintln!("Warning: market provider load failed: {}", e);
+            }
+
+            // 证券主表：加载索引，超过一天未同步时后台同步
+            if let Err(e) = services::security::load(&database) {
+                eprintln!("Warning: security master load failed: {}", e);
+            }
+            let security_db = Arc::clone(&database);
+            tauri::async_runtime::spawn(async move {
+                if services::security::needs_sync(&security_db) {
+                    if let Err(e) = services::security::sync(&security_db).await {
+                        eprintln!("同步证券主表失败: {}", e);
+                    }
+                }
+            });
+
+            // K 线持久化到本地库
+            services::kline::attach_store(Arc::clone(&database));
+
             // Scheduler 共享 Database
             let scheduler = Scheduler::new(Arc::clone(&database), app.handle().clone(), app_data_dir);
             tauri::async_runtime::spawn(async move {
@@ -52,6 +78,21 @@ pub fn run() {
             commands::market_data::cmd_search_stocks,
             commands::market_data::cmd_fetch_batch_quotes,
             commands::market_data::cmd_fetch_limit_stocks,
+            commands::market_data::cmd_fetch_order_book,
+            commands::market_data::cmd_fetch_trade_ticks,
+            commands::market_data::cmd_fetch_timeline,
+            commands::market_data::cmd_fetch_timeline_stats,
+            commands::market_data::cmd_fetch_limit_ladder,
+            commands::market_data::cmd_fetch_limit_pool,
+            commands::market_data::cmd_get_market_status,
+            commands::market_data::cmd_get_trading_days,
+            commands::market_data::cmd_get_trading_holidays,
+            commands::market_data::cmd_set_trading_holidays,
+            commands::market_data::cmd_relative_strength,
+            commands::market_data::cmd_sync_securities,
+            commands::market_data::cmd_get_security,
+            commands::market_data::cmd_get_market_provider,
+            commands::market_data::cmd_set_market_provider,
             // 提醒规则
             commands::alert::create_alert_rule,
             commands::alert::list_active_alerts,
@@ -82,11 +123,16 @@ pub fn run() {
             commands::browser::cmd_browser_get_info,
             // TDX 指标
             commands::indicator::cmd_validate_tdx_formula,
+            commands::indicator::cmd_formula_assist,
             commands::indicator::cmd_create_indicator,
             commands::indicator::cmd_list_indicators,
             commands::indicator::cmd_update_indicator,
             commands::indicator::cmd_delete_indicator,
             commands::indicator::cmd_evaluate_indicator,
+            commands::indicator::cmd_indicator_event_study,
+            commands::indicator::cmd_list_indicator_signals,
+            commands::indicator::cmd_acknowledge_indicator_signals,
+            commands::indicator::cmd_mark_indicator_signal_delivered,
         ])
         .run(tauri::generate_context!())
         .expect("error while running tauri application");
@@ -0,0 +1,225 @@
+//! K 线按交易日历对齐
+//!
+//! 东方财富的日 K 线在个股停牌日没有数据，序列中相邻两根可能相隔多个交易日。
+//! 按交易日历（calendar）补齐停牌日，并标记价格已过时的 K 线，
+//! 避免在停牌期间用停牌前的价格反复触发信号。
+
+use crate::services::calendar;
+use crate::services::kline::KlineBar;
+use chrono::{Datelike, NaiveDate};
+use serde::{Deserialize, Serialize};
+
+/// 停牌日的填充策略
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum FillPolicy {
+    /// 沿用停牌前的收盘价（开高低收相同），成交量为 0
+    ForwardFill,
+    /// 价格为未定义，涉及停牌日的计算结果未定义、条件不成立
+    Undefined,
+    /// 不插入停牌日（默认，与行情软件一致）
+    #[default]
+    Drop,
+}
+
+impl FillPolicy {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            FillPolicy::ForwardFill => "forward_fill",
+            FillPolicy::Undefined => "undefined",
+            FillPolicy::Drop => "drop",
+        }
+    }
+
+    /// 解析填充策略，兼容中文写法
+    pub fn parse(s: &str) -> Result<Self, String> {
+        match s.trim().to_lowercase().as_str() {
+            "forward_fill" | "ffill" | "前值填充" => Ok(FillPolicy::ForwardFill),
+            "undefined" | "未定义" => Ok(FillPolicy::Undefined),
+            "drop" | "跳过" => Ok(FillPolicy::Drop),
+            other => Err(format!(
+                "无效的停牌填充策略: {}，请使用 forward_fill / undefined / drop",
+                other
+            )),
+        }
+    }
+}
+
+/// 对齐后的 K 线
+#[derive(Debug, Clone, Default)]
+pub struct AlignedBars {
+    pub bars: Vec<KlineBar>,
+    /// 与 bars 等长：该根为停牌日填充值，或停牌至今时（drop 策略）最后一根已过时
+    pub stale: Vec<bool>,
+    /// 首根 K 线之后个股停牌的交易日（无论是否填充）
+    pub suspended_dates: Vec<String>,
+}
+
+impl AlignedBars {
+    /// 最后一根 K 线的价格是否已过时
+    pub fn is_stale(&self) -> bool {
+        self.stale.last().copied().unwrap_or(false)
+    }
+}
+
+/// 按交易日历对齐：calendar 为升序的交易日（YYYY-MM-DD），只处理首根 K 线之后的日期；
+/// 日历中没有而 K 线有的日期原样保留
+pub fn align(bars: &[KlineBar], calendar: &[String], policy: FillPolicy) -> AlignedBars {
+    let Some(first) = bars.first() else {
+        return AlignedBars::default();
+    };
+    let days: Vec<&str> = calendar
+        .iter()
+        .map(|d| d.as_str())
+        .filter(|d| *d >= first.date.as_str())
+        .collect();
+
+    let mut result = AlignedBars::default();
+    let (mut i, mut j) = (0, 0);
+    loop {
+        match (bars.get(i), days.get(j)) {
+            (Some(bar), Some(day)) if bar.date.as_str() <= *day => {
+                if bar.date.as_str() == *day {
+                    j += 1;
+                }
+                result.bars.push(bar.clone());
+                result.stale.push(false);
+                i += 1;
+            }
+            (_, Some(day)) => {
+                result.suspended_dates.push(day.to_string());
+                let filled = match policy {
+                    FillPolicy::ForwardFill => result.bars.last().map(|prev| KlineBar {
+                        date: day.to_string(),
+                        open: prev.close,
+                        close: prev.close,
+                        high: prev.close,
+                        low: prev.close,
+                        volume: 0.0,
+                        amount: 0.0,
+                    }),
+                    FillPolicy::Undefined => Some(KlineBar {
+                        date: day.to_string(),
+                        open: f64::NAN,
+                        close: f64::NAN,
+                        high: f64::NAN,
+                        low: f64::NAN,
+                        volume: 0.0,
+                        amount: 0.0,
+                    }),
+                    FillPolicy::Drop => None,
+                };
+                if let Some(bar) = filled {
+                    result.bars.push(bar);
+                    result.stale.push(true);
+                }
+                j += 1;
+            }
+            (Some(bar), None) => {
+                result.bars.push(bar.clone());
+                result.stale.push(false);
+                i += 1;
+            }
+            (None, None) => break,
+        }
+    }
+
+    // drop 策略下停牌至今：最后一根是停牌前的价格
+    let suspended_now = days.last().is_some_and(|d| bars.last().is_some_and(|b| b.date.as_str() < *d));
+    if policy == FillPolicy::Drop && suspended_now {
+        if let Some(last) = result.stale.last_mut() {
+            *last = true;
+        }
+    }
+    result
+}
+
+/// start（YYYY-MM-DD）至最近一个已开盘交易日的交易日。
+/// 没有节假日数据的年份无法区分节假日和停牌，不计入（这些年份的 K 线原样保留）
+pub fn trading_calendar(start: &str) -> Vec<String> {
+    let Ok(start) = NaiveDate::parse_from_str(start.get(..10).unwrap_or(start), "%Y-%m-%d") else {
+        return Vec::new();
+    };
+    let covered = calendar::covered_years();
+    calendar::trading_days(start, calendar::latest_session())
+        .into_iter()
+        .filter(|d| covered.contains(&d.year()))
+        .map(|d| d.format("%Y-%m-%d").to_string())
+        .collect()
+}
+
+/// 按首根 K 线以来的交易日历对齐
+pub fn align_to_calendar(bars: &[KlineBar], policy: FillPolicy) -> AlignedBars {
+    match bars.first() {
+        Some(first) => align(bars, &trading_calendar(&first.date), policy),
+        None => AlignedBars::default(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn bars(points: &[(&str, f64)]) -> Vec<KlineBar> {
+        points
+            .iter()
+            .map(|(date, c)| KlineBar {
+                date: date.to_string(),
+                open: *c,
+                close: *c,
+                high: *c,
+                low: *c,
+                volume: 1000.0,
+                amount: c * 1000.0,
+            })
+            .collect()
+    }
+
+    fn calendar(days: &[&str]) -> Vec<String> {
+        days.iter().map(|d| d.to_string()).collect()
+    }
+
+    #[test]
+    fn test_fill_policies() {
+        let cal = calendar(&["2025-01-02", "2025-01-03", "2025-01-06", "2025-01-07", "2025-01-08"]);
+        // 01-06、01-07 停牌
+        let stock = bars(&[("2025-01-02", 10.0), ("2025-01-03", 10.5), ("2025-01-08", 11.0)]);
+
+        let ffill = align(&stock, &cal, FillPolicy::ForwardFill);
+        assert_eq!(ffill.bars.len(), 5);
+        assert_eq!(ffill.bars[2].date, "2025-01-06");
+        assert_eq!(ffill.bars[3].close, 10.5);
+        assert_eq!(ffill.bars[3].volume, 0.0);
+        assert_eq!(ffill.stale, vec![false, false, true, true, false]);
+        assert_eq!(ffill.suspended_dates, vec!["2025-01-06", "2025-01-07"]);
+
+        let undefined = align(&stock, &cal, FillPolicy::Undefined);
+        assert!(undefined.bars[2].close.is_nan());
+        assert!(!undefined.is_stale());
+
+        let drop = align(&stock, &cal, FillPolicy::Drop);
+        assert_eq!(drop.bars.len(), 3);
+        assert_eq!(drop.stale, vec![false; 3]);
+        assert_eq!(drop.suspended_dates.len(), 2);
+    }
+
+    #[test]
+    fn test_suspended_until_today() {
+        let cal = calendar(&["2025-01-02", "2025-01-03", "2025-01-06"]);
+        let stock = bars(&[("2024-12-31", 9.0), ("2025-01-02", 10.0)]);
+
+        let drop = align(&stock, &cal, FillPolicy::Drop);
+        assert_eq!(drop.bars.len(), 2);
+        assert!(drop.is_stale());
+
+        let ffill = align(&stock, &cal, FillPolicy::ForwardFill);
+        assert_eq!(ffill.bars.last().unwrap().date, "2025-01-06");
+        assert_eq!(ffill.bars.last().unwrap().close, 10.0);
+        assert!(ffill.is_stale());
+
+        // 日历早于 K 线（日历获取范围不足）时 K 线原样保留
+        let partial = align(&stock, &calendar(&[]), FillPolicy::ForwardFill);
+        assert_eq!(partial.bars.len(), 2);
+        assert!(!partial.is_stale());
+    }
+}
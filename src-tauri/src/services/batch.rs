@@ -0,0 +1,83 @@
+//! 有界并发执行
+//!
+//! 调度器每个周期要为多个指标、多只股票请求行情和计算公式，逐个 await 会超过 10 秒的周期。
+//! 这里用信号量限制同时进行的任务数，并给每个任务单独设置超时，单个请求卡住不影响其他任务。
+
+use std::future::Future;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::sync::Semaphore;
+use tokio::task::JoinSet;
+
+/// 并发执行 tasks，最多 limit 个同时进行，每个任务超过 timeout 记为失败。
+/// 结果顺序与 tasks 一致
+pub async fn run_bounded<T, F>(tasks: Vec<F>, limit: usize, timeout: Duration) -> Vec<Result<T, String>>
+where
+    T: Send + 'static,
+    F: Future<Output = Result<T, String>> + Send + 'static,
+{
+    let semaphore = Arc::new(Semaphore::new(limit.max(1)));
+    let mut set = JoinSet::new();
+    let count = tasks.len();
+
+    for (index, task) in tasks.into_iter().enumerate() {
+        let semaphore = Arc::clone(&semaphore);
+        set.spawn(async move {
+            // 超时从拿到许可开始计算，排队时间不计入
+            let result = match semaphore.acquire_owned().await {
+                Ok(_permit) => match tokio::time::timeout(timeout, task).await {
+                    Ok(result) => result,
+                    Err(_) => Err(format!("超时（{} 秒）", timeout.as_secs_f64())),
+                },
+                Err(e) => Err(e.to_string()),
+            };
+            (index, result)
+        });
+    }
+
+    let mut results: Vec<Option<Result<T, String>>> = (0..count).map(|_| None).collect();
+    while let Some(joined) = set.join_next().await {
+        match joined {
+            Ok((index, result)) => results[index] = Some(result),
+            Err(e) => eprintln!("并发任务异常退出: {}", e),
+        }
+    }
+    results
+        .into_iter()
+        .map(|r| r.unwrap_or_else(|| Err("任务异常退出".to_string())))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    #[tokio::test]
+    async fn test_bounded_concurrency_and_timeout() {
+        let running = Arc::new(AtomicUsize::new(0));
+        let peak = Arc::new(AtomicUsize::new(0));
+
+        let tasks: Vec<_> = (0..10u64)
+            .map(|i| {
+                let running = Arc::clone(&running);
+                let peak = Arc::clone(&peak);
+                async move {
+                    let now = running.fetch_add(1, Ordering::SeqCst) + 1;
+                    peak.fetch_max(now, Ordering::SeqCst);
+                    // 第 3 个任务卡住
+                    let delay = if i == 3 { 1000 } else { 10 };
+                    tokio::time::sleep(Duration::from_millis(delay)).await;
+                    running.fetch_sub(1, Ordering::SeqCst);
+                    Ok(i * 2)
+                }
+            })
+            .collect();
+
+        let results = run_bounded(tasks, 3, Duration::from_millis(200)).await;
+        assert_eq!(results.len(), 10);
+        assert!(peak.load(Ordering::SeqCst) <= 3);
+        assert!(results[3].as_ref().unwrap_err().contains("超时"));
+        assert_eq!(results[9], Ok(18));
+    }
+}
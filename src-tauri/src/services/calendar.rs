@@ -0,0 +1,400 @@
+//! A 股交易日历
+//!
+//! 休市日 = 周末 + 节假日。节假日随程序内置（交易所每年年底公布次年安排），
+//! 用户可按年份更新（存入 trading_holiday 表，覆盖内置的同年数据）。
+//! 没有节假日数据的年份只排除周末，`covered_years` 给出有数据的年份。
+//!
+//! 交易时段（北京时间）：9:15-9:25 开盘集合竞价，9:25-9:30 等待开盘，
+//! 9:30-11:30 / 13:00-14:57 连续竞价，14:57-15:00 收盘集合竞价。
+
+use crate::db::Database;
+use chrono::{Datelike, NaiveDate, NaiveDateTime, NaiveTime};
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, BTreeSet};
+use std::sync::RwLock;
+
+/// 内置节假日休市安排（仅列出工作日，周末本来就休市）
+const BUNDLED_HOLIDAYS: &[(&str, &[&str])] = &[
+    ("元旦", &["2024-01-01", "2025-01-01", "2026-01-01", "2026-01-02"]),
+    (
+        "春节",
+        &[
+            "2024-02-09", "2024-02-12", "2024-02-13", "2024-02-14", "2024-02-15", "2024-02-16",
+            "2025-01-28", "2025-01-29", "2025-01-30", "2025-01-31", "2025-02-03", "2025-02-04",
+            "2026-02-16", "2026-02-17", "2026-02-18", "2026-02-19", "2026-02-20", "2026-02-23",
+        ],
+    ),
+    ("清明节", &["2024-04-04", "2024-04-05", "2025-04-04", "2026-04-06"]),
+    (
+        "劳动节",
+        &[
+            "2024-05-01", "2024-05-02", "2024-05-03", "2025-05-01", "2025-05-02", "2025-05-05",
+            "2026-05-01", "2026-05-04", "2026-05-05",
+        ],
+    ),
+    ("端午节", &["2024-06-10", "2025-06-02", "2026-06-19"]),
+    ("中秋节", &["2024-09-16", "2024-09-17", "2026-09-25"]),
+    (
+        "国庆节",
+        &[
+            "2024-10-01", "2024-10-02", "2024-10-03", "2024-10-04", "2024-10-07",
+            "2025-10-01", "2025-10-02", "2025-10-03", "2025-10-06", "2025-10-07", "2025-10-08",
+            "2026-10-01", "2026-10-02", "2026-10-05", "2026-10-06", "2026-10-07",
+        ],
+    ),
+];
+
+/// 内置数据覆盖的年份
+const BUNDLED_YEARS: &[i32] = &[2024, 2025, 2026];
+
+/// 向前/向后查找交易日的最大天数（超过说明日历数据有误）
+const MAX_SEARCH_DAYS: i64 = 60;
+
+/// 节假日
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Holiday {
+    /// YYYY-MM-DD
+    pub date: String,
+    pub name: String,
+}
+
+/// 交易时段
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum MarketPhase {
+    /// 非交易日
+    Closed,
+    /// 交易日 9:15 前
+    PreMarket,
+    /// 9:15-9:25 开盘集合竞价
+    OpeningAuction,
+    /// 9:25-9:30 集合竞价结束、等待连续竞价
+    PreOpen,
+    /// 9:30-11:30、13:00-14:57 连续竞价
+    Continuous,
+    /// 11:30-13:00 午间休市
+    LunchBreak,
+    /// 14:57-15:00 收盘集合竞价
+    ClosingAuction,
+    /// 15:00 后
+    AfterHours,
+}
+
+impl MarketPhase {
+    /// 是否在撮合（集合竞价或连续竞价），行情会变化
+    pub fn is_trading(self) -> bool {
+        matches!(self, Self::OpeningAuction | Self::Continuous | Self::ClosingAuction)
+    }
+}
+
+/// 当前时段
+#[derive(Debug, Clone, Serialize)]
+pub struct MarketStatus {
+    pub phase: MarketPhase,
+    /// 北京时间 YYYY-MM-DD HH:MM:SS
+    pub now: String,
+    pub is_trading_day: bool,
+    /// 下一个交易日（不含今天）
+    pub next_trading_day: Option<String>,
+    /// 上一个交易日（不含今天）
+    pub prev_trading_day: Option<String>,
+    /// 节假日名称（今天休市且不是周末时）
+    pub holiday: Option<String>,
+}
+
+#[derive(Default)]
+struct Calendar {
+    /// 日期 → 名称
+    holidays: BTreeMap<NaiveDate, String>,
+    years: BTreeSet<i32>,
+}
+
+impl Calendar {
+    fn bundled() -> Self {
+        let mut calendar = Self::default();
+        for (name, dates) in BUNDLED_HOLIDAYS {
+            for date in *dates {
+                if let Ok(d) = NaiveDate::parse_from_str(date, "%Y-%m-%d") {
+                    calendar.holidays.insert(d, name.to_string());
+                }
+            }
+        }
+        calendar.years.extend(BUNDLED_YEARS);
+        calendar
+    }
+
+    /// 用一年的数据替换该年的节假日
+    fn replace_year(&mut self, year: i32, holidays: &[(NaiveDate, String)]) {
+        self.holidays.retain(|d, _| d.year() != year);
+        for (date, name) in holidays {
+            self.holidays.insert(*date, name.clone());
+        }
+        self.years.insert(year);
+    }
+
+    fn is_trading_day(&self, date: NaiveDate) -> bool {
+        date.weekday().num_days_from_monday() < 5 && !self.holidays.contains_key(&date)
+    }
+}
+
+static CALENDAR: once_cell::sync::Lazy<RwLock<Calendar>> =
+    once_cell::sync::Lazy::new(|| RwLock::new(Calendar::bundled()));
+
+fn read_calendar() -> std::sync::RwLockReadGuard<'static, Calendar> {
+    match CALENDAR.read() {
+        Ok(calendar) => calendar,
+        Err(poisoned) => poisoned.into_inner(),
+    }
+}
+
+/// UTC 时刻对应的北京时间
+pub fn beijing(at: chrono::DateTime<chrono::Utc>) -> NaiveDateTime {
+    (at + chrono::Duration::hours(8)).naive_utc()
+}
+
+/// 北京时间的当前时刻
+pub fn now() -> NaiveDateTime {
+    beijing(chrono::Utc::now())
+}
+
+/// 北京时间的今天
+pub fn today() -> NaiveDate {
+    now().date()
+}
+
+pub fn is_trading_day(date: NaiveDate) -> bool {
+    read_calendar().is_trading_day(date)
+}
+
+/// date 之后的第一个交易日
+pub fn next_trading_day(date: NaiveDate) -> Option<NaiveDate> {
+    let calendar = read_calendar();
+    (1..=MAX_SEARCH_DAYS)
+        .map(|i| date + chrono::Duration::days(i))
+        .find(|d| calendar.is_trading_day(*d))
+}
+
+/// date 之前的最后一个交易日
+pub fn prev_trading_day(date: NaiveDate) -> Option<NaiveDate> {
+    let calendar = read_calendar();
+    (1..=MAX_SEARCH_DAYS)
+        .map(|i| date - chrono::Duration::days(i))
+        .find(|d| calendar.is_trading_day(*d))
+}
+
+/// start..=end 之间的交易日
+pub fn trading_days(start: NaiveDate, end: NaiveDate) -> Vec<NaiveDate> {
+    let calendar = read_calendar();
+    start
+        .iter_days()
+        .take_while(|d| *d <= end)
+        .filter(|d| calendar.is_trading_day(*d))
+        .collect()
+}
+
+/// 某一时刻的交易时段
+pub fn phase_at(at: NaiveDateTime) -> MarketPhase {
+    if !is_trading_day(at.date()) {
+        return MarketPhase::Closed;
+    }
+    let t = at.time();
+    let hm = |h, m| NaiveTime::from_hms_opt(h, m, 0).unwrap_or_default();
+    if t < hm(9, 15) {
+        MarketPhase::PreMarket
+    } else if t < hm(9, 25) {
+        MarketPhase::OpeningAuction
+    } else if t < hm(9, 30) {
+        MarketPhase::PreOpen
+    } else if t < hm(11, 30) {
+        MarketPhase::Continuous
+    } else if t < hm(13, 0) {
+        MarketPhase::LunchBreak
+    } else if t < hm(14, 57) {
+        MarketPhase::Continuous
+    } else if t <= hm(15, 0) {
+        MarketPhase::ClosingAuction
+    } else {
+        MarketPhase::AfterHours
+    }
+}
+
+/// 某一时刻最近一个已开盘的交易日：非交易日或开盘集合竞价结束（9:25）前取上一个交易日
+pub fn latest_session_at(at: NaiveDateTime) -> NaiveDate {
+    match phase_at(at) {
+        MarketPhase::Closed | MarketPhase::PreMarket | MarketPhase::OpeningAuction => {
+            prev_trading_day(at.date()).unwrap_or(at.date())
+        }
+        _ => at.date(),
+    }
+}
+
+/// 最近一个已开盘的交易日（涨停股池、连板天梯等当日数据的日期）
+pub fn latest_session() -> NaiveDate {
+    latest_session_at(now())
+}
+
+/// 当前时段
+pub fn current_phase() -> MarketPhase {
+    phase_at(now())
+}
+
+/// 当前是否在撮合（含开盘、收盘集合竞价）
+pub fn is_market_open() -> bool {
+    current_phase().is_trading()
+}
+
+/// 当前时段、今天是否交易日、前后交易日
+pub fn status() -> MarketStatus {
+    let now = now();
+    let today = now.date();
+    let is_trading_day = is_trading_day(today);
+    let fmt = |d: NaiveDate| d.format("%Y-%m-%d").to_string();
+    MarketStatus {
+        phase: phase_at(now),
+        now: now.format("%Y-%m-%d %H:%M:%S").to_string(),
+        is_trading_day,
+        next_trading_day: next_trading_day(today).map(fmt),
+        prev_trading_day: prev_trading_day(today).map(fmt),
+        holiday: read_calendar().holidays.get(&today).cloned(),
+    }
+}
+
+/// 有节假日数据的年份（其他年份只排除周末）
+pub fn covered_years() -> Vec<i32> {
+    read_calendar().years.iter().copied().collect()
+}
+
+/// 某年的节假日
+pub fn holidays(year: i32) -> Vec<Holiday> {
+    read_calendar()
+        .holidays
+        .iter()
+        .filter(|(d, _)| d.year() == year)
+        .map(|(d, name)| Holiday {
+            date: d.format("%Y-%m-%d").to_string(),
+            name: name.clone(),
+        })
+        .collect()
+}
+
+fn parse_year(year: i32, holidays: &[Holiday]) -> Result<Vec<(NaiveDate, String)>, String> {
+    holidays
+        .iter()
+        .map(|h| {
+            let date = NaiveDate::parse_from_str(h.date.trim(), "%Y-%m-%d")
+                .map_err(|_| format!("日期格式错误: {}，应为 YYYY-MM-DD", h.date))?;
+            if date.year() != year {
+                return Err(format!("{} 不在 {} 年", h.date, year));
+            }
+            Ok((date, h.name.clone()))
+        })
+        .collect()
+}
+
+/// 从数据库加载用户更新过的年份，覆盖内置数据
+pub fn load(db: &Database) -> Result<usize, String> {
+    let rows = {
+        let conn = db.conn.lock().map_err(|e| e.to_string())?;
+        let mut stmt = conn
+            .prepare("SELECT year, date, name FROM trading_holiday ORDER BY year, date")
+            .map_err(|e| e.to_string())?;
+        let rows = stmt
+            .query_map([], |row| Ok((row.get::<_, i32>(0)?, row.get::<_, String>(1)?, row.get::<_, String>(2)?)))
+            .map_err(|e| e.to_string())?
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(|e| e.to_string())?;
+        rows
+    };
+
+    let mut by_year: BTreeMap<i32, Vec<Holiday>> = BTreeMap::new();
+    for (year, date, name) in rows {
+        by_year.entry(year).or_default().push(Holiday { date, name });
+    }
+    let mut calendar = CALENDAR.write().map_err(|e| e.to_string())?;
+    for (year, holidays) in &by_year {
+        // 空日期的占位行表示该年没有工作日休市
+        let holidays: Vec<Holiday> = holidays.iter().filter(|h| !h.date.is_empty()).cloned().collect();
+        calendar.replace_year(*year, &parse_year(*year, &holidays)?);
+    }
+    Ok(by_year.len())
+}
+
+/// 更新某年的节假日（整年替换），写入数据库并立即生效
+pub fn set_year(db: &Database, year: i32, holidays: &[Holiday]) -> Result<(), String> {
+    let parsed = parse_year(year, holidays)?;
+    {
+        let mut conn = db.conn.lock().map_err(|e| e.to_string())?;
+        let tx = conn.transaction().map_err(|e| e.to_string())?;
+        tx.execute("DELETE FROM trading_holiday WHERE year = ?1", [year])
+            .map_err(|e| e.to_string())?;
+        let now = chrono::Utc::now().to_rfc3339();
+        if parsed.is_empty() {
+            tx.execute(
+                "INSERT INTO trading_holiday (year, date, name, updated_at) VALUES (?1, '', '', ?2)",
+                rusqlite::params![year, now],
+            )
+            .map_err(|e| e.to_string())?;
+        }
+        for (date, name) in &parsed {
+            tx.execute(
+                "INSERT INTO trading_holiday (year, date, name, updated_at) VALUES (?1, ?2, ?3, ?4)",
+                rusqlite::params![year, date.format("%Y-%m-%d").to_string(), name, now],
+            )
+            .map_err(|e| e.to_string())?;
+        }
+        tx.commit().map_err(|e| e.to_string())?;
+    }
+    CALENDAR.write().map_err(|e| e.to_string())?.replace_year(year, &parsed);
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn date(s: &str) -> NaiveDate {
+        NaiveDate::parse_from_str(s, "%Y-%m-%d").unwrap()
+    }
+
+    fn at(s: &str) -> NaiveDateTime {
+        NaiveDateTime::parse_from_str(s, "%Y-%m-%d %H:%M:%S").unwrap()
+    }
+
+    #[test]
+    fn test_trading_days() {
+        // 2025 国庆：9-30 周二交易，10-01 至 10-08 休市（10-04、10-05 周末）
+        assert!(!is_trading_day(date("2025-10-01")));
+        assert_eq!(next_trading_day(date("2025-09-30")), Some(date("2025-10-09")));
+        assert_eq!(prev_trading_day(date("2025-10-09")), Some(date("2025-09-30")));
+        // 调休上班的周六不开市
+        assert!(!is_trading_day(date("2025-09-28")));
+        assert_eq!(trading_days(date("2025-01-27"), date("2025-02-07")).len(), 4);
+    }
+
+    #[test]
+    fn test_phases() {
+        assert_eq!(phase_at(at("2025-06-30 09:14:59")), MarketPhase::PreMarket);
+        assert_eq!(phase_at(at("2025-06-30 09:15:00")), MarketPhase::OpeningAuction);
+        assert_eq!(phase_at(at("2025-06-30 09:27:00")), MarketPhase::PreOpen);
+        assert_eq!(phase_at(at("2025-06-30 11:30:00")), MarketPhase::LunchBreak);
+        assert_eq!(phase_at(at("2025-06-30 14:57:00")), MarketPhase::ClosingAuction);
+        assert_eq!(phase_at(at("2025-06-30 15:00:00")), MarketPhase::ClosingAuction);
+        assert_eq!(phase_at(at("2025-06-30 15:00:01")), MarketPhase::AfterHours);
+        // 端午节
+        assert_eq!(phase_at(at("2025-06-02 10:00:00")), MarketPhase::Closed);
+        assert!(MarketPhase::OpeningAuction.is_trading());
+        assert!(!MarketPhase::LunchBreak.is_trading());
+    }
+
+    #[test]
+    fn test_latest_session() {
+        // 周一开盘前取上周五，集合竞价结束后取当天
+        assert_eq!(latest_session_at(at("2025-06-30 08:00:00")), date("2025-06-27"));
+        assert_eq!(latest_session_at(at("2025-06-30 09:20:00")), date("2025-06-27"));
+        assert_eq!(latest_session_at(at("2025-06-30 09:25:00")), date("2025-06-30"));
+        assert_eq!(latest_session_at(at("2025-06-30 20:00:00")), date("2025-06-30"));
+        // 周末、节假日取节前最后一个交易日
+        assert_eq!(latest_session_at(at("2025-06-29 10:00:00")), date("2025-06-27"));
+        assert_eq!(latest_session_at(at("2025-10-03 10:00:00")), date("2025-09-30"));
+    }
+}
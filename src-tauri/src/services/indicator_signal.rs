@@ -0,0 +1,67 @@
+//! 指标信号历史
+//!
+//! 同一指标、股票、信号文字在同一根 K 线上只记录一次，写入成功才需要提醒。
+//! 信号价位（DRAWTEXT 的价格表达式）可能不是有限值（如 REF 越界为 NaN），这时记为 NULL。
+
+use crate::db::Database;
+
+/// 记录一次信号，返回新记录的 id；该 K 线上已记录过时返回 None
+pub fn record(
+    db: &Database,
+    indicator_id: &str,
+    symbol: &str,
+    signal_text: &str,
+    signal_value: f64,
+    bar_date: &str,
+    fired_at: &str,
+) -> Result<Option<String>, String> {
+    let id = uuid::Uuid::new_v4().to_string();
+    let value = signal_value.is_finite().then_some(signal_value);
+    let conn = db.conn.lock().map_err(|e| e.to_string())?;
+    let inserted = conn
+        .execute(
+            "INSERT OR IGNORE INTO indicator_signal (id, indicator_id, symbol, signal_text, signal_value, bar_date, fired_at)
+             VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7)",
+            rusqlite::params![id, indicator_id, symbol, signal_text, value, bar_date, fired_at],
+        )
+        .map_err(|e| e.to_string())?;
+    Ok((inserted > 0).then_some(id))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_record_non_finite_value() {
+        let dir = std::env::temp_dir().join(format!("indicator_signal_{}", uuid::Uuid::new_v4()));
+        let db = Database::new(dir.clone()).unwrap();
+        db.conn
+            .lock()
+            .unwrap()
+            .execute(
+                "INSERT INTO indicator (id, name, formula_source, stock_symbols, created_at, updated_at)
+                 VALUES ('ind', 'T', 'DRAWTEXT(C > 0, REF(C, 9999), ''X'');', '[]', '', '')",
+                [],
+            )
+            .unwrap();
+        let record = |value: f64, date: &str| record(&db, "ind", "600000", "X", value, date, "2025-06-30T10:00:00+08:00");
+
+        // NaN 价位照常记录（值为 NULL），同一根 K 线不重复记录
+        assert!(record(f64::NAN, "2025-06-30").unwrap().is_some());
+        assert!(record(f64::NAN, "2025-06-30").unwrap().is_none());
+        assert!(record(10.5, "2025-07-01").unwrap().is_some());
+
+        let values: Vec<Option<f64>> = {
+            let conn = db.conn.lock().unwrap();
+            let mut stmt = conn
+                .prepare("SELECT signal_value FROM indicator_signal ORDER BY bar_date")
+                .unwrap();
+            let rows = stmt.query_map([], |row| row.get(0)).unwrap();
+            rows.collect::<Result<_, _>>().unwrap()
+        };
+        assert_eq!(values, vec![None, Some(10.5)]);
+        drop(db);
+        let _ = std::fs::remove_dir_all(dir);
+    }
+}
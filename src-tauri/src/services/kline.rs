@@ -1,7 +1,9 @@
-use crate::services::market::get_market_code;
+use crate::db::Database;
+use crate::services::kline_store;
+use crate::services::provider::{self, KlineRange};
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
-use std::sync::Mutex;
+use std::sync::{Arc, Mutex};
 use std::time::Instant;
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -15,6 +17,51 @@ pub struct KlineBar {
     pub amount: f64,
 }
 
+/// K 线复权方式
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum AdjustMode {
+    /// 不复权（价格提醒用原始价）
+    None,
+    /// 前复权（默认，与东方财富/通达信默认一致）
+    #[default]
+    Forward,
+    /// 后复权（回测用，历史价格不随除权变化）
+    Backward,
+}
+
+impl AdjustMode {
+    /// 东方财富 fqt 参数: 0=不复权, 1=前复权, 2=后复权
+    pub fn fqt(self) -> u8 {
+        match self {
+            AdjustMode::None => 0,
+            AdjustMode::Forward => 1,
+            AdjustMode::Backward => 2,
+        }
+    }
+
+    pub fn as_str(self) -> &'static str {
+        match self {
+            AdjustMode::None => "none",
+            AdjustMode::Forward => "forward",
+            AdjustMode::Backward => "backward",
+        }
+    }
+
+    /// 解析复权方式，兼容中文写法
+    pub fn parse(s: &str) -> Result<Self, String> {
+        match s.trim().to_lowercase().as_str() {
+            "none" | "不复权" => Ok(AdjustMode::None),
+            "forward" | "前复权" => Ok(AdjustMode::Forward),
+            "backward" | "后复权" => Ok(AdjustMode::Backward),
+            other => Err(format!(
+                "无效的复权方式: {}，请使用 none / forward / backward",
+                other
+            )),
+        }
+    }
+}
+
 struct KlineCache {
     bars: Vec<KlineBar>,
     fetched_at: Instant,
@@ -26,9 +73,44 @@ static CACHE: once_cell::sync::Lazy<Mutex<HashMap<String, KlineCache>>> =
 const CACHE_TTL_SECS: u64 = 300; // 5 分钟
 const MAX_CACHE_ENTRIES: usize = 100;
 
-/// 获取日 K 线数据（带 5 分钟内存缓存）
-pub async fn fetch_daily_klines(symbol: &str, limit: usize) -> Result<Vec<KlineBar>, String> {
-    let cache_key = format!("{}_{}", symbol, limit);
+/// 本地 K 线库，启动时挂载；未挂载时直接请求数据源
+static STORE: once_cell::sync::OnceCell<Arc<Database>> = once_cell::sync::OnceCell::new();
+
+/// 挂载本地 K 线库
+pub fn attach_store(db: Arc<Database>) {
+    let _ = STORE.set(db);
+}
+
+/// 获取日 K 线数据（经本地 K 线库增量同步，未挂载时使用 5 分钟内存缓存，按复权方式区分）
+pub async fn fetch_daily_klines(
+    symbol: &str,
+    limit: usize,
+    adjust: AdjustMode,
+) -> Result<Vec<KlineBar>, String> {
+    fetch_klines(symbol, KlineRange::Latest(limit), adjust).await
+}
+
+/// 获取从 start（YYYY-MM-DD）至今的日 K 线，用于与其他股票的 K 线按日期对齐
+pub async fn fetch_daily_klines_since(
+    symbol: &str,
+    start: &str,
+    adjust: AdjustMode,
+) -> Result<Vec<KlineBar>, String> {
+    fetch_klines(symbol, KlineRange::Since(start.get(..10).unwrap_or(start).to_string()), adjust).await
+}
+
+async fn fetch_klines(symbol: &str, range: KlineRange, adjust: AdjustMode) -> Result<Vec<KlineBar>, String> {
+    let provider = provider::current();
+    // 本地 K 线库自身按 SYNC_INTERVAL_SECS 限制同步频率，不再经过内存缓存
+    if let Some(db) = STORE.get() {
+        return kline_store::fetch(db, provider.as_ref(), symbol, range, adjust, kline_store::SYNC_INTERVAL_SECS).await;
+    }
+
+    // 缓存按数据源区分，切换数据源后不会读到另一数据源的 K 线
+    let cache_key = match &range {
+        KlineRange::Latest(limit) => format!("{}_{}_{}_{}", provider.name(), symbol, limit, adjust.as_str()),
+        KlineRange::Since(start) => format!("{}_{}_since{}_{}", provider.name(), symbol, start, adjust.as_str()),
+    };
 
     // 检查缓存
     {
@@ -40,47 +122,7 @@ pub async fn fetch_daily_klines(symbol: &str, limit: usize) -> Result<Vec<KlineB
         }
     }
 
-    // 缓存未命中，请求东方财富 API
-    let market = get_market_code(symbol);
-    let url = format!(
-        "https://push2his.eastmoney.com/api/qt/stock/kline/get?secid={}.{}&klt=101&fqt=1&end=20500101&lmt={}&fields1=f1,f2,f3,f4,f5,f6&fields2=f51,f52,f53,f54,f55,f56,f57",
-        market, symbol, limit
-    );
-
-    let client = reqwest::Client::new();
-    let resp = client
-        .get(&url)
-        .header("User-Agent", "Mozilla/5.0")
-        .header("Referer", "https://quote.eastmoney.com/")
-        .send()
-        .await
-        .map_err(|e| format!("K线请求失败: {}", e))?;
-
-    let json: serde_json::Value = resp.json().await.map_err(|e| format!("K线解析失败: {}", e))?;
-
-    let klines = json["data"]["klines"]
-        .as_array()
-        .ok_or("K线数据为空")?;
-
-    let bars: Vec<KlineBar> = klines
-        .iter()
-        .filter_map(|v| {
-            let line = v.as_str()?;
-            let parts: Vec<&str> = line.split(',').collect();
-            if parts.len() < 7 {
-                return None;
-            }
-            Some(KlineBar {
-                date: parts[0].to_string(),
-                open: parts[1].parse().unwrap_or(0.0),
-                close: parts[2].parse().unwrap_or(0.0),
-                high: parts[3].parse().unwrap_or(0.0),
-                low: parts[4].parse().unwrap_or(0.0),
-                volume: parts[5].parse().unwrap_or(0.0),
-                amount: parts[6].parse().unwrap_or(0.0),
-            })
-        })
-        .collect();
+    let bars = provider.klines(symbol, range, adjust).await?;
 
     // 写入缓存（LRU 淘汰）
     {
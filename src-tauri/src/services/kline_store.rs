@@ -0,0 +1,426 @@
+//! 本地 K 线库
+//!
+//! K 线按数据源、代码、周期、复权方式存入 SQLite（kline_bar），重启后仍可使用。
+//! 每次只向数据源请求本地最后两根以来的 K 线：倒数第二根用于校验，价格不一致说明
+//! 发生了除权（前复权历史价格整体变化），此时清空重建；最后一根可能是盘中未收盘的 K 线，总是覆盖。
+//! 本地历史不够长时才整段请求；整段数据与本地没有重叠、且从本地最后一根之后开始时，
+//! 无法校验中间是否发生过除权，同样清空重建。数据源不可用时返回本地已有的数据。
+
+use crate::db::Database;
+use crate::services::kline::{AdjustMode, KlineBar};
+use crate::services::provider::{KlineRange, MarketDataProvider};
+
+/// 目前只有日线
+pub const PERIOD_DAY: &str = "day";
+
+/// 两次增量同步的最小间隔（秒）
+pub const SYNC_INTERVAL_SECS: i64 = 60;
+
+struct SeriesKey<'a> {
+    source: &'a str,
+    symbol: &'a str,
+    adjust: &'a str,
+}
+
+/// 本地序列的同步状态
+#[derive(Debug, Default)]
+struct SyncState {
+    /// 本地最后两根 K 线的日期（升序）
+    tail: Vec<String>,
+    count: usize,
+    /// 已取到上市以来的全部历史
+    complete: bool,
+    /// 本地数据覆盖的最早请求日期
+    covered_from: Option<String>,
+    synced_at: Option<chrono::DateTime<chrono::Utc>>,
+}
+
+/// 获取 K 线：先增量同步到本地库，再从本地库读取。max_age_secs 内同步过的不再请求数据源
+pub async fn fetch(
+    db: &Database,
+    provider: &dyn MarketDataProvider,
+    symbol: &str,
+    range: KlineRange,
+    adjust: AdjustMode,
+    max_age_secs: i64,
+) -> Result<Vec<KlineBar>, String> {
+    let key = SeriesKey {
+        source: provider.name(),
+        symbol,
+        adjust: adjust.as_str(),
+    };
+    let state = load_state(db, &key)?;
+
+    let covered = state.complete
+        || match &range {
+            KlineRange::Latest(limit) => state.count >= *limit,
+            KlineRange::Since(start) => state.covered_from.as_deref().is_some_and(|from| from <= start.as_str()),
+        };
+    let fresh = state
+        .synced_at
+        .is_some_and(|t| chrono::Utc::now().signed_duration_since(t).num_seconds() < max_age_secs);
+
+    if !covered {
+        // 本地历史不够：整段请求
+        let bars = provider.klines(symbol, range.clone(), adjust).await;
+        match bars {
+            Ok(bars) => {
+                let complete = matches!(range, KlineRange::Latest(limit) if bars.len() < limit);
+                let covered_from = match &range {
+                    KlineRange::Latest(_) => bars.first().map(|b| b.date.clone()),
+                    KlineRange::Since(start) => Some(start.clone()),
+                };
+                // 本地已有数据时先做一次增量校验，避免新旧复权价格混在一起
+                if state.count > 0 && !consistent(db, &key, &bars)? {
+                    clear(db, &key)?;
+                }
+                save(db, &key, &bars, complete, covered_from.as_deref())?;
+            }
+            Err(e) if state.count > 0 => eprintln!("获取 {} K线失败，使用本地数据: {}", symbol, e),
+            Err(e) => return Err(e),
+        }
+    } else if !fresh {
+        sync_tail(db, provider, &key, &state, adjust).await?;
+    }
+
+    load_bars(db, &key, &range)
+}
+
+/// 增量同步：请求本地倒数第二根以来的 K 线
+async fn sync_tail(
+    db: &Database,
+    provider: &dyn MarketDataProvider,
+    key: &SeriesKey<'_>,
+    state: &SyncState,
+    adjust: AdjustMode,
+) -> Result<(), String> {
+    let Some(from) = state.tail.first() else {
+        return Ok(());
+    };
+    let bars = match provider.klines(key.symbol, KlineRange::Since(from.clone()), adjust).await {
+        Ok(bars) => bars,
+        Err(e) => {
+            eprintln!("同步 {} K线失败，使用本地数据: {}", key.symbol, e);
+            return Ok(());
+        }
+    };
+
+    if consistent(db, key, &bars)? {
+        save(db, key, &bars, state.complete, state.covered_from.as_deref())
+    } else {
+        // 除权导致历史价格变化：按原来的长度（加上新增的 K 线）重新获取
+        let limit = state.count + bars.len();
+        let refetched = provider.klines(key.symbol, KlineRange::Latest(limit), adjust).await?;
+        clear(db, key)?;
+        let complete = refetched.len() < limit;
+        save(db, key, &refetched, complete, refetched.first().map(|b| b.date.as_str()))
+    }
+}
+
+/// 新数据与本地重叠的 K 线（最后一根除外）价格是否一致；
+/// 没有可比较的重叠且新数据从本地最后一根之后开始时视为不一致
+fn consistent(db: &Database, key: &SeriesKey<'_>, bars: &[KlineBar]) -> Result<bool, String> {
+    let conn = db.conn.lock().map_err(|e| e.to_string())?;
+    let last_date: Option<String> = conn
+        .query_row(
+            "SELECT MAX(date) FROM kline_bar WHERE source = ?1 AND symbol = ?2 AND period = ?3 AND adjust = ?4",
+            rusqlite::params![key.source, key.symbol, PERIOD_DAY, key.adjust],
+            |row| row.get(0),
+        )
+        .map_err(|e| e.to_string())?;
+    let mut stmt = conn
+        .prepare(
+            "SELECT close FROM kline_bar WHERE source = ?1 AND symbol = ?2 AND period = ?3 AND adjust = ?4 AND date = ?5",
+        )
+        .map_err(|e| e.to_string())?;
+
+    let mut compared = 0;
+    for bar in bars {
+        if Some(&bar.date) >= last_date.as_ref() {
+            break;
+        }
+        let stored: Option<f64> = stmt
+            .query_row(
+                rusqlite::params![key.source, key.symbol, PERIOD_DAY, key.adjust, bar.date],
+                |row| row.get(0),
+            )
+            .ok();
+        if let Some(close) = stored {
+            if (close - bar.close).abs() > 1e-6 * close.abs().max(1.0) {
+                return Ok(false);
+            }
+            compared += 1;
+        }
+    }
+    let after_tail = match (bars.first(), &last_date) {
+        (Some(first), Some(last)) => first.date > *last,
+        _ => false,
+    };
+    Ok(compared > 0 || !after_tail)
+}
+
+fn load_state(db: &Database, key: &SeriesKey<'_>) -> Result<SyncState, String> {
+    let conn = db.conn.lock().map_err(|e| e.to_string())?;
+    let params = rusqlite::params![key.source, key.symbol, PERIOD_DAY, key.adjust];
+
+    let mut state = conn
+        .query_row(
+            "SELECT complete, covered_from, synced_at FROM kline_sync
+             WHERE source = ?1 AND symbol = ?2 AND period = ?3 AND adjust = ?4",
+            params,
+            |row| {
+                Ok(SyncState {
+                    complete: row.get::<_, i64>(0)? != 0,
+                    covered_from: row.get(1)?,
+                    synced_at: row
+                        .get::<_, String>(2)
+                        .ok()
+                        .and_then(|s| chrono::DateTime::parse_from_rfc3339(&s).ok())
+                        .map(|t| t.with_timezone(&chrono::Utc)),
+                    ..SyncState::default()
+                })
+            },
+        )
+        .unwrap_or_default();
+
+    state.count = conn
+        .query_row(
+            "SELECT COUNT(*) FROM kline_bar WHERE source = ?1 AND symbol = ?2 AND period = ?3 AND adjust = ?4",
+            params,
+            |row| row.get::<_, i64>(0),
+        )
+        .map_err(|e| e.to_string())? as usize;
+
+    let mut stmt = conn
+        .prepare(
+            "SELECT date FROM kline_bar WHERE source = ?1 AND symbol = ?2 AND period = ?3 AND adjust = ?4
+             ORDER BY date DESC LIMIT 2",
+        )
+        .map_err(|e| e.to_string())?;
+    let mut tail = stmt
+        .query_map(params, |row| row.get::<_, String>(0))
+        .map_err(|e| e.to_string())?
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(|e| e.to_string())?;
+    tail.reverse();
+    state.tail = tail;
+    Ok(state)
+}
+
+fn load_bars(db: &Database, key: &SeriesKey<'_>, range: &KlineRange) -> Result<Vec<KlineBar>, String> {
+    let conn = db.conn.lock().map_err(|e| e.to_string())?;
+    let (sql, bound) = match range {
+        KlineRange::Latest(limit) => (
+            "SELECT * FROM (
+                SELECT date, open, close, high, low, volume, amount FROM kline_bar
+                WHERE source = ?1 AND symbol = ?2 AND period = ?3 AND adjust = ?4
+                ORDER BY date DESC LIMIT ?5
+             ) ORDER BY date",
+            rusqlite::types::Value::Integer(*limit as i64),
+        ),
+        KlineRange::Since(start) => (
+            "SELECT date, open, close, high, low, volume, amount FROM kline_bar
+             WHERE source = ?1 AND symbol = ?2 AND period = ?3 AND adjust = ?4 AND date >= ?5
+             ORDER BY date",
+            rusqlite::types::Value::Text(start.clone()),
+        ),
+    };
+    let mut stmt = conn.prepare(sql).map_err(|e| e.to_string())?;
+    let bars = stmt
+        .query_map(
+            rusqlite::params![key.source, key.symbol, PERIOD_DAY, key.adjust, bound],
+            |row| {
+                Ok(KlineBar {
+                    date: row.get(0)?,
+                    open: row.get(1)?,
+                    close: row.get(2)?,
+                    high: row.get(3)?,
+                    low: row.get(4)?,
+                    volume: row.get(5)?,
+                    amount: row.get(6)?,
+                })
+            },
+        )
+        .map_err(|e| e.to_string())?
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(|e| e.to_string())?;
+    Ok(bars)
+}
+
+fn save(
+    db: &Database,
+    key: &SeriesKey<'_>,
+    bars: &[KlineBar],
+    complete: bool,
+    covered_from: Option<&str>,
+) -> Result<(), String> {
+    let mut conn = db.conn.lock().map_err(|e| e.to_string())?;
+    let tx = conn.transaction().map_err(|e| e.to_string())?;
+    {
+        let mut stmt = tx
+            .prepare(
+                "INSERT OR REPLACE INTO kline_bar (source, symbol, period, adjust, date, open, close, high, low, volume, amount)
+                 VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11)",
+            )
+            .map_err(|e| e.to_string())?;
+        for b in bars {
+            stmt.execute(rusqlite::params![
+                key.source, key.symbol, PERIOD_DAY, key.adjust, b.date, b.open, b.close, b.high, b.low, b.volume, b.amount
+            ])
+            .map_err(|e| e.to_string())?;
+        }
+    }
+    tx.execute(
+        "INSERT INTO kline_sync (source, symbol, period, adjust, complete, covered_from, synced_at)
+         VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7)
+         ON CONFLICT (source, symbol, period, adjust) DO UPDATE SET
+            complete = MAX(complete, excluded.complete),
+            covered_from = CASE
+                WHEN covered_from IS NULL OR excluded.covered_from < covered_from THEN excluded.covered_from
+                ELSE covered_from END,
+            synced_at = excluded.synced_at",
+        rusqlite::params![
+            key.source,
+            key.symbol,
+            PERIOD_DAY,
+            key.adjust,
+            complete as i64,
+            covered_from,
+            chrono::Utc::now().to_rfc3339()
+        ],
+    )
+    .map_err(|e| e.to_string())?;
+    tx.commit().map_err(|e| e.to_string())
+}
+
+fn clear(db: &Database, key: &SeriesKey<'_>) -> Result<(), String> {
+    let conn = db.conn.lock().map_err(|e| e.to_string())?;
+    let params = rusqlite::params![key.source, key.symbol, PERIOD_DAY, key.adjust];
+    conn.execute(
+        "DELETE FROM kline_bar WHERE source = ?1 AND symbol = ?2 AND period = ?3 AND adjust = ?4",
+        params,
+    )
+    .map_err(|e| e.to_string())?;
+    conn.execute(
+        "DELETE FROM kline_sync WHERE source = ?1 AND symbol = ?2 AND period = ?3 AND adjust = ?4",
+        params,
+    )
+    .map_err(|e| e.to_string())?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::services::market::{StockQuote, StockSearchResult};
+    use crate::services::provider::ProviderFuture;
+    use std::sync::Mutex;
+
+    /// 记录请求次数、数据可修改的数据源
+    #[derive(Default)]
+    struct MockProvider {
+        bars: Mutex<Vec<KlineBar>>,
+        requests: Mutex<Vec<KlineRange>>,
+    }
+
+    impl MockProvider {
+        fn push(&self, date: &str, close: f64) {
+            self.bars.lock().unwrap().push(KlineBar {
+                date: date.to_string(),
+                open: close,
+                close,
+                high: close,
+                low: close,
+                volume: 1.0,
+                amount: close,
+            });
+        }
+    }
+
+    impl MarketDataProvider for MockProvider {
+        fn name(&self) -> &'static str {
+            "mock"
+        }
+
+        fn quote<'a>(&'a self, _symbol: &'a str) -> ProviderFuture<'a, StockQuote> {
+            Box::pin(async { Err("不支持".to_string()) })
+        }
+
+        fn search<'a>(&'a self, _keyword: &'a str) -> ProviderFuture<'a, Vec<StockSearchResult>> {
+            Box::pin(async { Ok(Vec::new()) })
+        }
+
+        fn klines<'a>(&'a self, _symbol: &'a str, range: KlineRange, _adjust: AdjustMode) -> ProviderFuture<'a, Vec<KlineBar>> {
+            Box::pin(async move {
+                self.requests.lock().unwrap().push(range.clone());
+                let bars = self.bars.lock().unwrap().clone();
+                Ok(match range {
+                    KlineRange::Latest(n) => bars[bars.len().saturating_sub(n)..].to_vec(),
+                    KlineRange::Since(start) => bars.into_iter().filter(|b| b.date >= start).collect(),
+                })
+            })
+        }
+
+        fn limit_stocks<'a>(&'a self, _limit_type: &'a str) -> ProviderFuture<'a, Vec<StockQuote>> {
+            Box::pin(async { Ok(Vec::new()) })
+        }
+    }
+
+    fn closes(bars: &[KlineBar]) -> Vec<f64> {
+        bars.iter().map(|b| b.close).collect()
+    }
+
+    #[tokio::test]
+    async fn test_incremental_sync() {
+        let dir = std::env::temp_dir().join(format!("kline_store_{}", uuid::Uuid::new_v4()));
+        let db = Database::new(dir.clone()).unwrap();
+        let p = MockProvider::default();
+        for (i, d) in ["2025-01-02", "2025-01-03", "2025-01-06", "2025-01-07", "2025-01-08"].iter().enumerate() {
+            p.push(d, 10.0 + i as f64);
+        }
+        let get = |range: KlineRange| fetch(&db, &p, "600000", range, AdjustMode::Forward, 0);
+
+        assert_eq!(closes(&get(KlineRange::Latest(3)).await.unwrap()), vec![12.0, 13.0, 14.0]);
+
+        // 新增一根：只请求倒数第二根以来的数据
+        p.push("2025-01-09", 15.0);
+        assert_eq!(closes(&get(KlineRange::Latest(3)).await.unwrap()), vec![13.0, 14.0, 15.0]);
+        assert_eq!(p.requests.lock().unwrap().last(), Some(&KlineRange::Since("2025-01-07".into())));
+
+        // 本地不足：整段请求，取到的比要求的少说明已是全部历史
+        assert_eq!(get(KlineRange::Latest(10)).await.unwrap().len(), 6);
+        let count = p.requests.lock().unwrap().len();
+        assert_eq!(get(KlineRange::Latest(20)).await.unwrap().len(), 6);
+        assert_eq!(get(KlineRange::Since("2025-01-06".into())).await.unwrap().len(), 4);
+        assert!(p.requests.lock().unwrap()[count..].iter().all(|r| matches!(r, KlineRange::Since(_))));
+
+        // 除权：前复权历史价格整体变化，清空重建
+        {
+            let mut bars = p.bars.lock().unwrap();
+            for b in bars.iter_mut() {
+                b.close /= 2.0;
+            }
+        }
+        p.push("2025-01-10", 8.0);
+        let rebuilt = get(KlineRange::Latest(10)).await.unwrap();
+        assert_eq!(closes(&rebuilt), vec![5.0, 5.5, 6.0, 6.5, 7.0, 7.5, 8.0]);
+
+        // 整段请求的数据从本地最后一根之后开始：中间无法校验，旧数据清空
+        let other = |range: KlineRange| fetch(&db, &p, "600001", range, AdjustMode::Forward, 0);
+        assert_eq!(other(KlineRange::Latest(2)).await.unwrap().len(), 2);
+        for (i, d) in ["2025-01-13", "2025-01-14", "2025-01-15", "2025-01-16"].iter().enumerate() {
+            p.push(d, 20.0 + i as f64);
+        }
+        assert_eq!(closes(&other(KlineRange::Latest(3)).await.unwrap()), vec![21.0, 22.0, 23.0]);
+        let key = SeriesKey {
+            source: "mock",
+            symbol: "600001",
+            adjust: AdjustMode::Forward.as_str(),
+        };
+        let stored = load_bars(&db, &key, &KlineRange::Since("2000-01-01".into())).unwrap();
+        assert_eq!(closes(&stored), vec![21.0, 22.0, 23.0]);
+
+        drop(db);
+        let _ = std::fs::remove_dir_all(dir);
+    }
+}
@@ -0,0 +1,195 @@
+//! 连板天梯
+//!
+//! 涨停股池（收盘封住涨停）和炸板股池（盘中触及涨停后打开）由数据源提供，
+//! 每只股票带连板数、首次/最后封板时间、炸板次数和封单金额。
+//! 按连板数从高到低分组即为当日的连板天梯，同时给出封板数、炸板数和炸板率。
+//! 非交易日和开盘集合竞价结束前取上一个交易日的股池。
+
+use crate::services::kline::KlineBar;
+use crate::services::{calendar, price_limit, provider};
+use chrono::NaiveDate;
+use serde::{Deserialize, Serialize};
+
+/// 涨停股池中的一只股票
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct LimitPoolEntry {
+    pub symbol: String,
+    pub name: String,
+    pub price: f64,
+    pub change_percent: f64,
+    pub limit_up: f64,
+    /// 连板数（含今天），炸板股为冲击的连板高度
+    pub streak: u32,
+    /// 首次封板时间 HH:MM:SS
+    pub first_sealed_at: Option<String>,
+    /// 最后封板时间 HH:MM:SS
+    pub last_sealed_at: Option<String>,
+    /// 炸板（封板后打开）次数
+    pub open_count: u32,
+    /// 封单金额（元），炸板股为 0
+    pub sealed_amount: f64,
+    /// 成交额（元）
+    pub amount: f64,
+    /// 换手率（%）
+    pub turnover_rate: f64,
+    /// 所属行业
+    pub industry: Option<String>,
+    /// sealed = 封板，broken = 炸板未回封
+    pub status: String,
+}
+
+/// 天梯的一级
+#[derive(Debug, Clone, Serialize)]
+pub struct LadderLevel {
+    pub streak: u32,
+    pub stocks: Vec<LimitPoolEntry>,
+}
+
+/// 连板天梯
+#[derive(Debug, Clone, Serialize)]
+pub struct LimitLadder {
+    pub date: String,
+    /// 按连板数从高到低
+    pub levels: Vec<LadderLevel>,
+    /// 炸板未回封的股票
+    pub broken: Vec<LimitPoolEntry>,
+    pub sealed_count: usize,
+    pub broken_count: usize,
+    /// 炸板率（%）= 炸板数 / (封板数 + 炸板数)
+    pub break_rate: f64,
+    /// 最高连板数
+    pub highest_streak: u32,
+}
+
+/// 把股池整理成天梯：同一级内按首次封板时间先后排序
+pub fn build_ladder(date: &str, entries: Vec<LimitPoolEntry>) -> LimitLadder {
+    let (mut sealed, mut broken): (Vec<_>, Vec<_>) = entries.into_iter().partition(|e| e.status == "sealed");
+    sealed.sort_by(|a, b| {
+        b.streak
+            .cmp(&a.streak)
+            .then_with(|| sort_time(&a.first_sealed_at).cmp(sort_time(&b.first_sealed_at)))
+            .then_with(|| a.symbol.cmp(&b.symbol))
+    });
+    broken.sort_by(|a, b| b.change_percent.total_cmp(&a.change_percent));
+
+    let sealed_count = sealed.len();
+    let broken_count = broken.len();
+    let highest_streak = sealed.first().map(|e| e.streak).unwrap_or(0);
+
+    let mut levels: Vec<LadderLevel> = Vec::new();
+    for entry in sealed {
+        match levels.last_mut() {
+            Some(level) if level.streak == entry.streak => level.stocks.push(entry),
+            _ => levels.push(LadderLevel {
+                streak: entry.streak,
+                stocks: vec![entry],
+            }),
+        }
+    }
+
+    let total = sealed_count + broken_count;
+    LimitLadder {
+        date: date.to_string(),
+        levels,
+        broken,
+        sealed_count,
+        broken_count,
+        break_rate: if total > 0 { broken_count as f64 / total as f64 * 100.0 } else { 0.0 },
+        highest_streak,
+    }
+}
+
+/// 没有封板时间的排在最后
+fn sort_time(time: &Option<String>) -> &str {
+    time.as_deref().unwrap_or("99:99:99")
+}
+
+/// 按日 K 线计算截至最后一根的连续涨停天数（收盘价等于涨停价）
+pub fn streak_from_bars(symbol: &str, name: &str, bars: &[KlineBar]) -> u32 {
+    let mut streak = 0;
+    for pair in bars.windows(2).rev() {
+        let today = NaiveDate::parse_from_str(&pair[1].date, "%Y-%m-%d").unwrap_or_else(|_| calendar::today());
+        match price_limit::for_symbol(symbol, name, pair[0].close, today) {
+            Some(limit) if pair[1].close >= limit.limit_up - 1e-6 => streak += 1,
+            _ => break,
+        }
+    }
+    streak
+}
+
+/// 最近一个交易日的涨停股池（含炸板股），非交易日和开盘前为上一个交易日
+pub async fn fetch_pool() -> Result<Vec<LimitPoolEntry>, String> {
+    provider::current().limit_pool().await
+}
+
+/// 最近一个交易日的连板天梯
+pub async fn fetch_ladder() -> Result<LimitLadder, String> {
+    let entries = fetch_pool().await?;
+    Ok(build_ladder(&calendar::latest_session().format("%Y-%m-%d").to_string(), entries))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entry(symbol: &str, streak: u32, first: Option<&str>, status: &str) -> LimitPoolEntry {
+        LimitPoolEntry {
+            symbol: symbol.to_string(),
+            name: String::new(),
+            price: 10.0,
+            change_percent: 10.0,
+            limit_up: 10.0,
+            streak,
+            first_sealed_at: first.map(|t| t.to_string()),
+            last_sealed_at: first.map(|t| t.to_string()),
+            open_count: 0,
+            sealed_amount: 0.0,
+            amount: 0.0,
+            turnover_rate: 0.0,
+            industry: None,
+            status: status.to_string(),
+        }
+    }
+
+    #[test]
+    fn test_build_ladder() {
+        let ladder = build_ladder(
+            "2025-06-30",
+            vec![
+                entry("600001", 1, Some("10:30:00"), "sealed"),
+                entry("600002", 3, Some("09:25:00"), "sealed"),
+                entry("600003", 1, Some("09:31:00"), "sealed"),
+                entry("600004", 2, None, "broken"),
+            ],
+        );
+        assert_eq!(ladder.highest_streak, 3);
+        assert_eq!(ladder.levels.iter().map(|l| l.streak).collect::<Vec<_>>(), vec![3, 1]);
+        let first_board: Vec<&str> = ladder.levels[1].stocks.iter().map(|e| e.symbol.as_str()).collect();
+        assert_eq!(first_board, vec!["600003", "600001"]);
+        assert_eq!((ladder.sealed_count, ladder.broken_count), (3, 1));
+        assert!((ladder.break_rate - 25.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_streak_from_bars() {
+        let bar = |date: &str, close: f64| KlineBar {
+            date: date.to_string(),
+            open: close,
+            close,
+            high: close,
+            low: close,
+            volume: 0.0,
+            amount: 0.0,
+        };
+        // 10.00 → 11.00 → 12.10 → 13.31 连续三个涨停，之前一天不是
+        let bars = vec![
+            bar("2025-06-24", 9.5),
+            bar("2025-06-25", 10.0),
+            bar("2025-06-26", 11.0),
+            bar("2025-06-27", 12.1),
+            bar("2025-06-30", 13.31),
+        ];
+        assert_eq!(streak_from_bars("600001", "", &bars), 3);
+        assert_eq!(streak_from_bars("600001", "", &bars[..2]), 0);
+    }
+}
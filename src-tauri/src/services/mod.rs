@@ -1,4 +1,18 @@
+pub mod align;
+pub mod batch;
+pub mod calendar;
+pub mod indicator_signal;
 pub mod kline;
+pub mod kline_store;
+pub mod limit_ladder;
 pub mod market;
+pub mod order_book;
+pub mod pinyin;
+pub mod price_limit;
+pub mod provider;
+pub mod relative;
 pub mod scheduler;
+pub mod security;
+pub mod symbol_source;
 pub mod tdx;
+pub mod timeline;
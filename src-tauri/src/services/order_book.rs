@@ -0,0 +1,220 @@
+//! 五档盘口和逐笔成交
+//!
+//! 盘口和成交明细由数据源提供，量的单位都是手（100 股）。
+//! 提醒中的两类盘口条件在这里判断：
+//! - 大卖单：连续竞价时段内，最近一分钟出现单笔不少于 N 手的主动卖出成交
+//! - 买一量骤减：同一交易日、同一买一价位上，挂单量比上一次检查减少超过 N%
+//!   （涨停板上即封单撤单或被砸）；买一价位变化后是另一档挂单，不做比较
+
+use crate::services::calendar::{self, MarketPhase};
+use crate::services::provider;
+use chrono::{NaiveDate, NaiveDateTime, Timelike};
+use serde::{Deserialize, Serialize};
+
+/// 默认返回的成交笔数
+pub const DEFAULT_TICK_COUNT: usize = 50;
+/// 成交明细最多返回的笔数
+pub const MAX_TICK_COUNT: usize = 1000;
+/// 大卖单提醒只看最近这么多秒内的成交（调度周期为 10 秒，留出余量）
+pub const BIG_ORDER_WINDOW_SECS: i64 = 60;
+
+/// 一档挂单
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct OrderBookLevel {
+    pub price: f64,
+    /// 手
+    pub volume: f64,
+}
+
+/// 五档盘口
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct OrderBook {
+    pub symbol: String,
+    pub name: String,
+    pub price: f64,
+    pub prev_close: f64,
+    /// 买一到买五
+    pub bids: Vec<OrderBookLevel>,
+    /// 卖一到卖五
+    pub asks: Vec<OrderBookLevel>,
+    pub timestamp: String,
+}
+
+/// 某次检查时的买一档，买一量骤减提醒以此为比较基准
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Bid1Snapshot {
+    /// 没有买盘时为 0
+    pub price: f64,
+    /// 手，没有买盘时为 0
+    pub volume: f64,
+    pub trading_day: NaiveDate,
+}
+
+impl OrderBook {
+    /// 买一挂单量（手），没有买盘时为 0（如跌停无买单）
+    pub fn bid1_volume(&self) -> f64 {
+        self.bids.first().map(|l| l.volume).unwrap_or(0.0)
+    }
+
+    /// 买一档快照
+    pub fn bid1(&self, trading_day: NaiveDate) -> Bid1Snapshot {
+        Bid1Snapshot {
+            price: self.bids.first().map(|l| l.price).unwrap_or(0.0),
+            volume: self.bid1_volume(),
+            trading_day,
+        }
+    }
+
+    /// 委比（%）=（委买量 - 委卖量）/（委买量 + 委卖量）
+    pub fn imbalance(&self) -> f64 {
+        let bid: f64 = self.bids.iter().map(|l| l.volume).sum();
+        let ask: f64 = self.asks.iter().map(|l| l.volume).sum();
+        if bid + ask > 0.0 {
+            (bid - ask) / (bid + ask) * 100.0
+        } else {
+            0.0
+        }
+    }
+}
+
+/// 一笔（分笔）成交
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TradeTick {
+    /// HH:MM:SS
+    pub time: String,
+    pub price: f64,
+    /// 手
+    pub volume: f64,
+    /// buy = 主动买入，sell = 主动卖出，neutral = 集合竞价等无方向成交
+    pub side: String,
+}
+
+/// 五档盘口
+pub async fn fetch_order_book(symbol: &str) -> Result<OrderBook, String> {
+    provider::current().order_book(symbol).await
+}
+
+/// 最近 count 笔成交，按时间先后排列
+pub async fn fetch_ticks(symbol: &str, count: usize) -> Result<Vec<TradeTick>, String> {
+    let count = count.clamp(1, MAX_TICK_COUNT);
+    provider::current().ticks(symbol, count).await
+}
+
+fn seconds_of(time: &str) -> Option<i64> {
+    let mut parts = time.split(':').map(|p| p.parse::<i64>().ok());
+    let (h, m, s) = (parts.next()??, parts.next()??, parts.next().flatten().unwrap_or(0));
+    Some(h * 3600 + m * 60 + s)
+}
+
+/// now 之前 BIG_ORDER_WINDOW_SECS 秒内不少于 min_volume 手的最大主动卖单。
+/// 只在连续竞价时段判断，避免把上一交易日或停牌前的成交当作新的大单
+pub fn big_sell_order(ticks: &[TradeTick], min_volume: f64, now: NaiveDateTime) -> Option<&TradeTick> {
+    if calendar::phase_at(now) != MarketPhase::Continuous {
+        return None;
+    }
+    let now = now.num_seconds_from_midnight() as i64;
+    ticks
+        .iter()
+        .filter(|t| t.side == "sell" && t.volume >= min_volume)
+        .filter(|t| seconds_of(&t.time).is_some_and(|s| (0..=BIG_ORDER_WINDOW_SECS).contains(&(now - s))))
+        .max_by(|a, b| a.volume.total_cmp(&b.volume))
+}
+
+/// 买一量相对上一次减少的百分比。跨交易日、买一价位变化或上一次没有买一量时为 None；
+/// 买盘被全部撤空时按减少 100% 计
+pub fn bid1_drop_percent(previous: &Bid1Snapshot, current: &Bid1Snapshot) -> Option<f64> {
+    let same_level = (previous.price - current.price).abs() < 1e-6 || current.volume <= 0.0;
+    if previous.trading_day != current.trading_day || !same_level || previous.volume <= 0.0 {
+        return None;
+    }
+    Some(((previous.volume - current.volume) / previous.volume * 100.0).max(0.0))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn tick(time: &str, volume: f64, side: &str) -> TradeTick {
+        TradeTick {
+            time: time.to_string(),
+            price: 10.0,
+            volume,
+            side: side.to_string(),
+        }
+    }
+
+    fn at(s: &str) -> NaiveDateTime {
+        NaiveDateTime::parse_from_str(s, "%Y-%m-%d %H:%M:%S").unwrap()
+    }
+
+    #[test]
+    fn test_big_sell_order() {
+        let ticks = vec![
+            tick("10:00:00", 5000.0, "sell"),
+            tick("10:05:10", 800.0, "sell"),
+            tick("10:05:20", 3000.0, "buy"),
+            tick("10:05:30", 1200.0, "sell"),
+            tick("10:05:40", 10.0, "neutral"),
+        ];
+        let now = at("2025-06-30 10:05:45");
+        // 10:00:00 的大单已超出一分钟窗口，买单不算
+        assert_eq!(big_sell_order(&ticks, 1000.0, now).map(|t| t.time.as_str()), Some("10:05:30"));
+        assert!(big_sell_order(&ticks, 2000.0, now).is_none());
+        assert!(big_sell_order(&[], 1.0, now).is_none());
+        // 窗口从现在算起：停牌后不再有新成交，旧的大单不会重复触发
+        assert!(big_sell_order(&ticks, 1000.0, at("2025-06-30 10:30:00")).is_none());
+        // 上一交易日收盘前的大单在次日开盘时不触发，集合竞价和休市时段不判断
+        let late = vec![tick("14:56:50", 5000.0, "sell")];
+        assert!(big_sell_order(&late, 1000.0, at("2025-06-30 14:56:55")).is_some());
+        assert!(big_sell_order(&late, 1000.0, at("2025-07-01 09:30:10")).is_none());
+        assert!(big_sell_order(&late, 1000.0, at("2025-06-30 14:57:10")).is_none());
+        assert!(big_sell_order(&late, 1000.0, at("2025-06-29 14:56:55")).is_none());
+    }
+
+    #[test]
+    fn test_bid1_drop() {
+        let day = NaiveDate::from_ymd_opt(2025, 6, 30).unwrap();
+        let snapshot = |price, volume, trading_day| Bid1Snapshot { price, volume, trading_day };
+        let previous = snapshot(11.0, 10000.0, day);
+        assert_eq!(bid1_drop_percent(&previous, &snapshot(11.0, 3000.0, day)), Some(70.0));
+        assert_eq!(bid1_drop_percent(&previous, &snapshot(11.0, 12000.0, day)), Some(0.0));
+        assert_eq!(bid1_drop_percent(&snapshot(11.0, 0.0, day), &snapshot(11.0, 100.0, day)), None);
+        // 买盘被全部撤空
+        assert_eq!(bid1_drop_percent(&previous, &snapshot(0.0, 0.0, day)), Some(100.0));
+    }
+
+    #[test]
+    fn test_bid1_drop_price_level_change() {
+        let day = NaiveDate::from_ymd_opt(2025, 6, 30).unwrap();
+        let previous = Bid1Snapshot { price: 11.0, volume: 10000.0, trading_day: day };
+        // 买一从 11.00 变为 10.99，是另一档挂单，不比较
+        let current = Bid1Snapshot { price: 10.99, volume: 200.0, trading_day: day };
+        assert_eq!(bid1_drop_percent(&previous, &current), None);
+    }
+
+    #[test]
+    fn test_bid1_drop_day_rollover() {
+        let day = NaiveDate::from_ymd_opt(2025, 6, 30).unwrap();
+        let previous = Bid1Snapshot { price: 11.0, volume: 10000.0, trading_day: day };
+        // 同一价位但已是下一个交易日
+        let next = Bid1Snapshot { trading_day: NaiveDate::from_ymd_opt(2025, 7, 1).unwrap(), volume: 100.0, ..previous };
+        assert_eq!(bid1_drop_percent(&previous, &next), None);
+    }
+
+    #[test]
+    fn test_bid1_and_imbalance() {
+        let level = |price, volume| OrderBookLevel { price, volume };
+        let book = OrderBook {
+            symbol: "600001".into(),
+            name: String::new(),
+            price: 11.0,
+            prev_close: 10.0,
+            bids: vec![level(11.0, 300.0)],
+            asks: vec![level(11.01, 100.0)],
+            timestamp: String::new(),
+        };
+        let day = NaiveDate::from_ymd_opt(2025, 6, 30).unwrap();
+        assert_eq!(book.bid1(day), Bid1Snapshot { price: 11.0, volume: 300.0, trading_day: day });
+        assert_eq!(book.imbalance(), 50.0);
+    }
+}
@@ -0,0 +1,684 @@
+//! 汉字拼音首字母
+//!
+//! 覆盖 CJK 统一汉字基本区（U+4E00–U+9FFF，含繁体，按拼音排序），多音字取常用读法，
+//! 证券简称中常见的多音字按证券行业读法修正（银行 → YH，重庆 → CQ，晟 → S）。
+//! 扩展区汉字及其他字符没有首字母，生成时跳过。
+
+use std::collections::HashMap;
+
+/// 首字母及其汉字
+const TABLE: &[(char, &str)] = &[
+    (
+        'A',
+        concat!(
+            "吖腌錒锕阿嗄啊哀哎唉埃娭挨捱欸诶銰鎄锿凒厓啀嘊娾嵦敱敳癌皑皚騃佁嗳噯昹毐矮蔼藹躷霭靄",
+            "馤伌僾叆呝嗌堨塧嫒嬡愛懓懝暧曖濭爱瑷璦皧瞹砹硋碍礙艾薆譪賹鑀隘靉鱫鴱侒厈媕安峖広庵桉",
+            "氨痷盦盫腤萻葊蓭誝諳谙阥鞌鞍韽鵪鶕鹌啽玵雸俺唵垵埯堷揞晻罯銨铵隌儑匎匼堓婩岸按暗案洝",
+            "犴胺荌菴豻貋錌闇鮟黬黯肮骯卬岇昂昻軮枊盎醠凹坳垇柪梎熬軪厫嗷嗸囂嶅廒摮敖滶爊獒獓璈磝",
+            "簢翱翶翺聱蔜螯謷謸遨鏖隞骜鰲鳌鷔鼇媪媼抝芺袄襖镺鴁傲墺奡奥奧嫯岙嶴慠懊扷擙澚澳詏鏊驁",
+        ),
+    ),
+    (
+        'B',
+        concat!(
+            "仈八叭吧哵夿岜巴扒捌朳玐疤笆粑紦羓芭豝釟魞鲃叐坺墢妭抜拔炦犮秡笩胈茇茷菝詙跋軷鈸钹颰",
+            "馛魃鼥把欛鈀钯靶坝垻壩弝灞爸猈罢罷耙覇霸鮁鮊鲅鲌杷掰白佰兡捭摆擺栢百矲粨絔襬呗唄庍拜",
+            "拝敗稗粺薭蛽贁败鞁扳搬攽斑斒朌班瘢癍肦般虨螌褩辬頒颁坂岅昄板版瓪粄舨蝂鈑钣闆阪伴办半",
+            "姅怑扮拌柈湴瓣秚絆绊辦鉡靽垹帮幇幚幫捠梆浜縍邦邫鞤挷搒榜氆牓綁绑膀髈傍塝棒棓磅稖艕蒡",
+            "蚌蜯謗谤鎊镑剥勹包孢枹煲笣胞苞蕔褒襃闁齙龅嫑瓝窇薄雹保堡堢媬宝宲寚寳寶怉珤緥葆褓賲靌",
+            "飽饱駂鳵鴇鸨儤勽嚗報忁报抱暴曓爆犦菢虣蚫袌豹趵鉋鑤铇骲鮑鲍伓俾偝卑埤悲揹杯桮椑盃碑禆",
+            "綼萆藣裨錃陂鵯鹎北鉳俻倍偹備僃哱备奰孛悖惫愂憊昁杮梖焙牬犕狈狽珼琲碚糒紴背蓓被褙誖貝",
+            "贝軰輩辈邶郥鄁鋇鐴鐾钡鞴韛倴奔栟泍渀犇賁贲逩錛锛夲奙本楍畚苯坌捹撪桳燌獖笨輽伻嘣塴奟",
+            "崩嵭弸祊絣綳繃绷閍甭埲玤琫菶誁鞛堋槰泵洴熢甏跰蹦迸逬鏰镚偪屄悂毴皀稫螕豍逼鲾荸鼻佊匕",
+            "吡啚夶妣彼朼柀比沘疕秕笔筆粃纰舭貏鄙魮佖哔嗶坒堛壁妼婢嫳嬖币幣幤庇庳廦弊弻弼彃必怭愊",
+            "愎払敝斃朇枈柫柲梐楅檘毕毖毙湢滗滭潷濞煏熚狴獘獙珌璧畀畁畐畢疪痹痺皕碧筚箄箅箆篦篳粊",
+            "縪罼聛腷臂芘苾荜蓖蓽蔽薜蜌袐襒襞襣觱詖诐貱贔赑跸蹕躃躄辟避邲鄨鄪鉍鎞鏎铋閇閉閟闢闭陛",
+            "鞸韠飶饆馝駜驆骳髀魓鮅鰏鵖鷝鷩鼊柉楄煸牑猵獱甂砭稨笾箯籩糄編编蝙边辺邉邊鍽鞭鯾鯿鳊匾",
+            "惼扁碥窆萹藊褊貶贬便卞变変弁徧忭抃揙昪汳汴炞玣緶缏艑苄覍變辡辧辨辩辫辮辯遍釆閞鴘僄儦",
+            "墂幖彪徱摽杓标標滮瀌灬熛爂猋瘭磦穮羆脿膘臕蔈藨謤贆鏢鑣镖镳颩颮颷飆飇飈飍飑飙飚驃驫骉",
+            "骠髟麃婊檦表裱褾諘錶俵鰾鳔憋瘪癟虌鱉鳖鼈龞別别咇徶莂蟞蹩蛂彆傧儐宾彬斌梹椕槟檳汃滨濒",
+            "濱濵濹瀕玢瑸璸矉穦繽缤蠙豩豳賓賔邠鑌镔霦顮馪驞摈擯殡殯膑臏髌髕髩鬂鬓鬢鶣仌傡兵冫冰掤",
+            "氷竝丙庰廪怲抦昞昺柄棅炳琕癝眪禀秉稟窉苪蛃邴鈵鉼陃鞞餅餠饼並併倂偋寎并幷摒栤病靐鮩鵧",
+            "剝啵岥嶓彂拨撥播柭波玻癶盋砵碆缽菠袚襏蹳鉢钵餑饽驋髉鱍亳伯侼僰勃博嶏帛愽懪挬搏敀柏桲",
+            "檗欂泊泺浡渤煿牔犻猼礡礴箔簙糪胉脖膊舶艊苩葧袯襮謈踄踣郣鉑鋍鎛鑮铂镈餺馎馞駁駮驳髆鵓",
+            "鸔鹁箥簸蚾跛駊孹挀擗擘疈繴蘗譒卜噃蔔峬庯晡逋鈽钸餔鵏不醭卟哺捕獛补補鳪佈勏咘埗埠布怖",
+            "悑捗步歨歩瓿篰簿荹蔀部郶鈈钚餢",
+        ),
+    ),
+    (
+        'C',
+        concat!(
+            "傪嚓擦礤囃偲猜睵才材溨纔裁財财倸婇寀彩採棌睬綵跴踩采埰縩菜蔡参參叄叅喰歺湌蓡謲飡餐驂",
+            "骖鯵鰺嬠嬱惭慙慚残殘蚕蝅蠶蠺噆惨慘憯朁黪黲孱摻澯灿燦璨粲薒仓仺伧倉傖凔嵢沧滄舱艙苍蒼",
+            "螥鶬鸧匨蔵藏鑶賶喿撡操糙鄵嘈嶆曹曺槽漕艚蓸螬褿鏪愺懆艸艹草騲肏襙侧側冊册厕厠墄嫧廁恻",
+            "惻憡拺敇测測畟矠笧策筞筴箣粣茦荝萗蓛遪嵾梫埁岑梣橬涔硶笒噌层層嶒曽碀竲缯鄫蹭偛叉嗏嫅",
+            "扠挿插揷杈疀肞艖銟鍤鎈锸餷馇垞察嵖搽查査楂槎檫猹碴秅臿茬茶詧靫衩蹅鑔镲仛侘剎奼姹岔差",
+            "汊紁訍詫诧偨拆芆釵钗侪儕喍柴犲祡豺茝囆瘥虿蠆袃嬓惉掺搀攙梴欃脠袩襜覘觇辿鋓僝劖単單嚵",
+            "壥婵嬋巉廛棎毚湹潹潺澶瀍瀺煘獑磛禅禪緾繵纏纒缠艬蝉蟬蟾誗讒谗躔鄽酁鋋鐔鑱镡镵饞馋丳产",
+            "冁刬剗剷啴嘽囅嵼幝摌旵浐滻灛燀產産簅繟蒇蕆諂譂讇谄醦鏟铲閳闡阐骣儳忏懴懺硟羼韂顫伥倀",
+            "娼昌晿淐猖琩菖裮錩锠閶阊鯧鲳鼚仧偿償兏嘗嚐場塲嫦尝常徜瑺瓺甞粻肠腸膓苌萇鋿鏛長镸长鱨",
+            "鲿僘厂厰场廠惝敞昶氅鋹倡唱怅悵暢焻玚畅畼誯韔鬯勦弨怊抄摷欩绰罺訬超鈔钞嘲巢巣晁樔潮濤",
+            "謿轈鄛鼂鼌吵巐炒煼眧禉麨仦仯耖觘伡俥唓砗硨莗蛼車车偖奲扯撦勶坼屮彻徹掣撤澈烲爡瞮硩聅",
+            "轍迠頙嗔堔抻搷棽琛瞋胂諃謓賝郴伔塵宸尘屒忱愖敐敶晨曟樄沉煁臣茞莀莐蔯薼螴訦諶谌軙辰迧",
+            "鈂陈陳霃鷐麎墋捵碜磣裖贂趻踸鍖儭嚫夦榇櫬疢称稱穪藽衬襯讖谶趁趂齓齔龀偁埥崝憆摚撐撑柽",
+            "棦橕檉泟浾湞爯牚牜琤瞠竀緽蛏蟶赪赬鏿鐺铛阷靗頳饓丞乗乘呈城埕堘塍塖娍宬峸惩憕懲成承挰",
+            "掁揨晟晠枨棖椉橙洆浈澂澄瀓珵珹畻程窚筬絾脀脭荿裎誠诚郕酲鋮铖騬侱塣庱徎悜睈逞騁骋秤吃",
+            "哧喫嗤噄妛媸彨彲摛瓻痴癡眵瞝笞絺蚩螭訵誺貾郗魑鴟鵄鸱麶黐齝齹俿匙坻墀岻弛徥徲忯持栘歭",
+            "池汦沱沲痄竾筂箈箎篪耛茌荎蚔蚳謘赿趍踟迟遅遟遲馳驰伬侈傂叺呎垑姼尺恀恥扡拸搋欼歯粎耻",
+            "蚇袳裭褫誃豉鉹鶒齒齿侙傺勅勑叱啻彳恜慗憏懘戠抶摰敕斥杘栻淔灻炽烾熾痓痸瘛眙翄翅翤腟赤",
+            "趩跮遫鉓銐飭饬鵣鷘傭充冲嘃徸忡憧摏沖浺湧珫祌翀舂艟茺衝蹖崇崈漴痋虫蝩蟲褈隀埫宠寵揰銃",
+            "铳婤抽搊犨犫瘳篘醔仇俦儔嚋嬦帱幬惆愁懤栦椆燽畴疇皗稠筹籌紬絒綢绸菗薵裯詶讎讐踌躊酧酬",
+            "醻雔雠鯈丑丒侴吜杻杽瞅矁莥醜魗憱殠溴臭臰出初岀樗貙齣儲刍厨墸幮廚恹懨橱櫉櫥滁犓篨耝耡",
+            "芻蒢蒭蕏藸蜍蠩趎跦蹰躇躕鉏鋤锄除雏雛鯺鶵储処憷杵椘楚楮檚濋础礎處褚齭齼亍俶傗儊嘼处怵",
+            "拀搐敊斶欪歜泏滀琡矗竌竐絀绌臅荲触觸詘豖踀遚鄐閦黜欻揣膗嘬踹巛川氚瑏穿鐉传傳暷椽歂篅",
+            "舡舩船諯輲遄僢喘堾舛荈蝽踳串汌玔賗釧钏刅刱剏創囪憃戧摐牎牕疮瘡窓窗窻噇幢床橦牀疒搶摤",
+            "漺闖闯创剙怆愴獊吹炊龡倕圌垂埀娷捶搥桘棰槌湷甀箠腄菙錘鎚锤陲顀諈媋旾春暙杶椿櫄焞瑃箺",
+            "膥萅輴鰆鶞唇憌浱淳湻滣漘純纯脣莼蒓蓴醇醕錞陙鯙鶉鹑偆惷睶萶蠢戳踔吷啜嚽娖婥婼惙擉歠涰",
+            "珿畷磭綽繛腏諁趠輟辍辵辶逴酫醊鑡齪齱龊呲庛疵赼趀骴垐嬨慈枱柌濨珁瓷甆磁礠祠粢糍茈茨薋",
+            "詞词辝辞辤辭雌飺餈鷀鹚佌此泚玼皉跐佽刺刾朿栨次絘莿蚝蛓螅螆賜赐匆囱從忩怱悤暰枞棇樅樬",
+            "漗焧熜燪瑽璁瞛篵総緫繱聡聦聪聰苁葱蓯蔥蟌鍃鍯鏓鏦騘驄骢丛从叢婃孮従徖悰慒樷淙漎潀灇爜",
+            "琮誴賨賩錝欉藂謥凑楱湊腠輳辏粗觕麁麄麤徂殂豠促噈梀殧猝瘄瘯簇縬脨蔟趗趨踧蹙蹴酢醋鏃鼀",
+            "撺攛汆蹿躥鋑鑹镩劗巑攅攢櫕濽灒殩熶爨窜竄篡篹簒催嗺墔崔摧榱槯獕磪缞鏙隹凗慛漼璀皠趡伜",
+            "倅啐啛忰悴毳淬濢焠琗疩瘁竁粋粹紣綷翆翠脃脆脺膬膵臎萃襊顇村澊皴竴邨存袸刌忖吋寸籿搓撮",
+            "磋蒫蹉遳髊嵯嵳痤睉矬蔖虘酂酇醝鹺鹾瑳硰縒脞侳剉剒厝挫措歵莝莡蓌逪銼錯锉错齰颤朝",
+        ),
+    ),
+    (
+        'D',
+        concat!(
+            "咑哒嗒噠墶搭撘答耷褡剳匒呾妲怛沓溚炟畗畣瘩笪箚繨荅荙薘蟽褟詚达逹達鎝鐽闒阘靼鞑韃打大",
+            "眔垯墖疸跶呆呔嘚懛獃傣歹逮代叇埭岱帒带帯帶廗待忕怠戴曃柋殆汏瀻玳瑇甙簤紿緿绐艜袋襶貸",
+            "贷跢蹛軑軚軩轪迨酨霴靆骀黛黱丹儋勯匰单噡嚪妉媅擔殚殫甔瘅癉眈砃箪簞耼耽聃聸襌躭郸鄲酖",
+            "頕丼亶刐掸撢撣澸玬瓭紞胆膽衴黵但僤啖啗啿嘾噉帎幨弾彈惮憚憺担旦暺柦氮沊泹淡潬澹灗狚疍",
+            "癚禫窞腅膻舕萏蛋蜑觛誕诞钽霮饏馾駳髧鴠儅噹嵣当澢珰璫當筜簹艡蟷裆襠党挡擋攩檔欓灙譡讜",
+            "谠黨凼圵垱壋宕愓档氹璗瓽盪瞊砀碭礑簜荡菪蕩蘯趤逿闣雼刀刂叨啁幍忉朷氘舠釖魛鱽捯倒壔导",
+            "導岛島嶋嶌嶹捣搗擣梼祷禂禱蹈隝隯到噵悼檤焘燾瓙盗盜稲稻纛翿菿衜衟軇道得徳德恴悳惪棏淂",
+            "鍀锝的哋噔嬁灯燈璒登竳簦覴豋蹬鐙镫戥等僜凳墱嶝櫈瞪磴邆邓鄧隥霯仾低嘀埞堤墑奃彽氐滴眡",
+            "磾羝袛趆鍉鏑镝隄鞮仢唙嚁嫡廸敌敵梑涤滌潪犒狄笛篴籴糴翟苖荻莜蔋蔐藋藡覿觌豴蹢迪靮頔鸐",
+            "呧坘底弤抵拞掋柢牴砥聜茋菧觝詆诋軧邸阺骶偙僀啇啲地坔埊墆娣嵽嶳帝弟怟慸摕旳杕梊棣楴樀",
+            "渧焍玓珶甋睇碲祶禘第締缔肑腣菂蒂蔕虳螮諦谛踶递逓遞釱鉪馰嗲傎厧嵮巅巓巔掂攧敁槇槙滇瘨",
+            "癫癲蹎顚顛颠齻典嚸奌婰敟点琠碘蕇踮錪點佃坫垫墊壂奠婝店惦扂橂殿淀澱玷琔电甸痁癜磹簟蜔",
+            "鈿钿阽電靛凋刁叼奝弴彫扚殦汈琱瞗矵碉虭蛁貂雕鮉鯛鲷鳭鵰鼦屌伄吊弔掉瘹窎窵竨蓧訋誂調调",
+            "釣鈟銚銱鋽鑃钓铞铫魡爹褺跌叠咥喋垤堞峌嶀恎惵戜挕揲昳曡楪氎牃牒瓞畳疂疉疊眣眰碟絰绖耊",
+            "耋聑胅臷艓苵蜨蝶褋詄諜谍趃跕蹀迭镻鰈鲽鳎丁仃叮奵帄玎町甼疔盯耵虰酊釘钉靪嵿檙濎薡鐤頂",
+            "顶鼎鼑啶娗定忊掟椗矴碇碠磸腚萣訂订鋌錠铤锭顁飣饤丟丢乣銩铥东倲冬咚埬娻岽崠崬昸東氡氭",
+            "涷炵笗苳菄蝀鯟鶇鸫鼕墥嬞懂箽董諌侗働冻凍动動垌峒峝恫戙挏栋棟洞硐胨胴腖霘駧兜兠吺橷篼",
+            "蔸郖都唗唞抖敨斗枓枡蚪闘阧陡鬥鬦鬪鬭斣梪毭浢痘窦竇脰荳読讀豆逗鋀閗餖饾鬬厾嘟督醏闍阇",
+            "儥凟匵嬻椟櫝殰毒涜渎瀆牍牘犊犢独獨瓄皾蝳讟读豄贕鑟韇韥頓騳髑黩黷堵帾暏琽睹笃篤裻覩賭",
+            "赌錖喥妒妬度斁杜槖橐殬渡秺簵肚芏荰螙蠧蠹鍍镀靯偳剬媏端耑褍鍴短塅断斷椴段毈煅瑖碫簖籪",
+            "緞缎腶葮躖鍛锻垖堆塠嵟痽磓頧兊兌兑对対對怼憝憞懟濧濻瀩碓綐薱譈轛鐓鐜镦队陮隊吨噸墩墪",
+            "惇撉撴敦犜礅蜳蹲蹾驐盹趸躉伅囤坉崸庉扽楯沌潡炖燉盾砘腞踲逇遁遯鈍钝顿鶨剟咄哆嚉多夛崜",
+            "掇茤裰凙剫夺奪敓敚敠敪椯毲痥莌襗踱鈬鐸铎鮵亸哚嚲垛垜埵挅挆朵朶桗痑綞缍趓躱躲軃鬌刴剁",
+            "堕墮墯尮嶞惰憜柁柮炧炨舵袉貀跥跺陊陏飿饳馱駄鵽弹",
+        ),
+    ),
+    (
+        'E',
+        concat!(
+            "妸妿娿婀屙峉痾钶俄吪囮娥峨峩涐珴皒睋硪磀莪蛾訛誐譌讹迗鈋鋨锇頟額额騀魤鵝鵞鹅悪惡桠椏",
+            "偔僫卾厄呃咢咹唖啞噩圔垩垭埡堊堮屵岋崿嶭愕戹扼搤搹枙櫮湂琧砐砨硆腭苊萼蕚蘁蚅蝁覨詻諤",
+            "讍谔豟貖軛軶轭遌遏鄂鍔鑩锷閼阏阨阸頞顎颚餓餩饿魥鰐鱷鳄鶚鹗齶誒奀恩煾蒽摁糦饎鞥侕儿児",
+            "兒唲栭檽洏而耏聏胹臑荋袻輀轜陑隭髵鮞鲕鴯鸸尒尓尔峏栮洱爾珥耳薾迩邇鉺铒餌饵駬二佴刵咡",
+            "弍弐樲毦眲衈誀貮貳贰恶",
+        ),
+    ),
+    (
+        'F',
+        concat!(
+            "发橃発發瞂酦醗醱乏伐傠垡姂栰疺筏罚罰罸藅閥阀佱法灋砝珐琺蕟髪髮僠嬏帆幡旙旛畨番籓繙翻",
+            "蕃藩轓飜鱕凡凢凣勫匥墦忛憣杋棥樊橎瀪瀿烦煩燔璠矾礬笲籵緐繁羳膰舤舧薠蘩蠜襎蹯釩鐇钒颿",
+            "鷭反返魬奿嬎嬔梵氾汎泛滼犯畈盕笵範范訉販贩軓軬鄤飯飰饭匚坊方枋汸淓牥芳蚄邡鈁钫鴋埅妨",
+            "房肪防魴鰟鲂鳑仿倣彷旊昉昘瓬眆紡纺舫訪访髣鶭放趽啡妃婓扉渄猆緋绯菲蜚裶霏非靟飛飝飞馡",
+            "騑騛鯡鲱淝肥腓萉蜰匪奜悱斐朏棐榧篚翡蕜誹诽餥俷剕厞吠屝废廃廢怫昲曊櫠沸濷狒疿痱癈砩肺",
+            "胇芾蟦費费鐨镄靅鼣兝分吩岎帉昐朆梤棻氛砏紛纷翂芬衯訜酚鈖隫雰餴饙鳻坆坟墳妢幩弅枌棼汾",
+            "濆炃焚燓羒蒶蕡蚠蚡豮豶轒鐼馚馩黂鼖鼢粉黺份偾僨坋奋奮忿愤憤橨瀵秎粪糞羵膹魵鱝鲼丰仹偑",
+            "僼凨凬凮妦寷封峯峰崶捀枫桻楓檒沣沨灃烽犎猦疯瘋盽砜碸篈莑葑蘴蜂蠭諷豊豐鄷酆鋒鏠锋靊風",
+            "飌风麷冯堸夆摓浲渢漨綘縫缝艂逢馮唪覂讽俸凤奉湗焨煈甮賵赗鳯鳳鴌覅仏佛坲髴剻哹紑罘芣裦",
+            "否殕缶缹缻雬鴀伕呋夫妋姇娐孵尃怤懯敷旉柎泭玞璷砆稃筟箙粰糐紨綒罦肤膚荴衭豧趺跗邞鄜鈇",
+            "鳺麩麬麱麸乀伏俘凫刜匐咈垘孚岪巿帗幅幞弗彿扶拂服枎栿桴棴氟沷洑浮涪澓炥烰玸琈甶畉癁祓",
+            "福符笰紱紼絥綍縛绂绋翇艀艴芙苻茀茯莩菔葍虙蚨蜉蝠袱諨踾輻辐郛鉘鉜錇韍韨颫鮄鳧鳬鴔鵩鶝",
+            "黻乶俌俯冹呒嘸府弣抚拊捬撫斧暊滏焤甫盙秿簠脯腐腑蚥蜅軵輔辅郙釜釡頫鬴鯆黼付偩傅冨副咐",
+            "坿复妇婏婦媍富峊復椱榑父祔稪竎緮缚胕腹萯蕧蚹蛗蝜蝮袝複褔覆訃詂讣負賦賻负赋赙赴輹鍑鍢",
+            "阜阝附陚馥駙驸鮒鰒鲋鳆",
+        ),
+    ),
+    (
+        'G',
+        concat!(
+            "伽呷嘎嘠旮噶尜軋釓錷钆尕玍尬侅垓姟峐晐畡祴絯胲荄該该豥賅賌赅郂陔隑忋改絠丐乢匃匄屲戤",
+            "摡概槩槪溉漑瓂盖葢蓋鈣钙乹亁凲咁坩尲尴尶尷嵅干幹忓攼杆柑泔浛玕甘疳矸竿筸粓肝芉苷虷蜬",
+            "迀酐飦鳱仠感擀敢桿橄澉皯盰秆稈衦贑赶趕鱤鳡倝凎旰榦檊汵涻淦灨簳紺绀詌贛赣骭冈冮刚剛堈",
+            "堽岗岡崗掆杠棡牨犅疘碙笐綱纲缸罁罓罡肛釭鋼鎠钢颃塂港戅戆戇槓焵筻槔槹橰櫜滜皋皐睪睾篙",
+            "糕羔羙膏臯韟餻高髙鷱鼛夰搞攪暠杲槁檺稁稾稿筶縞缟菒藁藳鎬镐吿告煰祮祰禞誥诰郜鋯锆仡割",
+            "哥圪戈戓戨搁擱歌渮滒牫疙紇纥肐胳袼謌鎶鴚鴿鸽麧仮佮匌呄嗝嘅塥愅挌搿敆敋晄格槅獦膈臵茖",
+            "葛蛒蛤裓觡諽輵轕郃鎘铬镉閣閤阁隔革鞷韐韚颌骼髂鬲鮯鰪齃哿擖笴舸騔魺鲄个亇個各嗰箇虼鉻",
+            "給给刯根跟哏艮亘亙茛庚揯搄浭畊絙絚緪縆羮羹耕菮賡赓鶊鹒哽埂峺挭梗綆绠耿莄郠頚頸骾鯁鲠",
+            "堩恆暅更供公功匑厷塨宫宮工幊弓恭愩攻杛碽篢糼肱蚣觥觵躬躳髸龏龔龚巩廾拱拲栱汞珙礦穬蛬",
+            "銾鋛鞏鞐共唝摃羾貢贡佝勾枸沟泃溝篝緱缑芶褠鈎鉤钩鞲岣狗玽笱耇耈耉苟茍茩蚼豿傋冓唦坸垢",
+            "够夠姤媾彀搆撀构構煹瞉簼覯觏訽詬诟購购遘雊韝估呱咕哌嗗夃姑嫴孤柧橭沽泒痼笟箍箛罛苽菇",
+            "菰蓇蛄觚軱軲轂轱辜酤鈲鈷钴鮕鴣鸪鶻古唂唃嘏尳愲扢杚榖毂汩淈濲瀔焸牯狜皷皼盬瞽穀糓縎罟",
+            "羖股脵臌薣蛊蛌蠱詁诂谷賈餶馉骨鵠鹄鹘鼓鼔僱凅固堌崓崮故梏棝榾牿祻稒錮锢雇頋顧顾鯝鲴刮",
+            "劀懖栝歄煱瓜瘑筈緺胍脶腡膼葀蝸趏踻銽頢颳騧鴰鸹冎剐剮叧寡卦啩坬挂掛絓罣罫褂詿诖髺乖拐",
+            "枴柺箉叏夬廥怪恠旝癐倌关官棺瘝癏矜窤綸莞蒄覌観觀观関闗關鰥鱞鳏琯痯筦管舘輨錧館馆鳤丱",
+            "冠悹悺惯慣掼摜樌毌泴涫潅灌爟瓘盥矔礶祼罆罐貫贯遦鏆鑵雚鱹鸛鹳侊僙光咣垙姯桄洸灮炗炚炛",
+            "烡珖胱茪輄銧黆广廣犷獷櫎矌臦臩逛亀圭妫媯嫢嬀帰归摫敮椝槣槻槼歸溈潙珪瑰璝瓌皈硅窐胿膭",
+            "袿規规邽郌閨闺鬶鬹鮭鲑鴂龜龟佹匦匭厬垝姽宄庋庪恑攱晷氿癸祪簋蛫蟡觤詭诡軌轨陒鬼刽刿劊",
+            "劌匮嶡巜攰昋暩柜桂桧楿檜櫃溎炅猤瞆筀蓕襘貴贵跪鞼鱖鱥鳜裩丨掍滚滾磙緄绲蓘蔉衮袞輥辊鮌",
+            "鯀鲧棍璭睴謴呙咼啯嘓埚埻堝墎崞彍渦濄聒蝈蟈過郭鈛鍋锅囯囶囻国圀國帼幗慖掴摑漍簂聝腘膕",
+            "虢馘惈果椁槨淉猓粿綶菓蜾裹輠錁鐹餜馃腂过",
+        ),
+    ),
+    (
+        'H',
+        concat!(
+            "菏哈鉿铪蝦咍嗨孩还還頦颏骸海烸酼醢亥嗐害氦餀饚駭骇唅嫨憨炶甝蚶谽酣頇顸馠魽鼾佄函凾含",
+            "圅娢寒崡晗梒涵焓琀筨邗邯鋡韓韩喊嚂浫罕蔊豃闞傼哻垾屽悍憾扞捍撖撼攌旱晘晥暵汉汗涆淊漢",
+            "澏瀚焊熯猂皔睅翰莟菡蛿蜭螒譀釬銲鋎閈闬雗頜頷顄颔馯駻鶾夯忼斻杭桁筕絎绗航苀蚢貥迒雽頏",
+            "魧鸻沆侾嚆蒿薅儫勂嗥嘷噑嚎壕椃毫濠狢獆獋獔籇號虠蠔諕譹豪貉鶴好恏郝傐号哠峼悎昊昦晧暤",
+            "暭曍浩淏滈澔瀥灏灝皓皜皞皡皥秏耗聕薃鄗顥颢鰝呵喝嗬峆抲訶诃何劾合咊和哬啝姀廅惒曷柇核",
+            "楁毼河涸滆澕熆盇盉盍盒礉禾秴篕翮荷萂蒚蚵螛覈訸詥貈趷釛鉌閡闔阂阖鞨餲饸鶡鹖齕龁龢佫嗃",
+            "嚇壑寉暍焃煂熇爀猲癋皬碋翯蠚袔褐賀贺赫隺靎靏鶮鸖鹤嘿潶黑黒拫痕鞎很狠詪恨亨哼悙脝諻佷",
+            "姮恒揘楻横橫珩蘅衡誙鑅鴴黉啈堼撔澋叿吽呍哄哅揈渹烘焢硡薨訇谾軣輷轟轰鍧閧顭魟仜垬妅娂",
+            "宏宖峵弘彋汯泓洪浤渱潂灴玒玜瓨硔竑竤篊粠紅紘紭綋红纮翃翝耾舼苰荭葒葓蕻虹訌讧谹谼鈜鉷",
+            "鋐閎闳霟鞃鴻鸿黌嗊晎澒鍙鬨齁侯喉帿猴瘊睺矦篌糇翭葔銗鍭餱骺鯸吼犼候厚后垕堠後洉缿豞逅",
+            "郈鄇鮜鱟鲎鲘乎匢匫吰呼唿啒嘑垀寣峘幠忽恗惖惚戯戲昒曶歑歘泘淲淴滹烀烼猢膴苸虍虖謼軤轷",
+            "雐餬喖嘝囫壶壷壺媩弧抇搰斛楜槲湖瀫焀煳狐瑚瓳箶糊絗縠胡葫蔛蝴螜衚觳醐鍸頶鬍魱鰗鶘鶦鹕",
+            "乕唬汻浒滸琥萀虎虝許互冱冴嚛婟嫭嫮岵帍弖怘怙戶户戸戽扈护摢昈枑楛槴沍沪滬熩瓠祜笏簄綔",
+            "臛蔰謢護豰鄠頀鳠鳸鸌鹱哗嘩砉花芲蕐錵鷨劃华崋樺浍滑澮狯猾磆華螖譁豁釫鏵铧驊骅划化婳嫿",
+            "嬅摦杹桦槬澅画畫畵繣舙觟話諣譮话竵佪徊怀懐懷槐櫰淮瀤耲褢褱踝咶坏壊壞孬蘹蘾諙懽欢歓歡",
+            "犿獾讙貛酄驩鴅鵍圜垸嬛寏寰懁捖桓梡洹澴狟环環瓛糫繯缳羦肒苋荁萈萑豲貆郇鍰鐶锾镮闤阛雈",
+            "鬟鹮澣睆緩缓藧輐唤喚喛嚾奂奐宦嵈幻患愌换換擐梙槵浣涣渙漶烉焕煥瑍痪瘓瞣脘觨豢轘逭鯇鰀",
+            "鲩嚝塃巟慌朚肓荒衁偟凰喤堭墴媓崲徨惶撗湟潢煌熿獚瑝璜癀皇磺穔篁簧艎葟蝗蟥趪遑鍠鐄锽隍",
+            "韹餭騜鰉鱑鳇鷬黃黄兤奛宺幌怳恍晃榥滉熀縨詤謊谎鎤愰曂皝皩軦咴噅噕婎媈幑徽恢拻挥揮撝晖",
+            "暉洃瀈灰灳烣煇珲琿睳禈翚翬虺袆褌褘詼诙豗輝辉隓隳顪鰴麾鼿囘回囬廻廽恛洄痐茴藱蚘蛔蛕蜖",
+            "迴逥鮰悔檓毀毁毇烠燬誨譭賄会僡儶匯卉叀哕喙嘒噦噧嚖圚嬇寭廆彗彙彚恚恵惠慧憓晦暳會槥橞",
+            "櫘殨汇泋湏滙潓潰濊烩燴獩璯瞺禬秽穢篲絵繐繢繪绘缋翙翽芔荟蔧蕙薈蟪詯詴諱譓譿讳诲贿鏸鐬",
+            "钺闠阓靧韢頮颒餯饖婚惛惽敯昏昬棔殙涽睧睯荤葷閽阍堚忶棞楎浑混渾祵繉轋顐餛餫馄魂鼲睔鯶",
+            "俒倱圂慁溷焝諢诨劐嚄耠锪佸活秳萿鈥钬伙夥漷火邩俰剨咟嗀嚯嚿奯彟彠惑或捇掝擭攉旤曤楇檴",
+            "沎湱濩瀖獲瓁癨眓矆矐矱礊祸禍穫耯臒艧获蒦藿蠖謋讗貨货鑊镬閄雘霍靃韄騞鱯",
+        ),
+    ),
+    (
+        'J',
+        concat!(
+            "剿芥颈丌乩僟击刏剞勣叽咭唧喞嗘嘰圾基墼姬尐屐峜嵆嵇幾擊朞机枅樍機櫅欚毄激犄玑璣畸畿癘",
+            "癪矶磯禨积稘稽積竒笄筓箕簊緝績绩缉羁羇羈耭聻肌脔芨萁虀虮蛣襀覉覊觭諅譏譤讥賫賷赍跡跻",
+            "踦蹟躋躸迹銈鐖鑇鑙隮雞霙鞿韲飢饑饥鰿鳮鶏鷄鸄鸡齍齎齏齑亟亼伋佶偮卙即卽及叝吉堲塉姞嫉",
+            "岌嵴嶯庴彶忣急愱戢揤撃擮极棘楖楫極槉橶檝殛汲湒漃潗濈焏狤疾瘠皍礏秸笈箿籍級级耤脊膌莋",
+            "蒺蓻蕀蕺藉蝍螏衱襋觙踖蹐輯轚辑郆鈒銡鍓鏶钑集雦雧霵鞊鴶鶺鹡丮几妀己戟挤掎撠擠泲濟犱穖",
+            "蟣魕魢鱾麂伎偈兾冀刉剂剤劑哜嚌坖垍塈墍妓季寂寄彐彑忌悸惎懻技斉斊旡既旣暨曁梞檕檵櫭洎",
+            "济済漈瀱璾痵瘈癠瞡祭禝稩稷穄穊穧紀継繋繫繼纪继罽臮芰荠萕葪蓟蔇薊薺蘎蘮蘻裚褀覬觊計記",
+            "誋计记跽际際霁霽騎驥骥髻鬾魝鮆鯚鯯鯽鰶鱀鱭鲚鲫鵋齌乫佳傢加嗧嘉夹夾宊家幏拁枷毠泇浃浹",
+            "犌猳珈痂笳耞腵葭袈豭貑跏迌迦鉫鎵镓鴐麚唊圿埉恝戛戞扴挾揳梜硈舺荚莢蛱蛺袷裌跲郏郟鋏铗",
+            "鞂鞈頬頰颊餄鵊假叚婽岬徦捁撟撹斚斝椵榎槚檟玾甲瘕矯絞繳胛贾踋鉀鉸钾餃价価價嫁架榢稼駕",
+            "驾兼冿囏坚堅奸姦姧尖幵廌惤戋戔揃搛椷樫櫼歼殱殲淺湔溅漸濺瀐瀸煎熞熸牋犍猏玪监監睷碊礛",
+            "稴笺箋篯籛緘縑缄缣肩艰艱菅菺葌蒹蕑蕳虃譼豜豣鈃銒鑯钘間閚间靬鞬鞯韀韉餰馢騝鬋魐鰜鰹鲣",
+            "鳒鳽鵳鶼鹣麉俭倹偂儉减剪囝堿寋弿戩戬拣挸捡揀撿暕枧柬梘检検檢減湕瀽瑐睑瞼硷碱礆笕筧简",
+            "簡絸繭翦茧藆蠒裥襇襺詃謇謭譾谫趼蹇醎鋄錽鎫鐗鐧锏鰔鹸鹻鹼件侟俴健僭剑剣剱劍劎劒劔建徤",
+            "擶旔栫楗榗槛檻毽洊涧渐澗瀳牮珔瞷磵礀箭糋糮繝腱臶舰艦荐蔪薦螹襉見覵覸见諓諫譖谏谮賎賤",
+            "贱趝践踐踺釰釼鉴鋻鍳鍵鏩鑑鑒鑬鑳键閒餞饯鰎僵壃姜将將摪橿殭江浆漿畕畺疅疆礓繮缰翞茳葁",
+            "薑螀螿豇韁鱂鳉勥奖奨奬桨槳滰獎繦耩膙蒋蔣講讲顜匞匠夅嵹弜弶強彊摾洚犟糡糨絳绛蔃袶謽酱",
+            "醤醬降交僬喬嘄姣娇嬌峧嶕憍憿教椒浇湫澆焦燋矫礁穚胶膠膲艽芁茭茮荞菽蕉蛟蟂蟭跤郊鐎驕骄",
+            "鮫鱎鲛鴵鵁鷍鷦鷮鹪嚼佼侥僥儌劋勪孂徺徼挢搅敫敽斆晈暞曒湬漅灚烄煍狡璬皎皦筊绞缴脚腳臫",
+            "蟜角譑賋蹻較铰饺叫呌嘂嘦噍噭峤嶠挍敎斠滘漖潐珓皭窌窖藠覐覚覺訆譥轎轿较酵醮釂啑喈嗟堦",
+            "媘幯接掲揭擑椄湝煯疖癤皆稭節結脻腉菨蝔街袺锴阶階鶛倢偼傑刦刧刼劫劼卩卪喼婕孑岊崨嵑嵥",
+            "巀截拮捷搩擳擷昅杰栉桀桔楬楶榤洁洯滐潔瀄犵睫碣竭絜緁緳结羯节莭蓵蜐蠘蠞蠽訐詰誱讦诘趌",
+            "踕迼鉣鍻鐑頡颉鮚鲒姐媎檞解觧飷丯介借吤唶堺屆届岕庎徣悈戒楐犗玠琾界畍疥痎砎紒繲艥蚧褯",
+            "誡诫躤鎅骱魀魪今埐嶜巾惍斤津珒琎瑧筋紟荕菳衿襟觔金钅鹶黅仅侭僅儘卺厪堇墐巹廑慬槿漌瑾",
+            "盡紧緊菫覲謹谨錦锦饉馑伒僸凚劤勁唫噤嚍妗嫤嬧寖尽搢晉晋歏殣浕浸溍濅濜烬燼瑨璡璶瘽祲禁",
+            "縉缙肵荩蓳藎觐賮贐赆近进進鋟靳齽京亰仱兢坕坙婛巠惊旌旍晶泾涇猄睛秔稉箐粳精経經经聙腈",
+            "茎荆荊莖菁葏驚鯨鲸鵛鶁鶄麖麠鼱井儆刭剄宑憬憼景暻汬烴燛璟璥穽肼蟼警阱俓倞傹净凈凊劲境",
+            "妌婙婧弪弳径徑擏敬曔桱梷浄淨濪瀞獍痉痙竞竟竧竫競竸胫脛葝誩踁迳逕鏡镜陉靓靖静靚靜冂冋",
+            "坰垧埛扃絅蘏蘔駉駫侰僒冏囧幜泂澃炯烱煚煛熲皛窘綗褧迥逈顈颎丩勼啾揂揪揫朻樛牞究糺糾纠",
+            "萛觓赳轇阄鬏鬮鳩鸠久乆九奺灸玖紤舏酒镹韭韮倃僦匓匛匶厩咎媨就廄廏廐慦捄救旧柩柾桕疚臼",
+            "舅舊鯦鷲鹫麔齨俱倶凥刟娵婮居崌抅拘拠掬揟據斪椐涺狙琚疽痀眗罝腒艍苴葅蜛裾趄跔踙輋鋦鋸",
+            "锔雎鞠鞫駒驹鮈鴡鶋侷匊婅局巈挶梮椈橘檋毩毱泦淗湨焗犑狊箤粷菊蘜諊趜跼踘蹫躹輂郹鄓陱駶",
+            "驧鵙鵴鶪鼳举咀弆挙擧椇楀榉榘櫸欅沮矩竘筥舉莒蒟蝺袓跙踽齟龃佢倨具冣剧劇勮句埧埾壉姖寠",
+            "屦屨岠巨怇怚惧愳懼拒据昛歫洰澽炬焣犋瞿秬窭窶簴粔絇耟聚苣菹虡蚷詎讵貗距踞躆遽邭醵鉅鐻",
+            "钜锯颶飓駏鮍鮔鲏剶勌勬圏姢娟捐朘涓睃脧蠲裐鎸鐫镌鵑鹃卷埢巻捲臇菤錈锩倦儁劵奆帣悁慻桊",
+            "淃狷獧瓹眷睊睠絹绢罥羂腃鄄隽雋韏飬餋鬳噘撅鞒鞽亅倔傕僪决刔劂厥啳噱堀孒孓屩屫崛崫嶥弡",
+            "彏憠憰戄抉挗捔掘撧攫斍桷橛橜欔欮殌氒決潏焆焳熦爑爝爴爵獗玃玦玨珏瑴疦瘚矍矡砄穱絕絶绝",
+            "臄芵蕝蕨蚗蟨蟩蠼觉觖觼訣譎诀谲谻貜赽趹蹶蹷躩逫鈌鐍鐝钁镢鱊鴃鷢龣军君囷均姰桾汮皲皸皹",
+            "碅筠箟莙菌蚐袀覠軍鈞銁銞钧頵鮶鲪麇麏麕蜠俊呁埈寯峻懏捃攈攟晙浚濬焌燇珺畯竣箘葰蔨蕈郡",
+            "陖餕馂駿骏鵘",
+        ),
+    ),
+    (
+        'K',
+        concat!(
+            "扛咯傀咳吭咔咖喀衉鉲佧卡胩奒开揩鐦锎開闿凯凱剀剴垲塏恺愷慨暟楷蒈豈輆鍇鎧铠闓颽勓壒忾",
+            "愒愾欬炌炏烗刊勘堪嵁戡栞龕龛侃偘冚凵坎埳塪崁惂欿歁歞砍莰輡轁轗顑墈看瞰矙磡竷衎阚鬫嫝",
+            "嵻康慷槺漮穅粇糠躿鏮閌闶鱇亢伉匟囥抗炕犺砊邟鈧钪尻髛丂拷攷栲槀洘烤燺考薧鮳鲓焅銬铐靠",
+            "鯌嗑坷峇嵙柯棵樖牁牱犐珂疴瞌砢磕礚科稞窠窼簻胢苛萪薖蝌軻轲醘鈳顆颗髁壳榼殻殼可堁岢嶱",
+            "敤渇渴炣礍閜克兣刻剋勀勊厒垎娔客尅恪愙揢搕氪溘碦緙缂課课锞騍骒啃垦墾恳懇肎肯肻豤錹颀",
+            "齗齦龂龈掯珢硍裉褃劥坈坑妔娙挳摼牼硁硜硻銵鍞鏗铿阬倥埪崆悾涳硿箜錓鵼孔恐控空鞚剾彄抠",
+            "摳眍瞘芤袧鏂劶口冦叩宼寇怐扣敂滱窛筘簆蔲蔻釦鷇刳哭圐扝枯桍矻窟胐跍郀顝骷鮬苦俈喾嚳库",
+            "庫瘔秙絝绔绹袴裤褲趶酷夸姱晇荂誇侉咵垮銙挎胯跨骻喎擓蒯侩儈凷哙噲块塊墤快欳獪筷糩脍膾",
+            "蒉郐鄶駃鬠鱠鲙宽寛寬臗髋髖欵款歀窾劻匡匩哐恇框洭硄筐筺誆诓軭邼忹抂狂誑诳軖鵟俇儣夼况",
+            "卝圹壙岲彉懬懭旷昿曠況爌眖眶矿砿絋絖纊纩貺贶躀邝鄺鉱鑛黋亏刲岿巋悝盔窥窺茥藈蘬虧鍷闚",
+            "鞹喹夔奎巙戣揆晆暌楏楑櫆湀犪睽聧葵蘷虁蝰躨逵鄈鍨隗頯馗騤骙魁尯峞煃跬蹞頍匱喟嘳媿愦愧",
+            "憒撌椢槶樻溃瞶硊篑簀簣籄聩聭聵蔮蕢謉鐀鑎餽饋馈騩坤堃婫崐崑惃昆晜焜猑琨瑻菎蜫裈貇醌錕",
+            "锟騉髠髡髨鯤鲲鵾鹍壸壼悃捆梱硱稇稛綑裍閫閸阃齫困涃睏擃姡廓扩拡括挄擴桰濶籗蛞適闊阔霩",
+            "鞟",
+        ),
+    ),
+    (
+        'L',
+        concat!(
+            "纶喇嚹垃拉柆磖翋菈邋剌揦旯砬藞揧攋楋溂爉瓎瘌腊臈臘蜡蝋蝲蠟辢辣鑞镴鬎鯻啦鞡來俫倈唻婡",
+            "崃崍庲徕徠来梾棶涞淶猍琜筙箂莱萊逨郲錸铼騋鯠鶆麳娕櫴濑瀨瀬癞癩睐睞籁籟藾襰賚賴赉赖頼",
+            "顂儖兰厱囒婪岚嵐幱懢拦攔斓斕栏欄欗澜瀾灆灡燣燷璼礷篮籃籣糷繿葻蓝藍蘫蘭褴襕襤襴襽譋讕",
+            "谰躝鑭钄镧闌阑韊壈嬾孄孏懒懶揽擥攬榄欖浨漤灠爦纜缆罱覧覽览醂顲壏滥濫烂燗爁爛爤爫瓓啷",
+            "俍勆嫏廊桹榔欴狼琅瑯稂筤艆莨蓈蓢蜋螂躴郎郒郞鋃锒閬阆塱峎崀悢斏朖朗朤樃烺硠誏埌浪蒗捞",
+            "撈劳労勞哰唠嘮崂嶗憥栎浶涝澇牢痨癆磱窂簩蟧醪鐒铹佬咾姥恅栳橑潦狫老荖轑銠铑僗嫪憦橯烙",
+            "絡耢耮軂酪髝嘞肋乐仂叻哷忇扐楽樂氻泐玏砳竻簕艻阞韷頱鰳鳓了餎饹勒囄擂壨嫘檑櫑欙瓃畾礌",
+            "縲纍纝缧罍羸蠝轠鐳鑘镭雷靁鼺傫儡厽垒塁壘樏櫐洡漯灅瘣癗磊磥礧礨絫耒蕌蕾藟蘲蘽虆誄讄诔",
+            "鑸頛鸓儽攂泪涙淚禷类累纇蘱酹銇錑頪類颣棱倰塄崚楞稜蔆薐冷堎愣踜刕剓剺劙厘喱嚟嫠孋孷廲",
+            "悡攡梨梩梸棃樆氂漓漦灕犁犂犛狸璃瓈盠睝离穲篱籬粍粚糎縭缡罹艃菞蓠蔾藜蘺蜊蟍蟸蠡褵謧貍",
+            "邌酾醨釃釐鋫錅鏫鑗離騹驪骊鯬鱺鲡鵹鸝鹂麗黎黧俚哩娌峛峢峲李浬澧理礼禮粴裏裡逦邐醴里鋰",
+            "锂鯉鱧鲤鳢丽例俐俪傈儮儷凓利力励勵历厉厤厯厲叕吏呖唎唳嚦囇坜塛壢娳婯屴岦巁悧悷慄戾搮",
+            "擽攊攦攭斄暦曆曞朸枥栗栛栵棙檪櫔櫟櫪欐歴歷沥沴沵浰涖溧濿瀝爄爏犡猁珕琍瑮瓅瓑瓥疠疬痢",
+            "癧皪盭矋砅砺砾磿礪礫礰禲秝立笠筣篥粒粝糲綟纅脷苈苙茘荔莅莉蒞藶蘚蚸蛎蛠蜧蝷蠇蠣蠫觻詈",
+            "讈赲跞躒轢轣轹郦酈釙鉝隶隷隸雳雴靂靋鬁鱱鱳鳨鴗鷅鷑麜倆亷劆匲匳嗹噒奁奩奱嫾嬚帘廉怜慩",
+            "憐梿槤櫣涟溓漣濂濓熑燫璉磏簾籢籨縺翴联聨聫聮聯臁莲莶蓮蔹薕螊蠊裢褳覝謰譧蹥连連鎌鐮镰",
+            "鬑鰱鲢僆摙敛斂琏羷脸膦臉蘞裣襝鄻堜娈媡孌恋戀楝歛殓殮湅潋澰瀲炼煉瑓練纞练萰薟蘝錬鍊鏈",
+            "链鰊凉墚梁椋樑涼簗粮粱糧綡良輬辌量駺両两俩兩唡啢掚緉脼蜽裲魉魎亮哴喨晾湸諒谅踉輌輛辆",
+            "鍄撩蹽僚嘹嫽寥寮尞屪嵺嶚嶛廫憀摎敹漻熮燎獠璙疗療窷簝繚缭聊膋膫豂賿蹘辽遼鐐镽顟飂飉髎",
+            "鷚鷯鹩憭暸瞭蓼蟟鄝釕钌尥尦廖撂料炓爒窲蟉镣咧儠冽列劣劽埒埓姴巤挒捩擸洌浖烈煭犣猎猟獵",
+            "睙聗脟茢蛚蛶裂趔躐迾颲鬛鬣鮤鱲鴷临僯厸啉壣崊嶙惏斴晽暽林淋潾瀶燐獜琳璘甐疄痳瞵矝碄磷",
+            "箖粦粼繗罧翷臨蹸轔辚遴邻鄰鏻隣霖驎鱗鳞麐麟亃凛凜廩懍懔撛檁檩澟癛綝菻吝恡悋橉焛蔺藺賃",
+            "赁躏躙躪轥閵拎伶凌刢呤呬囹坽夌姈婈孁岭岺掕昤朎柃棂櫺欞泠淩澪灵燯爧狑玲琌瓴皊睖砱碐祾",
+            "秢竛笭紷綾绫羚翎聆舲苓菱蕶蘦蛉衑裬詅跉軨輘酃醽鈴錂铃閝陵零霊霗霛霝靈駖魿鯪鲮鴒鸰鹷麢",
+            "齡齢龄龗嶺彾袊阾領领令另炩溜澑熘刘劉嚠媹嬼嵧懰旈旒榴橊沠流浏瀏琉瑠瑬璢畄留畱疁瘤癅硫",
+            "蒥蓅藰裗遛鎏鎦鏐鐂镏镠飀飅飗餾馏駠駵騮驑骝鰡鶹鷎鹠麍柳栁桺橮珋綹绺罶羀蒌鉚鋶锍飹六坴",
+            "塯廇畂碌磂翏蹓陸雡霤鬸鹨霳儱咙嚨屸嶐巃巄昽曨朧栊槞櫳泷湰滝漋瀧爖珑瓏癃眬矓砻礱礲窿竜",
+            "笼篭籠聋聾胧茏蘢蠪蠬襱豅躘鏧鑨隆靇驡鸗龍龒龓龙垄垅壟壠拢攏竉陇隴哢徿挊挵梇硦衖贚搂摟",
+            "偻僂剅喽嘍娄婁廔楼樓溇漊熡瞜耧耬艛蔞蝼螻謱軁遱鞻髅髏鷜塿嵝嶁甊篓簍屚漏瘘瘺瘻鏤镂陋露",
+            "噜嚕撸擼卢嚧垆壚庐廬攎曥枦栌櫨泸瀘炉爐獹玈瓐盧矑籚纑罏胪臚舮舻艫芦蘆蠦轤轳鈩鑪顱颅髗",
+            "魲鱸鲈鸕鸬黸卤塷掳擄樐橹櫓氇氌滷澛瀂硵磠艣艪蓾虏虜鏀鐪鑥镥魯鲁鹵侓僇剹勎勠圥垏塶娽峍",
+            "廘彔录戮摝椂樚淕淥渌漉潞熝琭璐甪盝睩硉磟祿禄稑穋箓簏簬簶籙粶膔菉蔍蕗虂螰觮賂赂趢路踛",
+            "蹗輅轆辂辘逯醁錄録錴鏕鏴陆騄騼鯥鵦鵱鷺鹭鹿麓圝圞孪孿峦巒挛攣曫栾欒滦灓灤癴癵羉臠虊銮",
+            "鑾鵉鸞鸾卵乱乿亂薍釠稤圙寽掠略畧鋝鋢锊抡掄仑伦侖倫囵圇婨崘崙惀棆沦淪碖腀菕蜦論踚輪轮",
+            "錀陯鯩埨稐耣溣论啰囉捋羅儸剆攞椤欏猡玀箩籮罗萝蔂蘿螺覶覼逻邏鏍鑼锣镙饠騾驘骡鸁倮曪瘰",
+            "癳臝蓏蠃裸躶鎯嗠峈摞洛洜濼犖珞硌笿纙络荦落雒駱骆鮥鴼鵅慺榈櫖櫚氀膢藘閭闾馿驢驴侣侶儢",
+            "吕呂屡屢履挔捛旅梠祣稆穞穭絽縷缕膂膐褛褸郘鋁铝勴卛嵂律慮氯滤濾爈率箻綠緑绿膟葎虑鑢",
+        ),
+    ),
+    (
+        'M',
+        concat!(
+            "泌铆嗎妈媽嬤嬷擵蔴蚂螞吗尛犘痲蟆蟇麻麼嘜杩溤犸獁玛瑪码碼鎷馬马鰢鷌傌唛帓榪睰祃禡罵閁",
+            "駡骂嘛埋薶霾买嘪荬蕒買鷶佅劢勱卖売眿脈脉蝐賣迈邁霡麥麦嫚顢颟悗慲摱槾璊瞒瞞蛮蠻謾谩蹣",
+            "鞔饅馒鬗鬘鰻鳗屘満满滿矕螨蟎襔鏋僈墁幔慢曼漫澫澷熳獌縵缦蔄蔓鏝镘牤吂哤娏尨忙恾杗杧氓",
+            "浝牻狵痝盲盳硭笀芒茫蘉蛖邙釯鋩铓駹鼆壾庬汒漭硥茻莽莾蟒蠎猫貓兞堥媌嫹旄枆毛渵牦矛罞芼",
+            "茅蝥蟊覒軞酕錨锚髦髳鶜冇卯夘峁戼昴泖笷茆冃冐冒媢帽懋暓柕楙毷瑁皃眊瞀瞐耄艒茂萺袤貌貿",
+            "贸鄚鄮么呅堳塺媒嵋徾攗枚栂梅楣楳槑沒没湄湈溦煤猸玫珻瑂眉睂禖篃脄脢腜苺莓葿郿酶鋂鎇镅",
+            "霉鶥鹛黴凂媄媺嬍嵄挴毎每浼渼燘美鎂镁黣妹媚寐昧沬煝痗眛睸祙蝞袂謎谜跊韎鬽魅悶们們扪捫",
+            "樠穈菛虋鍆钔門閅门暪懑懣焖燜闷儚冡幪懜曚朦橗檬氋濛獴甍甿盟瞢矇矒礞艨苎莔萌萠蕄虻蝱鄳",
+            "鄸雺靀饛鯍鸏鹲黾勐懞懵猛瓾艋蒙蜢蠓錳锰鯭夢夣孟梦溕霥霿咪眯瞇冞弥彌戂擟攠檷瀰爢狝猕獼",
+            "瓕祢禰籋糜縻罙蒾蘪蘼詸迷醚醾醿釄镾靡鸍麊麋麛侎孊弭敉洣渳濔灖眫米羋脒芈葞蔝銤冖冪嘧塓",
+            "宓宻密峚幂幎幦榓樒櫁汨淧淿滵漞濗祕秘簚糸纟羃蔤藌蜜蠠覓覔觅謐谧鼏婂媔嬵宀棉檰櫋眠矈矊",
+            "矏綿緜绵臱芇蝒丏俛偭免冕勉勔喕娩愐汅沔渑湎澠眄絻緬缅腼莬靦鮸麫黽粫糆面靣麪麵麺喵描瞄",
+            "緢苗鱙鶓鹋杪淼渺眇秒竗篎緲缈藐邈妙庙庿廟玅繆缪乜吀咩哶孭幭懱搣櫗滅瀎灭烕礣篾蔑薎蠛衊",
+            "覕鑖鱴鴓姄岷崏忞忟怋捪旻旼暋民玟珉琘瑉痻盿砇碈緍緡缗罠苠賯鈱錉鍲閺僶冺刡勄悯愍慜憫抿",
+            "敃敏泯湣潣皿笢閔閖閩闵闽鰵鳘佲冥名嫇明暝朙榠洺溟熐猽眀瞑茗蓂螟覭詺鄍銘铭鳴鸣凕姳慏眳",
+            "酩命唒謬谬摸劘嚤嚩嚰嫫摩摹模橅無磨糢膜藦蘑謨谟饃饝馍髍魔麽懡抹抺劰嗼嚜圽塻墨妺嫼寞帞",
+            "慔昩暯末枺歾歿殁沫湐漠爅獏瘼皌眜眽瞙砞礳秣粖絈縸纆耱茉莈莫萬蓦蛨蟔衇袹覛謩貃貊貘銆鏌",
+            "镆陌霢靺鞆驀鬕魩默黙庅哞侔劺呣恈桙洠牟眸蛑蟱謀谋鉾鍪鴾麰某踇愗墲毪氁亩姆娒峔拇母牡牳",
+            "畆畒畝畞畮砪胟鉧仫募坶墓幕幙慕暮木楘樢毣沐炑牧狇目睦穆苜莯蚞鉬钼雮霂鞪鶩鹜",
+        ),
+    ),
+    (
+        'N',
+        concat!(
+            "嗱拏拿挐訤誽鎿镎乸哪雫吶呐妠娜抐捺笝納纳肭蒳衲袦訥豽軜那鈉钠靹魶孻摨熋乃奶妳嬭廼氖氝",
+            "疓艿迺釢倷奈柰渿耐萘螚褦錼鼐囡侽南喃奻娚暔枏枬柟楠男畘莮萳諵难難戁揇湳腩蝻赧婻乪嚢囊",
+            "囔憹欜饢馕攮曩灢儾齉峱呶夒嶩巎怓挠撓桡橈猱獶獿硇繷蛲蟯詉譊鐃铙髐匘垴堖嫐恼悩惱瑙碯脑",
+            "脳腦淖閙闹鬧讷呢脮腇餒馁鮾鯘內内黁嫩嫰恁儜能薴濘嗯妮倪坭埿婗尼屔怩泥淣狋狔猊秜籾臡蚭",
+            "蜺觬貎跜輗郳鈮铌霓鯓鯢鲵鶂麑齯伱伲你儗儞孴抳拟擬旎柅苨薿譺鉨隬馜匿堄嫟嬺孨屰惄愵慝昵",
+            "暱氼溺痆睨縌胒腻膩迡逆鷊鹝鹢拈蔫姩年秊秥鮎鯰鲇鲶黏捻撚撵攆涊碾簐跈蹨躎輦辇卄唸埝廿念",
+            "淰艌鼰齞娘嬢孃酿醸釀嫋嬝嬲茑蔦袅裊褭鳥鸟尿氽脲惗捏揑踗鈢鑈苶啮嗫噛嚙囁囓圼孼孽嵲巕帇",
+            "摂擜敜枿棿櫱涅湼疌篞糱糵聂聶臬臲菍蘖蠥踂蹑躡鉩錜鎳鑷钀镊镍闑陧隉顳颞齧囜您拰凝咛嚀嬣",
+            "宁寍寕寗寜寧拧擰柠檸狞獰甯聍聹苧鑏鬡鸋橣矃佞侫泞妞汼牛忸扭炄狃紐纽鈕钮靵拗蚴侬儂农哝",
+            "噥檂欁浓濃癑禯秾穠脓膿蕽襛農辳醲鬞齈弄獳羺啂嗕槈耨譨譳鎒鐞奴孥笯胬蒘駑驽伮努弩砮怒搙",
+            "愞暖渜煖煗餪疟瘧硸虐謔谑傩儺挪捼梛郍橠砈喏懦懧挼掿搦搻榒稬穤糑糥糯諾诺蹃逽鍩锘女籹釹",
+            "钕恧朒衂衄",
+        ),
+    ),
+    (
+        'O',
+        "喔噢哦區呕嘔塸慪櫙欧歐殴毆沤漚熰瓯甌膒蓲謳讴鴎鷗鸥偶吘湡耦腢蕅藕怄",
+    ),
+    (
+        'P',
+        concat!(
+            "刨埔莆啪妑皅舥葩蚆趴掱潖爬琶筢跁帊帕怕袙拍俳徘排棑牌猅篺簰簲輫俖汖派湃蒎鎃攀潘眅砙丬",
+            "媻幋搫槃瀊爿盘盤磐磻縏蒰蟠跘踫蹒鎜鞶坢奤冸判叛拚沜泮溿炍牉畔盼袢襻詊鋬鑻頄頖乓沗滂胮",
+            "膖厐厖嫎庞徬旁篣舽螃逄雱霶騯龎龐嗙耪覫炐肨胖抛拋脬匏咆垉庖炰爮狍瓟袍軳鞄麅跑奅泡炮疱",
+            "皰砲礟礮靤髱麭呸垺妚娝岯柸肧胚衃醅培毰裴裵賠赔锫阫陪陫昢琣伂佩姵帔斾旆沛浿珮苝轡辔配",
+            "霈馷喯喷噴歕湓瓫盆葐呠翉翸亯匉嘭怦恲抨梈漰澎烹砰硑磞軯閛駍倗傰埄塜塳彭憉朋棚椖樥淜痭",
+            "硼稝竼篷纄膨芃蓬蟚蟛輣錋鑝韸韼髼鬅鬔鵬鹏捧淎皏掽椪碰丕伾劈匹噼坯怌怶憵批披抷旇炋狉狓",
+            "砒磇礕秛秠紕翍耚苤豾邳釽鈹鉟銔铍霹駓髬鴄啤壀枇毗毘毞焷犤玭琵疲皮笓罴肶脾腗膍蚍蚽蜱螷",
+            "蠯豼貔郫鈚錍阰陴隦魾鼙仳噽嚭圮崥庀疋痞癖脴苉諀僻嚊媲嫓屁揊淠渒潎澼甓睤睥礔譬髲鷿鸊偏",
+            "囨媥犏篇翩貵頨楩胼腁諚諞谝賆蹁駢騈骈骿覑片騗騙骗剽嘌慓旚漂縹缥翲薸螵飃飄飘魒嫖朴瓢竂",
+            "闝殍犥皫瞟醥勡彯票篻顠撆撇暼氕瞥丿鐅姘拼涄礗嚬娦嫔嬪薲蘋貧贫頻顰频颦品榀朩牝聘乒俜娉",
+            "焩砯聠覮頩凭凴呯坪屏屛帡帲幈平慿憑枰泙炾玶瓶甁甹竮箳簈缾胓艵苹荓萍蓱蚲蛢評评軿輧郱鮃",
+            "鲆坡泼溌潑癹鉕鏺钋钷颇嘙婆櫇皤蔢鄱叵尀笸頗岶廹洦炇烞珀破砶粕蒪迫魄剖吥抔抙捊掊裒咅哣",
+            "婄廍犃仆僕噗扑抪撲擈攴攵柨潽痡瞨舗鋪铺陠匍圤墣樸檏濮璞穙箁纀菐菩葡蒱蒲襆襥轐酺鏷镤圃",
+            "圑普暜浦溥烳誧諩譜谱蹼鐠镨曝瀑舖",
+        ),
+    ),
+    (
+        'Q',
+        concat!(
+            "橇炔茄嵌七倛僛凄咠唭嘁妻娸徛悊悽慼慽戚捿攲期柒栖桤桼棲榿槭欹欺沏淒漆磎磩紪緀萋諆谿蹊",
+            "郪霋顣魌鶈鸂亓亝俟其剘圻埼墘奇岐岓崎嵜帺愭懠扺掑旂旗棊棋檱櫀歧淇濝猉玂琦琪璂畦疷碁碕",
+            "祁祇祈祺禥竢簱籏粸綥綦綨纃翗耆脐臍艩芪蕲藄蘄蚑蚚蛴蜝蜞蠐衹跂踑軝迉鄿釮錡錤锜頎騏骐骑",
+            "鬐鬿鮨鯕鰭鲯鳍鵸鶀麒麡齊齐乞企启呇唘啓啔啟婍屺岂敧晵杞棨玘盀綮綺绮芑芞裿諬起邔闙呚呮",
+            "噐器夡契弃忔憇憩摖暣栔棄欫气気氣汔汽泣洓湆湇炁甈盵砌碛碶磜磧礘葺蟿訖諿讫趿迄鏚鼜掐葜",
+            "拤跒酠圶帢恰愘殎洽仟佥僉兛千圱圲奷婜孯岍悭愆慳扦拪掔搴撁攐攑攓杄檶櫏欦汘汧牵牽瓩签箞",
+            "簽籖籤粁縴羟羥臤芊蚈褰諐謙譣谦谸迁遷釺鉛鐱钎铅锓阡韆顅顩騫骞鬜鬝鵮鹐乾前姏媊岒忴扲拑",
+            "掮揵榩歬漧潛潜濳灊燂箝荨葥蕁虔蚙軡鈐鉗銭錢钤钱钳騚鬵鰬鳹黔黚嵰槏浅繾缱肷膁蜸譴谴遣倩",
+            "傔儙刋嗛堑塹壍悓慊棈椠槧欠歉皘篏篟綪芡茜蒨蔳輤呛嗆嶈戕戗斨枪椌槍溬牄猐玱瑲矼篬羌羗羫",
+            "腔蜣謒跄蹌蹡錆鎗鏘鏹鑓锖锵镪墙墻嫱嬙廧强樯檣漒牆艢蔷薔蘠傸墏抢磢繈襁唴炝熗羻劁塙墝墽",
+            "嵪幧庨悄敲硗磽繑繰缲趬跷蹺郻鄡鄥鍫鍬鏒鐰锹頝骹髜乔侨僑嘺墧嫶嶣憔敿桥槗樵橋燆癄瞧硚礄",
+            "簥翘翹蕎藮譙谯趫鐈顦巧愀釥鵲俏僺峭帩撬撽窍竅誚诮躈陗鞘韒髚切聺癿且匧唼妾怯悏惬愜挈朅",
+            "淁穕窃竊笡箧篋籡藒蛪踥鍥锲鯜亲侵媇寴嵚嶔欽綅衾親誛钦駸骎鮼勤嗪噙嫀庈懃懄捦擒斳檎澿珡",
+            "琴琹禽秦耹肣芩芹菦螓蠄覃赺鈙雂靲鵭坅寑寝寢昑曋螼赾顉儬吢吣唚抋揿搇撳沁瀙菣倾傾卿啨圊",
+            "寈氢氫淸清狅蜻軽輕轻郬靑青頃鯖鲭剠勍夝情擎晴暒樈檠殑氰甠黥庼廎檾苘請謦请顷庆慶掅殸汫",
+            "漀碃磘磬罄罊鑋靘匔焪熍銎儝卭宆惸桏棾橩焭煢琼璚瓊睘瞏穷穹窮竆笻筇茕蒆藑藭蛩赹跫輁邛丘",
+            "丠偢坵媝恘楸秋秌穐篍緧萩蚯蝵蟗蠤趥邱鞦鞧鰌鰍鱃鳅鶖鹙龝俅僋厹叴囚崷巯巰扏朹梂殏毬求汓",
+            "泅浗渞湭煪犰玌球璆皳盚紌絿肍脙苬莍虬虯蛷蝤裘觩訄訅賕赇逎逑遒酋釚銶鮂鯄鰽鼽搝糗伹佉匤",
+            "区呿坥屈岖岨岴嶇憈抾敺砠祛筁粬紶胠蛆蛐袪覰覷誳诎趋躯軀镼阹駆駈驅驱髷魼鰸鱋鶌麯麴麹黢",
+            "劬匷忂懅戵朐欋氍淭渠灈爠璖璩癯磲籧翑翵胊臞菃葋蕖蘧螶蟝蠷衢豦躣軥鑺鴝鸜鸲鼩取娶曲浀竬",
+            "詓齲龋刞厺去覻觑趣閴闃阒麮鼁圈弮悛惓棬絟佺全姾婘孉峑巏恮拳搼权権權泉洤湶灥牷犈犬瑔痊",
+            "硂筌縓荃葲蜷蠸觠詮诠跧踡輇辁醛銓铨顴颧駩騡鬈鰁鳈齤呟汱犭琄甽畎綣绻虇券劝勧勸烇牶絭缺",
+            "缼蒛闕阙瘸却卻埆崅悫愨慤搉榷毃灍燩琷皵硞确碏確碻礐礭趞闋阕雀鹊夋峮踆逡宭帬羣群裙裠",
+        ),
+    ),
+    (
+        'R',
+        concat!(
+            "嘫然燃繎肰蚒蚦蚺蛅衻袇袡髥髯冄冉呥姌媣染橪珃苒嚷儴勷瀼獽瓤禳穣穰蘘蠰躟鬤壌壤攘爙懹譲",
+            "讓让娆嬈荛蕘襓饒饶扰擾繞绕遶隢惹渃热熱人亻仁壬忈忎朲秂纴芢鈓銋魜鴹忍栠栣棯秹稔腍荏荵",
+            "仞仭任刃刄妊姙屻扨杒梕牣紉紝絍纫肕葚衽袵訒認认讱軔軠轫靭靱韌韧飪餁饪鵀扔仍礽辸陾芿囸",
+            "日氜衵鈤馹驲媶容嵘嵤嶸戎曧栄榕榮榵毧溶瀜烿熔爃狨瑢穁絨縙绒羢肜茙茸荣蓉蝾融螎蠑褣鎔鎹",
+            "镕駥髶鰫鷛傇冗宂搑氄鴧媃揉柔楺沑渘瑈瓇禸糅腬葇蝚蹂輮鍒鞣韖騥鰇鶔煣粈鍕宍肉偄儒嚅如孺",
+            "帤曘桇渪濡燸筎茹蕠薷蠕袽襦邚醹銣铷顬颥鱬鴑鴽乳侞擩汝肗辱傉入媷洳溽縟缛蓐褥鄏堧壖撋媆",
+            "朊瑌瓀碝礝緛耎腝蝡軟輭软阮婑甤緌蕤惢桵橤繠蕊蕋蘂蘃叡壡枘棁汭瑞睿芮蚋蜹銳鋭锐犉瞤橍润",
+            "潤膶閏閠闰偌叒弱楉焫爇箬篛若蒻鄀鰙鰯鶸",
+        ),
+    ),
+    (
+        'S',
+        concat!(
+            "刹裳娠盛伺赡仨撒洒潵灑訯靸卅攃櫒脎萨蕯薩隡颯飒馺噻塞恖愢揌毢腮顋鰓鳃僿嗮簺賽赛三叁嘇",
+            "弎攕毵毿犙糁鬖仐伞佡傘糂糝糣糤繖鏾饊馓俕散閐丧喪桑桒嗓搡磉褬鎟顙颡慅掻搔溞繅缫臊颾騒",
+            "騷骚鰠鱢鳋埽嫂扫掃氉瘙矂啬嗇懎擌歮歰涩渋澀澁濇濏瀒犞瑟璱穑穡繬翜色譅趇轖銫铯雭飋幓曑",
+            "森椮槮穼篸蔘襂僧鬙乷帴摋杀杉桬榝樧殺沙煞猀痧砂粆紗纱莎蔱裟赊鎩铩髿魦鯊鯋鲨啥傻儍倽喢",
+            "帹廈歃箑翣萐閯霎筛篩簛籭繺晒曬閷删刪剼姍姗山彡扇挻搧杦椙檆潸澘烻煽狦珊穇笘縿羴羶舢芟",
+            "苫衫跚軕邖釤钐鯅晱熌睒覢閃闪陕陜陝陿傓僐儃剡善墠墡嬗掞摲擅敾椫汕疝磰繕缮膳蟮蟺訕謆譱",
+            "讪贍赸鄯銏鐥饍騸骟鱓鱔鳝鳣伤傷商墒慯殇殤湯滳漡熵蔏螪觞觴謪鬺丄扄晌賞赏鑜上仩姠尙尚緔",
+            "绱銄弰捎旓梢烧焼燒稍筲艄莦蕱蛸輎髾鮹勺圴牊玿竰芍苕韶少佋劭卲哨娋潲睄紹綤绍袑邵颵奓奢",
+            "檨猞畲譇賒賖佘舌虵蛇蛥鉈鍦铊捨舎厍厙射弽慑懾捑摄攝欇歙涉渉滠灄社舍葉蔎蠂設设赦韘騇麝",
+            "誰伸侁兓呻妽屾峷扟敒柛氠深燊珅甡甧申眒砷籶籸紳绅莘葠薓裑訷詵诜身鉮阠駪鲹鵢神吲哂婶嬸",
+            "审宷審弞沈渖瀋瞫矤矧覾訠諗讅谂谉邥頣魫侺愼慎昚椹涁渗滲甚瘆瘎瘮眘肾脤腎蜃鋠升呏声斘昇",
+            "曻殅泩湦焺牲狌珄生甥竍竏竓竔竕竡笙聲苼鉎阩陞陹鵿鼪憴溗縄繩绳譝鱦偗冼省眚箵剩剰勝圣墭",
+            "嵊榺琞聖胜蕂貹賸呞噓失尸屍师師施浉湤湿溮溼濕狮獅瑡箷絁葹蒒蓍虱蝨螄褷襹詩诗邿釶鉇鯴鰤",
+            "鲺鳲鳾鶳鸤乭什兙十埘塒实実寔實峕拾时旹時榯湁湜溡炻瓧石碩祏莳蒔蚀蝕識识遈鉐食飠饣鰣鲥",
+            "鼫鼭乨使兘史始宩屎矢笶豕鉂駛驶世丗亊事仕佀侍冟势勢卋卶叓呩唑嗜噬士奭媞嬕室崼市式弑弒",
+            "恃戺拭揓是昰枾柹柿氏澨烒眂眎睗示礻筮簭翨舐舓螫衋褆襫視视詍試誓諟諡謚试谥貰贳跩軾轼适",
+            "逝遾釈释釋鈰鉃鉽銴铈飾餙餝饰鳀齛収收荍垨守手扌艏首兽受售壽夀寿授涭狩獣獸痩瘦綬绶膄鏉",
+            "书俆倏倐叔圕姝抒摅摴攄書杸枢梳樞橾殊殳毹淑疎疏祋紓綀纾舒蔬藲踈軗輸输陎鵨埱塾孰尗掓焂",
+            "熟璹秫虪襡贖赎跾鸀婌属屬數暑曙潻癙糬署薥薯藷蜀襩钃韣黍鼠鼡侸儵兪凁墅尌庶庻恕戍捒数术",
+            "束树樜樹沭漱潄澍濖竖竪絉翛腧荗蒁術裋豎述鉥錰鏣鶐刷唰鮛耍誜孈摔縗衰甩咰帅帥繂蟀拴栓閂",
+            "闩涮腨双孀孇欆礵艭雙霜驦骦鷞鸘鹴塽慡樉爽縔騻灀挩捝脽谁水氵氺帨涗涚睡瞓祱稅税蛻裞說説",
+            "吮揗賰橓瞚瞬舜蕣順顺鬊哾说嗍妁揱搠朔槊欶烁爍獡矟硕箾蒴鎙鑠铄丝俬凘厮厶司咝嘶噝媤廝思",
+            "撕斯楒榹泀澌燍磃禗禠私糹絲緦缌罳蕬虒蛳蜤蟖蟴覗謕鉰鋖鍶鐁锶颸飔騦鷥鸶鼶死亖似儩兕嗣四",
+            "姒娰孠寺巳杫柶汜泗泤洍涘瀃牭祀禩笥耜肂肆蕼貄釲鈻飤飼饲饴駟驷倯凇娀崧嵩庺忪憽松枀枩柗",
+            "梥檧淞濍硹菘蜙鬆傱嵷怂悚愯慫楤竦耸聳駷宋訟誦讼诵送頌颂餸叜嗖廀廋捜搜摉摗溲獀艘蒐蓃螋",
+            "鄋醙鎪锼颼飕餿馊騪傁叟嗾擞擻櫢瞍籔薮藪謏嗽瘶囌櫯甦稡稣穌窣苏蘇蘓酥鯂俗傃僳嗉塐塑夙嫊",
+            "宿愫愬憟栜榡樕橚殐泝洬涑溯溸溹潚潥玊珟璛碿簌粛粟素縤縮肃肅膆蔌藗蜶觫訴誎謖诉谡趚蹜速",
+            "遡遬鋉餗驌骕鱐鷫鹔狻痠酸匴祘笇筭算蒜哸夊娞毸浽滖濉熣眭睢綏芕荽荾虽雖鞖绥遀遂隋随隨雟",
+            "嶲巂瀡膸髄髓亗埣嬘岁嵗旞檅檖歲歳湪澻煫燧璲瓍睟砕碎祟禭穂穗穟繀繸襚誶譢谇賥邃鐆鐩隧孙",
+            "孫搎槂狲猻荪蓀蕵薞飧飱损損榫笋筍箰簨鎨隼愻潠傞唆嗦娑挱挲摍桫梭樎簑簔缩羧莏蓑趖鮻唢嗩",
+            "所摵暛溑琐琑瑣璅瘷索褨鎍鎖鎻鏁鏼锁逤",
+        ),
+    ),
+    (
+        'T',
+        concat!(
+            "驮烃他塌她它牠祂禢塔榙獭獺鰨亣傝嚃嚺崉拓挞搨撻榻橽毾涾溻澾濌狧羍誻譶踏蹋蹹躂躢迖遝遢",
+            "錔鎉鑉闥闼鞜鞳鮙龖龘囼坮孡胎臺苔儓台嬯抬擡旲檯炱炲秮籉菭薹跆邰颱駘鮐鲐冭太夳忲态態汰",
+            "泰溙燤粏肽舦酞鈦钛坍怹抩摊擹攤滩灘瘫癱緂舑貪贪倓坛墰墵壇壜婒憛昙曇榃橝檀潭痰罈罎藫談",
+            "譚谈谭貚郯醈醰錟锬餤騨驔鷤黮嗿坦忐憳憻毯璮菼袒裧襢贉醓鉭叹嘆埮探歎湠炭碳賧赕劏嘡坣汤",
+            "羰蝪趟蹚鏜鐋铴镗鞺鼞傏唐啺堂塘搪棠榶樘橖溏漟煻瑭磄禟篖糃糖糛膅膛蓎薚螗螳赯踼鄌醣鎕闛",
+            "隚餳餹饄饧鶶伖倘偒傥儻帑戃曭淌爣矘耥躺鎲钂镋摥烫燙嫍弢慆掏搯槄涛滔瑫絛縚縧绦翢蜪詜謟",
+            "鞱韜韬飸饕匋咷啕桃檮洮淘祹綯萄裪迯逃醄鋾錭陶鞀鞉饀駣騊鼗討讨套忑忒特脦蚮螣蟘貣鋱铽鴏",
+            "膯鼟儯幐滕漛疼籐籘縢腾藤誊謄駦騰驣鰧剔梯踢銻锑鷈鷉偍厗啼嗁媂崹惿提漽瑅睼碮禔禵稊穉綈",
+            "緹绨缇罤苐荑蕛蝭趧蹄蹏遆醍鍗題题騠鮷鯷鴺鵜鶗鶙鹈体挮躰軆骵體俤倜剃嚏嚔屉屜屟悌悐惕戻",
+            "掦揥替歒殢涕籊薙裼褅迏逖逷錫鐟钖髢髰鬀鬄倎兲天婖屇添酟靔靝黇塡填恬沺湉璳甛甜田畋畑盷",
+            "磌窴胋菾闐阗鷆唺忝悿晪殄淟痶睓腆舔覥觍賟餂掭煔瑱舚颋佻庣恌旫祧聎鮡岧岹条條樤祒笤芀萔",
+            "蜩趒迢鋚鎥鞗髫鰷鲦齠龆嬥宨挑斢晀朓窕眺窱粜糶絩脁覜跳帖怗萜貼贴僣鉄鐡鐵铁驖蛈飻餮厅厛",
+            "听庁廰廳桯汀耓聴聼聽艼鞓亭停婷嵉庭廷朾楟榳渟筳綎聤莛葶蜓蝏諪邒閮霆鼮侹圢挺梃涏烶珽脡",
+            "艇誔頲嗵囲樋熥狪痌蓪通仝佟僮勭同哃峂庝彤晍曈朣桐氃浵潼烔燑爞犝獞眮瞳砼秱穜童粡絧罿膧",
+            "茼蕫詷迵酮鉖鉵銅铜餇鮦鲖鼨姛捅桶筒筩統綂统恸慟憅痛蘳衕偷偸婾媮鍮亠坄头投牏酘頭骰妵紏",
+            "蘣鈄钭黈透凸堗捸涋痜禿秃突葖鋵鵚凃図图圖圗塗屠峹嵞庩廜徒怢捈揬梌涂湥潳瑹瘏稌筡腯荼莵",
+            "菟蒤跿途酴鈯鍎馟駼鶟鷋鷵鼵吐唋土圡釷钍兎兔堍鵵湍煓猯貒剸团団團慱抟摶槫檲漙糰鏄鷻疃黗",
+            "彖褖推蓷藬僓尵弚橔穨蘈蹪隤頹頺頽颓魋俀腿蹆骽娧煺蜕螁褪退駾吞呑啍噋旽暾朜涒屯忳拵臀臋",
+            "芚豘豚軘霕飩饨魨鲀畽乇侂侻咃堶托扥拕拖杔汑沰涶矺脫脱袥託詑讬饦馲驝魠佗坨岮狏砣砤碢紽",
+            "跎迱酡阤陀陁飥駝駞驒驼鮀鴕鸵鼉鼍鼧妥媠嫷庹彵撱椭楕橢鰖鵎唾柝毤毻箨籜萚蘀跅",
+        ),
+    ),
+    (
+        'W',
+        concat!(
+            "涡劸哇啘娲媧徍挖搲攨洼溛漥畖穵窊窪聉蛙鼃娃佤咓瓦邷嗢腽膃袜襪韈韤歪崴外剜塆帵弯彎湾潫",
+            "灣蜿豌丸刓婠完岏抏汍烷玩琓紈纨芄頑顽倇唍埦婉宛惋挽晚晩晼梚椀涴琬畹皖盌睌睕碗箢綩綰绾",
+            "脕菀萖葂輓鋔鍐鑁万仴卍卐忨捥綄翫腕蟃貦贃贎踠尢尣尩尪尫汪亡亾仼兦彺王蚟往徃惘暀枉棢瀇",
+            "網网罒罔菵蛧蝄誷輞辋迬魍妄忘旺望朢莣迋偎危喴威婔媁媙巍微愄揋椳楲渨溾烓煨燰碨葨葳薇蝛",
+            "覣逶隇隈鰃鰄鳂为唯囗围圍圩嵬帏帷幃惟桅欈沩洈涠湋潍潿濰瀢為爲琟癓矀硙維维覹违違鄬醀鍏",
+            "闈闱霺韋韦鮠亹伟伪偉儰厃喡壝委娓寪尾峗崣嵔徫愇斖暐梶椲洧浘炜煒猥玮瑋痏痿磈緯纬腲艉芛",
+            "苇荱萎葦蒍蓶蔿薳蘤蜲諉诿踓鍡韑韙韡韪頠颹骩骪骫鮪鲔位偽僞卫叞味喂媦嬒尉徻慰懀未渭熭犚",
+            "犩猬璏畏磑緭罻胃苿菋蔚薉藯蘶蜼蝟螱衛衞褽謂讆讏谓贀躗躛軎轊遺錗鏏霨餧餵魏鮇鳚塭昷殟温",
+            "溫瑥瘟瞃緼缊蕰豱輼轀辒馧鰛鰮鳁彣文炆珳琝璺瘒紋纹聞芠蚉蚊螡蟁閿闅闦闻阌雯馼駇魰鳼鴍鴖",
+            "鼤刎吻呡桽煴稳穏穩紊肳脗問妏抆揾搵汶渂熓问嗡奣嵡滃翁螉鎓霐鶲鹟勜塕暡浻瞈聬蓊瓮甕罋蕹",
+            "齆倭唩挝撾涹猧窝窩莴萵蜗踒婐我捰偓卧嬳幄捾握斡楃沃渥濣焥肟臥齷龌乌剭呜嗚圬媉屋巫弙杇",
+            "歍汙汚污洿烏窏箼腛螐誣诬邬鄔鎢钨陓鰞鴮俉吳吴吾呉唔娪峿庑无梧毋洖浯牾珸璑瞴祦禑芜茣莁",
+            "蕪蜈誈譕郚鋘鋙铻鯃鵐鷡鹀麌鼯乄五仵伍侮倵儛午啎妩娬嫵嵨廡忤怃憮捂摀旿橆武潕玝珷瑦甒碔",
+            "舞躌迕鵡鹉伆兀务務勿卼噁坞塢奦婺寤屼岉嵍忢悞悟悮戊扤敄晤杌沕溩焐煟熃物痦矹窹粅芴蓩誤",
+            "误軏逜遻鋈阢隖雾霚霧靰騖骛齀",
+        ),
+    ),
+    (
+        'X',
+        concat!(
+            "畜行巷纤戌厦俙傒僁僖兮凞卥厀吸唏嘻噏夕奚媐嬆嬉屖嵠巇希徆徯忚怷怸恓息悉悕惁惜扱扸昔晞",
+            "晰晳曦析桸榽樨橀欷歖氥汐浠淅渓溪潝烯焁焈煕熄熈熙熹熺熻燨爔牺犀犠犧琋瓗疧皙盻睎瞦矽硒",
+            "礂稀穸窸粞縘繥羲翕膝舾莃菥蒵蜥螇螝蟋蠵西覀觹觽觿譆豀豨豯貕赥郋酅醯鏭鑴锡闟隵饻騱驨鵗",
+            "黊鼷习媳嶍席棤椺槢檄欯漝焟焬獥瘜習蒠蓆薂袭襲覡觋謵趘鎴隰霫飁騽鰼鳛喜囍壐屣徙憘憙敼暿",
+            "枲洗漇狶玺璽矖禧簁縰纚葈葸蓰蟢諰蹝躧銑铣霼鱚係匸卌唽喺嚱屃屓屭忥怬恄戏戱椞滊潟澙熂犔",
+            "磶禊稧系細綌细绤翖肸肹舃舄蕮虩褉覤謑赩郄郤鄎釳釸鎎阋隙隟餼饩鬩黖齂傄岈煵疨瞎虾谺鍜閕",
+            "颬鰕侠俠冾匣峡峽搳暇柙炠烚狎狭狹珨瑕硖硤碬磍祫笚筪縀縖翈舝蕸赮轄辖遐鎋霞騢魻黠丅下吓",
+            "夏夓懗欱疜睱罅芐鏬鶷仙仚僊僲先嘕奾嬐孅屳廯忺憸掀暹杴枮氙澖灦珗祆秈籼繊纎纖苮蓒褼襳訮",
+            "跹蹮躚酰銛鍁铦锨韯韱馦鮮鱻鲜咞咸唌啣娴娹婱嫌嫺嫻弦憪挦撏涎燅甉痃痫癇癎瞯礥絃羬胘舷葴",
+            "藖蚿蛝衔衘諴賢贒贤輱銜閑闲鷳鷴鷼鹇鹹姺尟尠崄嶮幰搟攇显櫶毨烍燹猃獫獮玁癣癬禒筅箲藓蚬",
+            "蜆赻跣鍌险険險韅顕顯鼸齴伣俔僩僴县哯垷姭娊娨宪岘峴憲撊晛橌涀瀗献獻现現県睍粯絤綫線縣",
+            "线缐羡羨腺臔臽莧誢豏軐轞鋧錎限陥陷霰餡馅麲乡厢啌廂忀欀湘瓖相箱緗纕缃芗葙薌襄郷鄉鄊鄕",
+            "鑲镶香驤骧鱜麘佭庠栙祥絴羏翔詳详跭享响嶑想晑蚃響飨餉饗饟饷鮝鯗鱶鲞像勨向嚮恦曏橡潒珦",
+            "萫蟓蠁襐象鐌闀闂項项鱌削呺哓哮啋嘋嘐嘵嚣嚻婋宯宵彇憢撨枭枵梟櫹歊毊洨消涍潇瀟灱灲烋焇",
+            "獢痚痟硝硣穘窙箫簘簫綃绡膮萧萷蕭藃虈虓蟏蟰蠨踃逍銷销霄顤驍骁髇魈鴞鸮淆笅誵郩小晓暁曉",
+            "皢筱筿篠俲傚効咲啸嘨嘯孝恔效敩斅校歗滧熽笑肖詨誟踍鞩些嗋楔歇蝎蠍偕劦勰协協垥奊恊愶慀",
+            "拹挟搚携撷擕攜斜旪熁燲瑎籺綊纈缬翓胁脅脇脋脥膎蝢衺襭諧谐邪鞋鞵龤写冩寫藛蠏亵伳偰卨卸",
+            "塮妎娎媟屑屧嶰廨徢懈斺暬械榍榭泄泻洩渫澥瀉瀣灺焎燮爕獬疶祄禼糏紲絏絬緤绁缷薢薤蟹衸褻",
+            "謝谢躞躠邂鞢韰駴齘齥俽兟噷妡嬜廞心忄忻惞新昕欣歆炘盺芯薪訢辛邤鈊鋅鑫锌馨尋攳杺桪樳襑",
+            "鄩伈伩信卂囟孞焮煡脪膷舋衅訫釁顖馸兴垶惺星曐煋猩瑆皨篂腥興蛵觪觲鍟馫騂骍鮏鯹侀刑坓型",
+            "形洐滎濚濴烆硎硏胻荥邢郉鉶鋞铏陘擤渻睲醒倖姓婞嬹幸性悻杏涬緈臖荇莕兄兇凶匈忷恟汹洶胷",
+            "胸芎訩詾讻熊赨雄夐敻詗诇休俢修咻庥樇潃烌羞脩臹茠蓚蓨貅銝鎀鏅饈馐髤髹鵂鸺朽滫糔綇嗅岫",
+            "峀珛琇璓秀綉繍繡绣螑袖褎褏銹鏥鏽锈齅倠偦呴嘘墟媭嬃嬬幁旴楈欨欰歔殈疞盱稰籲縃繻胥蕦虗",
+            "虚虛蝑訏譃鑐需須頊须顼驉鬚魆魖徐蒣冔喣姁栩湑珝盨糈詡諝许诩谞鄦醑伃伵侐勖勗卹叙垿壻婿",
+            "序怴恤慉敍敘旭昫朂槒汿沀洫溆漵潊烅煦珬盢瞁瞲稸窢絮続緒緖續绪续聟芧蓄藇藚訹賉酗銊魣鱮",
+            "蓿儇吅喧埙塇塤媗宣弲愃愋揎昍晅暄梋煊瑄睻矎禤箮翧翾萱萲蕿藼蘐蝖蠉諠諼譞谖軒轩鋗鍹鶱伭",
+            "妶嫙悬懸旋暶檈漩玄玹琁璇璿蜁誸咺烜选選馔埍怰昡楥楦泫渲炫眩眴碹絢縼繏绚蔙衒袨讂贙鉉鏇",
+            "铉镟鞙颴駽吙嶨薛辥靴鞾乴壆学學峃斈泶澩燢穴茓觷踅雤鷽鸴膤雪鱈鳕坹岤桖泬血袕謞趐勋勛勲",
+            "勳坃壎壦峋曛焄熏燻獯矄窨纁臐蔒薫薰蘍醺偱噚寻巡廵循恂旬杊枔栒槆橁毥洵浔潯燖珣璕畃紃荀",
+            "蟳詢询馴駨驯鱏鱘鲟伨侚噀奞巺巽徇殉殾汛爋狥訊訓訙训讯賐迅迿逊遜鑂韗顨鵔鵕",
+        ),
+    ),
+    (
+        'Y',
+        concat!(
+            "吁丫压哑圧壓孲庘押煆鐚鴉鴨鵶鸦鸭伢堐崕崖枒涯漄牙犽猚琊睚笌芽蚜衙齖亞厊庌掗痖瘂蕥雃雅",
+            "亚亜俹劜圠娅婭挜揠氩氬猰玡砑稏窫聐襾訝讶迓錏铔鼼齾呀偣剦厭咽啱嫣嬮崦懕殷淹湮漹烟焉煙",
+            "猒珚篶胭臙菸鄢酀醃閹阉严厳喦嚴埏塩壛壧妍姸娫娮孍岩嵒嵓巌巖巗延揅昖楌檐櫩沿湺炎狿琂盐",
+            "研碞礹筵簷綖芫莚葕蔅虤蜒言訁詽讠郔鈆閆閰閻闫阎顃顏顔颜鹽麙麣乵俨偃儼兖兗匽厣厴夵奄姶",
+            "嬿嵃嶖巘巚弇惔愝戭扊抁掩揜曮棪椼檿沇渰渷演琰甗眼硽罨萒蝘衍裺褗躽遃郾酓隒馣魇魘鰋鶠黡",
+            "黤黭黶鼴鼹龑偐傿厌唁喭噞嚥堰墕妟姲嬊宴彥彦敥晏暥曕曣椻滟灎灔灧灩焔焰焱燄燕爓牪砚硯艳",
+            "艶艷覎觃觾諺讌讞谚谳豓豔贋贗赝酽醶醼釅隁雁餍饜騐験騴驗驠验鳫鴈鴳鷃鷰佒咉坱央姎柍殃泱",
+            "眏秧紻胦鉠雵鞅鴦鸯佯劷垟婸崵徉扬揚敭旸昜暘杨楊洋炀烊煬珜瑒疡瘍眻禓羊蛘諹輰鍚鐊阦阳陽",
+            "霷颺飏鰑鸉仰傟养岟慃懩抰攁氧氱炴痒癢羪蝆養駚怏恙样様樣漾瀁羕詇吆喓夭妖幺枖楆祅約腰葽",
+            "訞邀倄傜嗂垚堯姚媱尧尭峣崤嶢嶤徭愮揺搖摇暚柼榣殽烑爻猇猺珧瑤瑶窑窯窰繇肴蘨謠謡谣軺轺",
+            "遙遥颻飖餆餚鰩鳐仸偠咬婹宎岆崾抭杳榚殀溔狕眑窅窈窔舀苭蓔闄騕鷕齩曜熎燿獟矅穾筄耀艞药",
+            "葯薬藥袎要覞讑趭鑰钥靿鷂鹞倻噎掖椰潱耶蠮峫捓揶擨爷爺瑘釾鋣鎁铘也冶吔嘢埜壄漜野业亱偞",
+            "僷叶嚈墷夜嶪嶫忦擛擪擫晔曄曅曗曳曵枼枽業殗殜液澲烨煠燁爗皣瞱瞸腋謁谒邺鄴鍱鎑鐷靥靨頁",
+            "页餣饁馌驜鵺鸈一伊依医吚咿噫壱壹夁嫛嬄嶬弌悘揖曀檹毉洢溰漪燚猗瑿祎禕稦繄蛜衣衤譩郼醫",
+            "銥铱陭餏鷖鹥黟黳乁仪侇儀冝匜咦圯夷姨宐宜宧寲峓嶷巸弬彛彜彝彞怡恞扅暆杝柂桋椸歋沂沶洟",
+            "熪珆瓵疑痍眤眱移笫簃羠胰萓蛦螔衪袘袲觺訑詒誼謻讉诒貤貽贻跠迆迤迻遗酏鈶銕頉頤頥顊颐飴",
+            "鮧鸃乙以倚偯崺已庡扆掜攺旑旖晲椅檥矣礒笖肔胣舣艤苡苢蚁螘蟻輢轙逘釔鈘鉯钇顗鳦齮乂义亄",
+            "亦亿伇伿佚佾俋億兿刈劓劮勚勩呓呭呹唈囈圛垼埶埸墿奕妷嫕嬑嬟寱屹峄嶧帟帠幆廙异弈弋役忆",
+            "怈怿悒悥意憶懌懿抑抴挹捙撎敡易晹曎杙枍枻栧棭榏槷槸檍欭歝殔殪殹毅泆洂浂浥浳湙溢潩澺瀷",
+            "炈焲熠熤熼燡燱獈玴異疫痬瘗瘞瘱癔益瞖秇穓竩緆縊繶繹绎缢羛義羿翊翌翳翼肄肊膉臆艗艺芅苅",
+            "蓺薏藙藝蘙虉蛡蜴螠袣裔裛褹襼訲訳詣譯議讛议译诣谊豙豛豷跇軼轶逸邑醳醷釴鈠鎰鐿镒镱阣隿",
+            "霬靾顡饐駅驛驿骮鯣鶃鷁鷧鷾黓齸侌凐喑噾囙因垔堙姻婣峾愔慇摿栶歅氤洇洕溵瘖禋秵筃絪緸绬",
+            "茵荫蒑蔭裀諲銦铟闉阴陰陻隂霠鞇音韾駰骃黫冘吟噖嚚圁垠夤婬寅崟崯斦檭殥泿淫滛烎犾狺璌碒",
+            "苂荶蔩蟫訔訚訡誾鄞釿鈝銀鏔银霪鷣乚尹嶾廴引檃櫽淾濦瘾癮磤粌紖縯纼蘟蚓螾讔趛鈏隐隠隱靷",
+            "飮飲饮馻印垽廕慭憖憗懚朄檼湚濥猌癊胤茚酳鮣偀嘤嚶婴媖嫈嬰孆孾应応應撄攖朠桜楧樱櫻渶煐",
+            "珱瑛璎瓔甇甖碤礯緓纓缨罂罃罌膺英莺蘡蝧蠳褮譻鍈鑍锳霒韺鴬鶧鶯鷪鷹鸎鸚鹦鹰僌営塋嬴巆廮",
+            "攍楹櫿溁滢潆濙瀅瀛瀠瀯熒營瑩盁盈籝籯縈茔荧莹萤营萦萾蓥藀蛍蝇蝿螢蠅謍贏赢迎鎣巊影梬浧",
+            "潁瀴璄瘿癭矨穎郢頴颍颕颖噟媵摬映暎硬膡譍賏鐛鞕哟唷喲佣嗈噰墉壅庸廱慵拥擁滽澭灉牅痈癕",
+            "癰臃邕郺鄘銿鏞镛雍雝饔鱅鳙傛喁嫆嫞嬫嵱槦顒颙俑勇勈咏埇塎彮恿悀惥愑愹慂搈柡栐永泳涌甬",
+            "硧禜蛹詠踊踴銢鯒鲬用苚醟优優呦嚘幽忧怮悠憂懮攸櫌瀀纋耰鄾麀偤尤峳怣斿柚楢櫾沋油浟游滺",
+            "犹猶猷由疣秞肬莤莸蕕蚰蝣訧輏輶逌逰遊邎邮郵鈾铀駀魷鮋鱿鲉丣卣友岰庮有梄槱泑湵牖牗羐羑",
+            "聈脜苃莠蜏酉銪铕黝亴佑侑又右哊唀囿姷宥峟幼牰狖狛祐糿誘诱貁迶酭釉鴢鼬扜於毺淤瘀盓穻箊",
+            "紆纡虶迂迃于亐余俞唹堣堬妤娛娯娱媀嬩崳嵎嵛愉愚扵揄旟杅楡楰榆欤歈歟歶渔渝漁澞狳玗玙瑜",
+            "璵畬畭盂睮硢禺窬竽籅緰羭腴臾舁舆艅茰萸蕍蘛虞蝓螸衧褕覦觎諛謣谀踰輿轝逾邘鄃釪鍝隃隅雓",
+            "雩餘馀騟骬髃魚鮽鰅鱼鵌鷠鸆鸒齵与予伛俁俣偊傴匬噳圄圉宇寙屿嶼庾懙敔斔斞瑀瘐祤禹窳篽羽",
+            "聥與萭蘌螤語语貐鄅酑雨齬龉俼喅喐喩喻噊圫域堉妪嫗寓峪嶎庽彧御忬悆悇惐愈慾戫昱栯棛棜棫",
+            "櫲欎欝欥欲毓浴淢淯滪澦灪焴煜熨燏燠爩狱獄獝玉琙瘉癒矞砡硲礇礖礜禦秗稢稶穥籞緎繘罭聿肀",
+            "育芋芌茟萮蒮蓣蓹蕷薁蜟蜮袬裕誉諭譽谕豫軉輍逳遇遹郁醧鈺銉鋊錥鐭钰閾阈隩霱預预飫饇饫馭",
+            "驈驭鬰鬱鬻魊鳿鴥鴪鵒鷸鹆鹬黦龥冤剈囦嬽寃惌棩淵渁渆渊渕灁眢肙葾蒬蜎蜵裫裷鋺駌鳶鴛鵷鸢",
+            "鸳鹓鼘鼝元円原厡厵员員园圆圎園圓垣塬妧媛媴嫄援杬榞榬橼櫞沅湲源溒爰猨猭猿獂笎緣縁缘羱",
+            "蒝薗蚖蝝蝯螈袁貟贠轅辕邍邧鎱騵魭鶢鶰黿鼋妴盶远逺遠傆噮夗怨愿掾瑗禐苑衏褑褤謜院願曰曱",
+            "箹约刖妜岄岳嶽恱悅悦戉抈捳月枂樾泧瀹爚狘玥礿禴篗籆籥籰粤粵蘥蚎蚏越趯跀跃躍鈅鉞閱閲阅",
+            "鸑鸙龠奫晕暈氲氳縕蒀蒕蝹贇赟云伝勻匀妘愪昀枃榅榲橒沄涢溳澐熅熉畇眃秐筼篔紜縜纭耘耺芸",
+            "荺蒷蕓郧鄖鋆雲饂傊允喗抎殒殞狁玧磒褞賱輑鈗阭陨隕霣齳囩夽孕恽惲愠慍枟緷腪蕴薀藴蘊运運",
+            "郓鄆酝醖醞韞韫韵韻鶤",
+        ),
+    ),
+    (
+        'Z',
+        concat!(
+            "曾喳椎兹卒攒锗轧粘辗栅峙匝咂帀抸拶沞紮臜臢迊鉔偺囐嶻杂砸磼襍雑雜雥韴魳咋鮺哉栽渽災灾",
+            "烖甾菑賳仔儎宰崽縡載载侢傤再在扗兂簪簮糌鐕咱儧儹喒寁揝撍昝桚沯礸禶趱趲囋暂暫欑瓉瓒瓚",
+            "穳襸讃讚賛贊赞鄼錾鏨饡牂羘脏臧賍賘贓贜赃鍺髒駔驵塟奘弉臓臟葬銺傮糟蹧遭凿醩鑿早枣棗澡",
+            "璪薻藻蚤唕唣噪慥梍灶燥皁皂竃竈簉艁譟趮躁造髞则則啧嘖崱帻幘択择擇沢泽溭澤皟瞔笮箦耫舴",
+            "萴蔶蠈蠌諎謮責賾责赜迮鰂鲗齚仄夨庂昃昗汄稄戝賊贼鱡怎譛増增憎橧熷璔矰磳繒罾譄驓甑綜贈",
+            "赠鋥锃偧吒哳扎抯挓揸摣柤樝渣皶皻謯齄齇劄拃札炸牐甴紥蚻蠿譗鍘铡閘闸霅厏眨砟苲踷鮓鲊鲝",
+            "乍咜咤宱搾柞柵榨溠灹簎膪蚱詐诈醡捚摘斋斎榸齋宅礋岝窄鉙飵债債寨瘵砦呫嶦旃旜栴毡氈氊沾",
+            "瞻薝詀詹譠譫讝谵趈邅鉆霑饘驙鱣鸇鹯嫸展崭嶃嶄搌斩斬榐樿橏琖皽盏盞蹍輾醆颭飐魙佔偡占嶘",
+            "战戦戰栈桟棧椾湛站綻绽菚蘸虥虦覱蹔輚轏驏傽墇嫜张張彰慞暲樟漳獐璋章蔁蟑遧鄣餦騿鱆麞仉",
+            "掌涨漲礃鞝丈仗嶂帐帳幛扙杖涱痮瘬瘴瞕粀胀脹賬账障妱巶招昭柖盄釗鉊鍣钊駋鸼找沼爪瑵菬兆",
+            "召垗旐曌枛棹櫂炤照燳狣瞾笊箌罩羄肁肇肈詔诏赵趙雿鵫嗻嫬晢晣蜇遮厇哲啠喆嚞埑慴折摺歽瓋",
+            "砓磔籷耴虴蛰蟄襵詟謫謺讁讋讘谪輒輙辄辙銸鮿鸅啫禇者褶赭柘檡浙烢蔗蟅这這鷓鹧着侦偵堻媜",
+            "嫃寊帪揁搸斟栕桢楨榛樼殝溱潧澵獉珍珎瑊甄眞真砧碪祯禎禛箴籈缜胗臻蒖蒧蓁薽診貞贞轃遉酙",
+            "針鉁錱鍼针靕駗鱵鷏姫弫抌抮昣晸枕畛疹眕祳稹笉紾絼縝縥聄袗覙诊軫轸辴鬒黕黰侲圳塦挋振揕",
+            "朕栚桭眹蜄誫賑赈鎭鎮镇阵陣震鴆鸩争佂埩姃峥崢征徰徴徵怔挣掙炡烝爭狰猙症癥眐睁睜筝箏篜",
+            "聇蒸諍诤踭鉦錚鏳钲铮鬇愸抍拯撜整氶糽帧幀政正証證证郑鄭鴊之倁卮吱巵掷搘支枝枳栀梔椥榰",
+            "汁汥泜知祗祬秓秖秪綕織织肢胑胝脂芝蜘衼觯隻馶鳷鴲鼅侄値值儨坧埴執墌姪嬂慹懫执摭擿柣桎",
+            "植樴殖漐犆瓆瓡直禃秷稙絷縶聀职職膱蘵蟙褁跖踯蹠躑軄釞馽劧厎只咫址坁夂帋恉抧指旨晊栺止",
+            "沚洔淽疻砋祉紙纸芷藢襧訨趾軹轵酯阯黹偫制劕厔垁娡寘崻帙帜幟庢庤彘徏徝志忮憄懥挃挚搱摯",
+            "擲旘智梽櫍櫛治洷淛滍滞滯潌炙熫狾猘畤疐痔痣礩祑秩秲稚稺窒筫紩緻置翐胵膣至致臸芖蛭螲袟",
+            "袠製覟觗觢觶誌豑豒豸貭質贄质贽跱踬躓輊轾迣遰郅銍鋕鑕铚锧陟陦隲雉駤騭騺驇骘鴙鴩鷙鸷中",
+            "伀刣妐幒彸忠柊汷泈潨炂煄盅籦終终舯蔠螽衳衷蹱鈡鍾鐘钟锺鴤冢喠塚尰歱瘇种種肿腫踵乑仲众",
+            "偅堹妕媑湩狆眾筗緟茽蚛衆衶諥重侜周喌州徟洀洲淍烐珘盩矪粥舟謅譸诌诪賙赒輈輖辀週郮銂霌",
+            "騆鵃妯碡軸轴帚晭疛睭箒肘菷鯞伷僽冑呪咒咮噣宙怞昼晝甃皱皺籀籒籕粙紂縐纣绉胄荮葤詋酎駎",
+            "驟驺骤侏劯朱株槠橥櫧櫫洙潴瀦猪珠硃絑茱蛛蝫袾觰誅諸诛诸豬邾銖铢駯鮢鴸鼄劅劚斸曯朮灟炢",
+            "烛燭爥瘃窋竹竺笁笜築篫舳茿蓫蠋蠾趉躅逐鱁丶主嘱囑壴孎宔拄欘渚濐煑煮眝瞩矚砫罜詝貯陼麈",
+            "伫佇住助坾嵀杼柱柷樦櫡殶注炷疰祝祩秼竚筑筯箸紵紸纻羜翥莇著蛀註諔贮跓軴鉒鋳鑄铸霔飳馵",
+            "駐驻麆抓檛髽拽転专塼嫥専專瑼甎砖磚篿膞蟤鄟顓颛鱄鷒囀竱轉转僎啭堟撰灷瑑篆籑縳蒃襈譔賺",
+            "赚饌妆妝娤庄桩梉樁粧糚荘莊装裝壮壯壵撞焋状狀娺追錐锥騅骓鴭鵻沝坠墜惴硾礈窡笍綴縋缀缒",
+            "膇譵贅赘錺鑆餟宒窀肫衠訰諄谆迍准凖準綧鶽稕倬拙捉桌涿穛鐯丵剢卓啄啅妰彴撯擆擢斀斫斮斱",
+            "斲斵晫梲棳椓槕汋泎浊浞濁濯灂灼炪烵焯犳琢琸硺禚篧籱罬茁蝃蠗諑謶诼酌鋜錣鐲镯鷟咨嗞姕姿",
+            "孖孜孳孶崰嵫栥椔淄湽滋澬玆禌秶稵紎緇缁茊茲葘蠀觜訾諮谘貲資赀资趑趦輜輺辎鄑鈭錙鍿鎡锱",
+            "镃頾頿髭鯔鰦鲻鴜鶅鶿鼒齜龇吇呰啙姉姊子杍梓榟滓矷秄秭籽紫耔胏芓虸訿釨倳剚字恣扻渍漬牸",
+            "眥眦胔胾自茡倧堫宗嵏嵕嵸惾棕椶熧猣磫稯緃緵縂縱综翪腙艐葼蝬豵踨踪蹤騌騣骔鬃鬉鬷鯮鯼偬",
+            "傯总惣愡憁捴揔搃摠朡總蓗倊昮猔疭瘲碂粽糉糭縦纵媰掫棷棸箃緅菆諏诹邹郰鄒鄹陬騶鯫鲰黀齺",
+            "走赱奏揍租蒩傶卆哫崒崪捽族足踤踿蹵鎐镞俎唨爼珇祖組组詛诅阻靻躜躦鑚鑽钻籫繤纂纉纘缵攥",
+            "厜嶉樶纗蟕嘴噿嶊洅璻墬嶵晬最栬槜檇檌祽絊罪蕞辠酔酻醉鋷錊墫壿尊嶟樽繜罇遵鐏鱒鳟鷷僔噂",
+            "撙譐捘銌昨椊秨稓筰葃鈼佐咗左毑繓作做坐夎岞座怍祚糳胙葄袏阼",
+        ),
+    ),
+];
+
+/// 多音字按证券简称中的读法
+const OVERRIDES: &[(char, char)] = &[
+    ('行', 'H'),
+    ('重', 'C'),
+    ('藏', 'Z'),
+    ('长', 'C'),
+    ('乐', 'L'),
+    ('晟', 'S'),
+];
+
+static INITIALS: once_cell::sync::Lazy<HashMap<char, char>> = once_cell::sync::Lazy::new(|| {
+    let mut map: HashMap<char, char> = TABLE
+        .iter()
+        .flat_map(|(initial, chars)| chars.chars().map(move |c| (c, *initial)))
+        .collect();
+    map.extend(OVERRIDES.iter().copied());
+    map
+});
+
+/// 单个汉字的拼音首字母
+pub fn initial(c: char) -> Option<char> {
+    INITIALS.get(&c).copied()
+}
+
+/// 简称的拼音首字母（大写），字母和数字原样保留，如 "*ST华仪" → "STHY"
+pub fn initials(name: &str) -> String {
+    name.chars()
+        .filter_map(|c| {
+            if c.is_ascii_alphanumeric() {
+                Some(c.to_ascii_uppercase())
+            } else {
+                initial(c)
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_initials() {
+        assert_eq!(initials("贵州茅台"), "GZMT");
+        assert_eq!(initials("平安银行"), "PAYH");
+        assert_eq!(initials("*ST华仪"), "STHY");
+        assert_eq!(initials("重庆啤酒"), "CQPJ");
+        assert_eq!(initials("沪深300ETF"), "HS300ETF");
+    }
+
+    #[test]
+    fn test_initials_beyond_gb2312_level1() {
+        assert_eq!(initials("泸州老窖"), "LZLJ");
+        assert_eq!(initials("鑫铂股份"), "XBGF");
+        assert_eq!(initials("晟楠科技"), "SNKJ");
+        assert_eq!(initials("昊华科技"), "HHKJ");
+        assert_eq!(initials("睿创微纳"), "RCWN");
+        assert_eq!(initials("玲珑轮胎"), "LLLT");
+        assert_eq!(initials("蠡湖股份"), "LHGF");
+        assert_eq!(initials("璞泰来"), "PTL");
+    }
+}
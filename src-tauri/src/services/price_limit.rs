@@ -0,0 +1,237 @@
+//! 涨跌停价计算
+//!
+//! 涨跌幅比例由板块和风险警示状态决定：主板 10%（ST 5%）、创业板/科创板 20%、北交所 30%，
+//! ETF 按跟踪指数所在板块（创业板/科创板 ETF 20%，其余 10%），指数没有涨跌幅限制；
+//! 新股上市后前若干个交易日（按交易日历）不设涨跌幅限制：主板、创业板、科创板 5 天，北交所仅上市首日。
+//! 涨跌停价 = 前收盘价 × (1 ± 比例)，按最小价格变动单位四舍五入：
+//! 股票和深市 B 股 0.01，ETF 和沪市 B 股 0.001。
+//! 计算在整数上进行，避免 10.01 × 1.1 这类浮点误差。
+
+use crate::services::market::StockQuote;
+use crate::services::calendar;
+use crate::services::security::{self, Security};
+use chrono::NaiveDate;
+use serde::Serialize;
+
+/// 新股不设涨跌幅限制的交易日数（含上市当日）
+fn ipo_free_days(board: Option<&str>) -> i64 {
+    match board {
+        Some("北交所") => 1,
+        _ => 5,
+    }
+}
+
+/// 涨跌停价
+#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
+pub struct PriceLimit {
+    /// 涨跌幅比例（%）
+    pub ratio: u32,
+    pub limit_up: f64,
+    pub limit_down: f64,
+}
+
+/// 涨跌幅比例（%），None 表示不设限制（指数）。ETF 的 board 为跟踪指数所在板块
+pub fn limit_ratio(board: Option<&str>, sec_type: &str, is_st: bool) -> Option<u32> {
+    match sec_type {
+        "index" => None,
+        "etf" => match board {
+            Some("创业板") | Some("科创板") => Some(20),
+            _ => Some(10),
+        },
+        _ => match board {
+            Some("创业板") | Some("科创板") => Some(20),
+            Some("北交所") => Some(30),
+            _ if is_st => Some(5),
+            _ => Some(10),
+        },
+    }
+}
+
+/// ETF 跟踪指数所在板块：科创板 ETF 代码为 588 开头，其余按简称判断（双创 ETF 同时跟踪两个板块，也是 20%）
+fn etf_board(code: &str, name: &str) -> Option<&'static str> {
+    if code.starts_with("588") || name.contains("科创") || name.contains("双创") {
+        Some("科创板")
+    } else if name.contains("创业") || name.contains("创成长") {
+        Some("创业板")
+    } else {
+        None
+    }
+}
+
+/// 价格的小数位数（最小变动单位）
+fn decimals(sec_type: &str, exchange: &str) -> u32 {
+    match (sec_type, exchange) {
+        ("etf", _) | ("b_share", "SH") => 3,
+        _ => 2,
+    }
+}
+
+/// 由前收盘价计算涨跌停价
+pub fn compute(prev_close: f64, ratio: u32, decimals: u32) -> Option<PriceLimit> {
+    if prev_close.is_nan() || prev_close <= 0.0 {
+        return None;
+    }
+    let unit = 10i64.pow(decimals);
+    let prev = (prev_close * unit as f64).round() as i64;
+    let ratio = ratio as i64;
+    // 四舍五入：(prev × (100 ± r) + 50) / 100
+    let up = (prev * (100 + ratio) + 50) / 100;
+    let down = ((prev * (100 - ratio) + 50) / 100).max(1);
+    Some(PriceLimit {
+        ratio: ratio as u32,
+        limit_up: up as f64 / unit as f64,
+        limit_down: down as f64 / unit as f64,
+    })
+}
+
+/// 上市后的交易日数（含上市当日）
+fn days_since_listing(listing_date: &str, today: NaiveDate) -> Option<i64> {
+    let listed = NaiveDate::parse_from_str(listing_date, "%Y-%m-%d").ok()?;
+    if listed > today {
+        return Some(0);
+    }
+    // 只关心前几个交易日，超过一个月的不必逐日计算
+    if (today - listed).num_days() > 30 {
+        return Some(i64::MAX);
+    }
+    Some(calendar::trading_days(listed, today).len() as i64)
+}
+
+/// 证券的涨跌停价；指数、新股上市初期不设涨跌幅限制的交易日返回 None
+pub fn for_security(security: &Security, prev_close: f64, today: NaiveDate) -> Option<PriceLimit> {
+    if let Some(days) = security.listing_date.as_deref().and_then(|d| days_since_listing(d, today)) {
+        if days <= ipo_free_days(security.board.as_deref()) {
+            return None;
+        }
+    }
+    let board = match security.sec_type.as_str() {
+        "etf" => etf_board(&security.code, &security.name),
+        _ => security.board.as_deref(),
+    };
+    let ratio = limit_ratio(board, &security.sec_type, security.is_st)?;
+    compute(prev_close, ratio, decimals(&security.sec_type, &security.exchange))
+}
+
+/// 按代码、简称计算涨跌停价：优先用证券主表，主表中没有时按号段推断类型和板块，ST 以简称为准
+pub fn for_symbol(symbol: &str, name: &str, prev_close: f64, today: NaiveDate) -> Option<PriceLimit> {
+    let security = security::lookup(symbol).unwrap_or_else(|| {
+        let exchange = security::infer_exchange(symbol);
+        Security::new(symbol, exchange, name, security::infer_sec_type(symbol), None)
+    });
+    // 简称变化（戴帽/摘帽）比主表同步更及时
+    let security = Security {
+        is_st: if name.is_empty() { security.is_st } else { name.to_uppercase().contains("ST") },
+        ..security
+    };
+    for_security(&security, prev_close, today)
+}
+
+/// 行情的涨跌停价
+pub fn for_quote(quote: &StockQuote, today: NaiveDate) -> Option<PriceLimit> {
+    for_symbol(&quote.symbol, &quote.name, quote.prev_close, today)
+}
+
+/// 填充行情的涨跌停价
+pub fn apply(quote: &mut StockQuote) {
+    let limit = for_quote(quote, calendar::today());
+    quote.limit_up = limit.map(|l| l.limit_up);
+    quote.limit_down = limit.map(|l| l.limit_down);
+}
+
+/// 是否涨停（现价等于涨停价）
+pub fn is_limit_up(quote: &StockQuote) -> bool {
+    quote.limit_up.is_some_and(|p| quote.price > 0.0 && quote.price >= p - 1e-6)
+}
+
+/// 是否跌停（现价等于跌停价）
+pub fn is_limit_down(quote: &StockQuote) -> bool {
+    quote.limit_down.is_some_and(|p| quote.price > 0.0 && quote.price <= p + 1e-6)
+}
+
+/// 是否炸板（盘中触及涨停价，现价已打开）
+pub fn is_limit_broken(quote: &StockQuote) -> bool {
+    quote
+        .limit_up
+        .is_some_and(|p| quote.price > 0.0 && quote.high >= p - 1e-6 && quote.price < p - 1e-6)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn security(code: &str, board: Option<&str>, sec_type: &str, is_st: bool, listing_date: Option<&str>) -> Security {
+        Security {
+            code: code.to_string(),
+            exchange: security::infer_exchange(code).to_string(),
+            name: String::new(),
+            board: board.map(|b| b.to_string()),
+            sec_type: sec_type.to_string(),
+            is_st,
+            listing_date: listing_date.map(|d| d.to_string()),
+            pinyin: String::new(),
+        }
+    }
+
+    #[test]
+    fn test_rounding() {
+        // 10.01 × 1.1 = 11.011 → 11.01；× 0.9 = 9.009 → 9.01
+        let l = compute(10.01, 10, 2).unwrap();
+        assert_eq!((l.limit_up, l.limit_down), (11.01, 9.01));
+        // 四舍五入：8.15 × 1.1 = 8.965 → 8.97
+        assert_eq!(compute(8.15, 10, 2).unwrap().limit_up, 8.97);
+        // ST 5%：3.33 × 1.05 = 3.4965 → 3.50
+        assert_eq!(compute(3.33, 5, 2).unwrap().limit_up, 3.5);
+        // ETF 三位小数
+        assert_eq!(compute(3.861, 10, 3).unwrap().limit_up, 4.247);
+        assert_eq!(compute(0.0, 10, 2), None);
+    }
+
+    #[test]
+    fn test_board_rules() {
+        let today = NaiveDate::from_ymd_opt(2025, 6, 30).unwrap();
+        let main_st = security("600289", Some("主板"), "stock", true, Some("2000-05-25"));
+        assert_eq!(for_security(&main_st, 2.0, today).unwrap().limit_up, 2.1);
+        let gem = security("300750", Some("创业板"), "stock", true, None);
+        assert_eq!(for_security(&gem, 200.0, today).unwrap().limit_down, 160.0);
+        let bj = security("830799", Some("北交所"), "stock", false, None);
+        assert_eq!(for_security(&bj, 10.0, today).unwrap().limit_up, 13.0);
+        let index = security("000300", None, "index", false, None);
+        assert_eq!(for_security(&index, 4000.0, today), None);
+
+        // 6 月 24 日（周二）上市：截至 6 月 30 日是第 5 个交易日，仍不设限
+        let ipo = security("301999", Some("创业板"), "stock", false, Some("2025-06-24"));
+        assert_eq!(for_security(&ipo, 30.0, today), None);
+        let next_day = NaiveDate::from_ymd_opt(2025, 7, 1).unwrap();
+        assert!(for_security(&ipo, 30.0, next_day).is_some());
+
+        // 北交所只有上市首日不设限，第 2 个交易日起 30%
+        let bj_ipo = security("920001", Some("北交所"), "stock", false, Some("2025-06-27"));
+        let listing_day = NaiveDate::from_ymd_opt(2025, 6, 27).unwrap();
+        assert_eq!(for_security(&bj_ipo, 10.0, listing_day), None);
+        assert_eq!(for_security(&bj_ipo, 10.0, today).unwrap().limit_up, 13.0);
+    }
+
+    #[test]
+    fn test_etf_and_b_share() {
+        let today = NaiveDate::from_ymd_opt(2025, 6, 30).unwrap();
+        let etf = |code: &str, name: &str| Security {
+            name: name.to_string(),
+            ..security(code, None, "etf", false, None)
+        };
+        // 沪深300ETF 10%，创业板 / 科创板 / 双创 ETF 20%，均为三位小数
+        assert_eq!(for_security(&etf("510300", "沪深300ETF"), 3.861, today).unwrap().limit_up, 4.247);
+        assert_eq!(for_security(&etf("159915", "创业板ETF"), 2.0, today).unwrap().limit_up, 2.4);
+        assert_eq!(for_security(&etf("588000", "科创50ETF"), 1.0, today).unwrap().limit_down, 0.8);
+        assert_eq!(for_security(&etf("159781", "双创50ETF"), 1.0, today).unwrap().ratio, 20);
+
+        // 沪市 B 股最小变动单位 0.001 美元：0.457 × 1.1 = 0.5027 → 0.503；深市 B 股 0.01 港元
+        let sh_b = security("900901", Some("主板"), "b_share", false, None);
+        assert_eq!(for_security(&sh_b, 0.457, today).unwrap().limit_up, 0.503);
+        let sz_b = security("200596", Some("主板"), "b_share", false, None);
+        assert_eq!(for_security(&sz_b, 90.15, today).unwrap().limit_up, 99.17);
+        // 主表中没有时按号段识别 B 股和 ETF
+        assert_eq!(for_symbol("900901", "", 0.457, today).unwrap().limit_up, 0.503);
+        assert_eq!(for_symbol("510300", "沪深300ETF", 3.861, today).unwrap().limit_up, 4.247);
+        assert_eq!(for_symbol("159915", "创业板ETF", 2.0, today).unwrap().ratio, 20);
+    }
+}
@@ -0,0 +1,741 @@
+//! 东方财富数据源
+
+use super::{KlineRange, MarketDataProvider, ProviderFuture, LIMIT_CANDIDATE_PCT};
+use crate::services::security::{self, Security};
+use crate::services::{batch, calendar};
+use crate::services::kline::{AdjustMode, KlineBar};
+use crate::services::limit_ladder::LimitPoolEntry;
+use crate::services::market::{secid, BatchQuotes, Board, QuoteError, StockQuote, StockSearchResult};
+use crate::services::order_book::{OrderBook, OrderBookLevel, TradeTick};
+use crate::services::timeline::{Timeline, TimelinePoint};
+use std::collections::HashMap;
+use std::time::Duration;
+
+pub const NAME: &str = "eastmoney";
+
+/// 批量行情每次请求的股票数（受 URL 长度限制）
+const ULIST_CHUNK: usize = 100;
+/// 批量行情同时进行的请求数
+const ULIST_CONCURRENCY: usize = 4;
+const ULIST_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// 证券主表各类型的列表过滤条件（fs）
+const SECURITY_CATEGORIES: &[(&str, &str)] = &[
+    // 沪主板、科创板、深主板、创业板、北交所
+    ("stock", "m:1+t:2,m:1+t:23,m:0+t:6,m:0+t:80,m:0+t:81+s:2048"),
+    ("b_share", "m:1+t:3,m:0+t:7"),
+    ("etf", "b:MK0021,b:MK0022,b:MK0023,b:MK0024"),
+    ("index", "m:1+s:2,m:0+t:5"),
+];
+
+pub struct EastmoneyProvider;
+
+impl MarketDataProvider for EastmoneyProvider {
+    fn name(&self) -> &'static str {
+        NAME
+    }
+
+    fn quote<'a>(&'a self, symbol: &'a str) -> ProviderFuture<'a, StockQuote> {
+        Box::pin(fetch_stock_quote(symbol))
+    }
+
+    fn batch_quotes<'a>(&'a self, symbols: &'a [String]) -> ProviderFuture<'a, BatchQuotes> {
+        Box::pin(fetch_batch_quotes(symbols))
+    }
+
+    fn search<'a>(&'a self, keyword: &'a str) -> ProviderFuture<'a, Vec<StockSearchResult>> {
+        Box::pin(search_stocks(keyword))
+    }
+
+    fn klines<'a>(
+        &'a self,
+        symbol: &'a str,
+        range: KlineRange,
+        adjust: AdjustMode,
+    ) -> ProviderFuture<'a, Vec<KlineBar>> {
+        Box::pin(fetch_klines(symbol, range, adjust))
+    }
+
+    fn limit_stocks<'a>(&'a self, limit_type: &'a str) -> ProviderFuture<'a, Vec<StockQuote>> {
+        Box::pin(fetch_limit_stocks(limit_type))
+    }
+
+    fn order_book<'a>(&'a self, symbol: &'a str) -> ProviderFuture<'a, OrderBook> {
+        Box::pin(fetch_order_book(symbol))
+    }
+
+    fn ticks<'a>(&'a self, symbol: &'a str, count: usize) -> ProviderFuture<'a, Vec<TradeTick>> {
+        Box::pin(fetch_ticks(symbol, count))
+    }
+
+    fn timeline<'a>(&'a self, symbol: &'a str, days: usize) -> ProviderFuture<'a, Vec<Timeline>> {
+        Box::pin(fetch_timeline(symbol, days))
+    }
+
+    fn limit_pool(&self) -> ProviderFuture<'_, Vec<LimitPoolEntry>> {
+        Box::pin(fetch_limit_pool())
+    }
+
+    fn boards(&self) -> ProviderFuture<'_, Vec<Board>> {
+        Box::pin(fetch_boards())
+    }
+
+    fn board_members<'a>(&'a self, board_code: &'a str) -> ProviderFuture<'a, Vec<String>> {
+        Box::pin(fetch_board_members(board_code))
+    }
+
+    fn securities<'a>(&'a self, sec_type: &'a str) -> ProviderFuture<'a, Vec<Security>> {
+        Box::pin(fetch_securities(sec_type))
+    }
+}
+
+/// 获取单股实时行情
+async fn fetch_stock_quote(symbol: &str) -> Result<StockQuote, String> {
+    let url = format!(
+        "https://push2.eastmoney.com/api/qt/stock/get?secid={}&fields=f43,f44,f45,f46,f47,f48,f50,f57,f58,f60,f85,f116,f170&fltt=2&invt=2",
+        secid(symbol)
+    );
+
+    let client = reqwest::Client::new();
+    let resp = client
+        .get(&url)
+        .header("User-Agent", "Mozilla/5.0")
+        .header("Referer", "https://quote.eastmoney.com/")
+        .send()
+        .await
+        .map_err(|e| format!("请求失败: {}", e))?;
+
+    let json: serde_json::Value = resp.json().await.map_err(|e| format!("解析失败: {}", e))?;
+
+    let data = json.get("data").ok_or("返回数据为空")?;
+
+    let price = data["f43"].as_f64().unwrap_or(0.0);
+    let prev_close = data["f60"].as_f64().unwrap_or(0.0);
+    let change = price - prev_close;
+    let change_percent = if prev_close > 0.0 {
+        (change / prev_close) * 100.0
+    } else {
+        data["f170"].as_f64().unwrap_or(0.0)
+    };
+
+    Ok(StockQuote {
+        symbol: data["f57"].as_str().unwrap_or(symbol).to_string(),
+        name: data["f58"].as_str().unwrap_or("").to_string(),
+        price,
+        change,
+        change_percent,
+        volume: data["f47"].as_f64().unwrap_or(0.0),
+        high: data["f44"].as_f64().unwrap_or(0.0),
+        low: data["f45"].as_f64().unwrap_or(0.0),
+        open: data["f46"].as_f64().unwrap_or(0.0),
+        prev_close,
+        turnover: data["f48"].as_f64().unwrap_or(0.0),
+        volume_ratio: data["f50"].as_f64().unwrap_or(0.0),
+        pe_ratio: 0.0, // 需要额外字段
+        market_cap: data["f116"].as_f64().unwrap_or(0.0),
+        float_shares: data["f85"].as_f64().unwrap_or(0.0),
+        timestamp: chrono::Local::now().format("%Y-%m-%d %H:%M:%S").to_string(),
+        limit_up: None,
+        limit_down: None,
+    })
+}
+
+/// 批量行情：ulist 接口一次请求多只股票，按 secid 对应回请求的代码
+async fn fetch_batch_quotes(symbols: &[String]) -> Result<BatchQuotes, String> {
+    let mut unique: Vec<String> = Vec::new();
+    for s in symbols {
+        if !unique.contains(s) {
+            unique.push(s.clone());
+        }
+    }
+    let chunks: Vec<Vec<String>> = unique.chunks(ULIST_CHUNK).map(|c| c.to_vec()).collect();
+    let requests = chunks.iter().cloned().map(fetch_ulist).collect();
+    let responses = batch::run_bounded(requests, ULIST_CONCURRENCY, ULIST_TIMEOUT).await;
+
+    let mut result = BatchQuotes::default();
+    for (chunk, response) in chunks.into_iter().zip(responses) {
+        match response {
+            Ok(mut by_secid) => {
+                for symbol in chunk {
+                    match by_secid.remove(&secid(&symbol)) {
+                        // 保留请求的代码：sh000001 与 000001 返回的代码相同
+                        Some(Some(quote)) => result.quotes.push(StockQuote { symbol, ..quote }),
+                        Some(None) => result.errors.push(QuoteError {
+                            symbol,
+                            error: "停牌/无行情".to_string(),
+                        }),
+                        None => result.errors.push(QuoteError {
+                            symbol,
+                            error: "未返回行情（代码无效或已退市）".to_string(),
+                        }),
+                    }
+                }
+            }
+            Err(e) => result.errors.extend(chunk.into_iter().map(|symbol| QuoteError {
+                symbol,
+                error: e.clone(),
+            })),
+        }
+    }
+    Ok(result)
+}
+
+/// 请求一批行情，结果按 secid（市场.代码）索引
+/// 一批股票的行情，键为 secid；停牌等没有最新价的股票为 None
+async fn fetch_ulist(symbols: Vec<String>) -> Result<HashMap<String, Option<StockQuote>>, String> {
+    let secids: Vec<String> = symbols.iter().map(|s| secid(s)).collect();
+    let url = format!(
+        "https://push2.eastmoney.com/api/qt/ulist.np/get?fltt=2&invt=2&np=1&secids={}&fields=f2,f3,f4,f5,f6,f9,f10,f12,f13,f14,f15,f16,f17,f18,f20,f21",
+        secids.join(",")
+    );
+
+    let client = reqwest::Client::new();
+    let resp = client
+        .get(&url)
+        .header("User-Agent", "Mozilla/5.0")
+        .header("Referer", "https://quote.eastmoney.com/")
+        .send()
+        .await
+        .map_err(|e| format!("请求失败: {}", e))?;
+
+    let json: serde_json::Value = resp.json().await.map_err(|e| format!("解析失败: {}", e))?;
+    let timestamp = chrono::Local::now().format("%Y-%m-%d %H:%M:%S").to_string();
+
+    let quotes = json["data"]["diff"]
+        .as_array()
+        .into_iter()
+        .flatten()
+        .map(|item| {
+            let code = item["f12"].as_str().unwrap_or("");
+            let market = item["f13"].as_i64().unwrap_or(0);
+            (format!("{}.{}", market, code), ulist_quote(item, &timestamp))
+        })
+        .collect();
+    Ok(quotes)
+}
+
+/// ulist 返回的单只股票行情；停牌股的价格字段为 "-"，没有可用的最新价时返回 None
+fn ulist_quote(item: &serde_json::Value, timestamp: &str) -> Option<StockQuote> {
+    let price = item["f2"].as_f64().filter(|p| *p > 0.0)?;
+    let float_cap = item["f21"].as_f64().unwrap_or(0.0);
+    Some(StockQuote {
+        symbol: item["f12"].as_str().unwrap_or("").to_string(),
+        name: item["f14"].as_str().unwrap_or("").to_string(),
+        price,
+        change: item["f4"].as_f64().unwrap_or(0.0),
+        change_percent: item["f3"].as_f64().unwrap_or(0.0),
+        volume: item["f5"].as_f64().unwrap_or(0.0),
+        high: item["f15"].as_f64().unwrap_or(0.0),
+        low: item["f16"].as_f64().unwrap_or(0.0),
+        open: item["f17"].as_f64().unwrap_or(0.0),
+        prev_close: item["f18"].as_f64().unwrap_or(0.0),
+        turnover: item["f6"].as_f64().unwrap_or(0.0),
+        volume_ratio: item["f10"].as_f64().unwrap_or(0.0),
+        pe_ratio: item["f9"].as_f64().unwrap_or(0.0),
+        market_cap: item["f20"].as_f64().unwrap_or(0.0),
+        float_shares: float_cap / price,
+        timestamp: timestamp.to_string(),
+        limit_up: None,
+        limit_down: None,
+    })
+}
+
+/// 五档盘口：f11-f20 为买五到买一（价、量交替），f31-f40 为卖五到卖一，量的单位为手
+async fn fetch_order_book(symbol: &str) -> Result<OrderBook, String> {
+    let url = format!(
+        "https://push2.eastmoney.com/api/qt/stock/get?secid={}&fields=f11,f12,f13,f14,f15,f16,f17,f18,f19,f20,f31,f32,f33,f34,f35,f36,f37,f38,f39,f40,f43,f57,f58,f60&fltt=2&invt=2",
+        secid(symbol)
+    );
+
+    let client = reqwest::Client::new();
+    let resp = client
+        .get(&url)
+        .header("User-Agent", "Mozilla/5.0")
+        .header("Referer", "https://quote.eastmoney.com/")
+        .send()
+        .await
+        .map_err(|e| format!("请求失败: {}", e))?;
+
+    let json: serde_json::Value = resp.json().await.map_err(|e| format!("解析失败: {}", e))?;
+    let data = json.get("data").filter(|d| !d.is_null()).ok_or("返回数据为空")?;
+
+    // 没有挂单的档位价格为 "-"
+    let level = |price_field: &str, volume_field: &str| {
+        let price = data[price_field].as_f64().filter(|p| *p > 0.0)?;
+        Some(OrderBookLevel {
+            price,
+            volume: data[volume_field].as_f64().unwrap_or(0.0),
+        })
+    };
+    let bids = [("f19", "f20"), ("f17", "f18"), ("f15", "f16"), ("f13", "f14"), ("f11", "f12")]
+        .iter()
+        .filter_map(|(p, v)| level(p, v))
+        .collect();
+    let asks = [("f39", "f40"), ("f37", "f38"), ("f35", "f36"), ("f33", "f34"), ("f31", "f32")]
+        .iter()
+        .filter_map(|(p, v)| level(p, v))
+        .collect();
+
+    Ok(OrderBook {
+        symbol: data["f57"].as_str().unwrap_or(symbol).to_string(),
+        name: data["f58"].as_str().unwrap_or("").to_string(),
+        price: data["f43"].as_f64().unwrap_or(0.0),
+        prev_close: data["f60"].as_f64().unwrap_or(0.0),
+        bids,
+        asks,
+        timestamp: chrono::Local::now().format("%Y-%m-%d %H:%M:%S").to_string(),
+    })
+}
+
+/// 分笔成交：每条为 "时间,价格,量(手),笔数,方向"，方向 1 = 卖、2 = 买、4 = 中性
+async fn fetch_ticks(symbol: &str, count: usize) -> Result<Vec<TradeTick>, String> {
+    let url = format!(
+        "https://push2.eastmoney.com/api/qt/stock/details/get?secid={}&fields1=f1,f2,f3,f4&fields2=f51,f52,f53,f54,f55&pos=-{}",
+        secid(symbol),
+        count
+    );
+
+    let client = reqwest::Client::new();
+    let resp = client
+        .get(&url)
+        .header("User-Agent", "Mozilla/5.0")
+        .header("Referer", "https://quote.eastmoney.com/")
+        .send()
+        .await
+        .map_err(|e| format!("请求失败: {}", e))?;
+
+    let json: serde_json::Value = resp.json().await.map_err(|e| format!("解析失败: {}", e))?;
+
+    let ticks = json["data"]["details"]
+        .as_array()
+        .into_iter()
+        .flatten()
+        .filter_map(|line| {
+            let parts: Vec<&str> = line.as_str()?.split(',').collect();
+            if parts.len() < 5 {
+                return None;
+            }
+            Some(TradeTick {
+                time: parts[0].to_string(),
+                price: parts[1].parse().ok()?,
+                volume: parts[2].parse().unwrap_or(0.0),
+                side: match parts[4] {
+                    "1" => "sell",
+                    "2" => "buy",
+                    _ => "neutral",
+                }
+                .to_string(),
+            })
+        })
+        .collect();
+    Ok(ticks)
+}
+
+/// 分时：每条为 "YYYY-MM-DD HH:MM,开,收,高,低,量(手),额,均价"，preClose 为第一天的昨收
+async fn fetch_timeline(symbol: &str, days: usize) -> Result<Vec<Timeline>, String> {
+    let url = format!(
+        "https://push2his.eastmoney.com/api/qt/stock/trends2/get?secid={}&fields1=f1,f2,f3,f4,f5,f6,f7,f8,f9,f10,f11,f12,f13&fields2=f51,f52,f53,f54,f55,f56,f57,f58&iscr=0&ndays={}",
+        secid(symbol),
+        days
+    );
+
+    let client = reqwest::Client::new();
+    let resp = client
+        .get(&url)
+        .header("User-Agent", "Mozilla/5.0")
+        .header("Referer", "https://quote.eastmoney.com/")
+        .send()
+        .await
+        .map_err(|e| format!("请求失败: {}", e))?;
+
+    let json: serde_json::Value = resp.json().await.map_err(|e| format!("解析失败: {}", e))?;
+    let data = json.get("data").filter(|d| !d.is_null()).ok_or("返回数据为空")?;
+
+    let mut timelines: Vec<Timeline> = Vec::new();
+    for line in data["trends"].as_array().into_iter().flatten() {
+        let Some(line) = line.as_str() else {
+            continue;
+        };
+        let parts: Vec<&str> = line.split(',').collect();
+        if parts.len() < 8 {
+            continue;
+        }
+        let Some((date, time)) = parts[0].split_once(' ') else {
+            continue;
+        };
+        let num = |i: usize| parts[i].parse::<f64>().unwrap_or(0.0);
+        let point = TimelinePoint {
+            time: time.to_string(),
+            price: num(2),
+            volume: num(5),
+            amount: num(6),
+            avg_price: num(7),
+        };
+        match timelines.last_mut() {
+            Some(t) if t.date == date => t.points.push(point),
+            last => {
+                // 之后每天的昨收是前一天最后一分钟的价格
+                let prev_close = match last {
+                    Some(t) => t.points.last().map(|p| p.price).unwrap_or(0.0),
+                    None => data["preClose"].as_f64().unwrap_or(0.0),
+                };
+                timelines.push(Timeline {
+                    date: date.to_string(),
+                    prev_close,
+                    points: vec![point],
+                });
+            }
+        }
+    }
+    Ok(timelines)
+}
+
+/// 搜索股票
+async fn search_stocks(keyword: &str) -> Result<Vec<StockSearchResult>, String> {
+    let url = format!(
+        "https://searchapi.eastmoney.com/api/suggest/get?input={}&type=14&token=D43BF722C8E33BDC906FB84D85E326E8&count=10",
+        keyword
+    );
+
+    let client = reqwest::Client::new();
+    let resp = client
+        .get(&url)
+        .header("User-Agent", "Mozilla/5.0")
+        .send()
+        .await
+        .map_err(|e| format!("搜索失败: {}", e))?;
+
+    let json: serde_json::Value = resp.json().await.map_err(|e| format!("解析失败: {}", e))?;
+
+    let mut results = Vec::new();
+
+    if let Some(quote_list) = json["QuotationCodeTable"]["Data"].as_array() {
+        for item in quote_list {
+            let code = item["Code"].as_str().unwrap_or("");
+            let name = item["Name"].as_str().unwrap_or("");
+            let market_id = item["MktNum"].as_str().unwrap_or("");
+
+            // 只保留A股（沪深）
+            if market_id == "01" || market_id == "02" {
+                let market = if market_id == "01" { "沪" } else { "深" };
+                results.push(StockSearchResult {
+                    symbol: code.to_string(),
+                    name: name.to_string(),
+                    market: market.to_string(),
+                });
+            }
+        }
+    }
+
+    Ok(results)
+}
+
+/// 获取涨停/跌停候选：涨跌幅绝对值不低于 ST 股的 5% 限制，精确筛选由调用方按涨跌停价完成
+async fn fetch_limit_stocks(limit_type: &str) -> Result<Vec<StockQuote>, String> {
+    const PAGE_SIZE: usize = 500;
+    const MAX_PAGES: usize = 20;
+
+    // 涨跌停列表：按涨跌幅排序
+    let (sort_field, sort_order) = match limit_type {
+        "up" => ("f3", "1"),   // 涨幅降序
+        "down" => ("f3", "0"), // 涨幅升序
+        _ => return Err("无效的类型，请使用 up 或 down".to_string()),
+    };
+
+    let client = reqwest::Client::new();
+    let mut results = Vec::new();
+    // 大面积上涨（下跌）时候选超过一页，逐页获取直到涨跌幅低于阈值
+    for page in 1..=MAX_PAGES {
+        let url = format!(
+            "https://push2.eastmoney.com/api/qt/clist/get?pn={}&pz={}&po={}&np=1&fltt=2&invt=2&fields=f2,f3,f4,f5,f6,f7,f12,f14,f15,f16,f17,f18&fid={}&fs=m:0+t:6,m:0+t:80,m:1+t:2,m:1+t:23,m:0+t:81+s:2048",
+            page, PAGE_SIZE, sort_order, sort_field
+        );
+        let resp = client
+            .get(&url)
+            .header("User-Agent", "Mozilla/5.0")
+            .header("Referer", "https://quote.eastmoney.com/")
+            .send()
+            .await
+            .map_err(|e| format!("请求失败: {}", e))?;
+
+        let json: serde_json::Value = resp.json().await.map_err(|e| format!("解析失败: {}", e))?;
+        let diff = json["data"]["diff"].as_array().cloned().unwrap_or_default();
+        let page_len = diff.len();
+
+        for item in diff {
+            let change_pct = item["f3"].as_f64().unwrap_or(0.0);
+            let include = match limit_type {
+                "up" => change_pct >= LIMIT_CANDIDATE_PCT,
+                "down" => change_pct <= -LIMIT_CANDIDATE_PCT,
+                _ => false,
+            };
+            if !include {
+                // 已按涨跌幅排序，后面的不会再满足
+                return Ok(results);
+            }
+
+            results.push(StockQuote {
+                symbol: item["f12"].as_str().unwrap_or("").to_string(),
+                name: item["f14"].as_str().unwrap_or("").to_string(),
+                price: item["f2"].as_f64().unwrap_or(0.0),
+                change: item["f4"].as_f64().unwrap_or(0.0),
+                change_percent: change_pct,
+                volume: item["f5"].as_f64().unwrap_or(0.0),
+                high: item["f15"].as_f64().unwrap_or(0.0),
+                low: item["f16"].as_f64().unwrap_or(0.0),
+                open: item["f17"].as_f64().unwrap_or(0.0),
+                prev_close: item["f18"].as_f64().unwrap_or(0.0),
+                turnover: item["f6"].as_f64().unwrap_or(0.0),
+                volume_ratio: item["f7"].as_f64().unwrap_or(0.0),
+                pe_ratio: 0.0,
+                market_cap: 0.0,
+                float_shares: 0.0,
+                timestamp: chrono::Local::now().format("%Y-%m-%d %H:%M:%S").to_string(),
+                limit_up: None,
+                limit_down: None,
+            });
+        }
+
+        let total = json["data"]["total"].as_u64().unwrap_or(0) as usize;
+        if page_len < PAGE_SIZE || page * PAGE_SIZE >= total {
+            break;
+        }
+    }
+
+    Ok(results)
+}
+
+async fn fetch_clist(fs: &str, fields: &str, page_size: usize) -> Result<Vec<serde_json::Value>, String> {
+    let url = format!(
+        "https://push2.eastmoney.com/api/qt/clist/get?pn=1&pz={}&po=1&np=1&fltt=2&invt=2&fid=f3&fs={}&fields={}",
+        page_size, fs, fields
+    );
+
+    let client = reqwest::Client::new();
+    let resp = client
+        .get(&url)
+        .header("User-Agent", "Mozilla/5.0")
+        .header("Referer", "https://quote.eastmoney.com/")
+        .send()
+        .await
+        .map_err(|e| format!("请求失败: {}", e))?;
+
+    let json: serde_json::Value = resp.json().await.map_err(|e| format!("解析失败: {}", e))?;
+    Ok(json["data"]["diff"].as_array().cloned().unwrap_or_default())
+}
+
+/// 行业板块（m:90+t:2）和概念板块（m:90+t:3）
+async fn fetch_boards() -> Result<Vec<Board>, String> {
+    let mut boards = Vec::new();
+    for fs in ["m:90+t:2", "m:90+t:3"] {
+        for b in fetch_clist(fs, "f12,f14", 1000).await? {
+            boards.push(Board {
+                code: b["f12"].as_str().unwrap_or("").to_string(),
+                name: b["f14"].as_str().unwrap_or("").to_string(),
+            });
+        }
+    }
+    Ok(boards)
+}
+
+async fn fetch_board_members(board_code: &str) -> Result<Vec<String>, String> {
+    let members = fetch_clist(&format!("b:{}", board_code), "f12,f14", 1000).await?;
+    Ok(members
+        .iter()
+        .filter_map(|m| m["f12"].as_str().map(|s| s.to_string()))
+        .collect())
+}
+
+/// 分页获取列表的全部记录（按代码排序，翻页期间顺序稳定）
+async fn fetch_clist_all(fs: &str, fields: &str) -> Result<Vec<serde_json::Value>, String> {
+    const PAGE_SIZE: usize = 500;
+    const MAX_PAGES: usize = 50;
+
+    let client = reqwest::Client::new();
+    let mut items = Vec::new();
+    for page in 1..=MAX_PAGES {
+        let url = format!(
+            "https://push2.eastmoney.com/api/qt/clist/get?pn={}&pz={}&po=0&np=1&fltt=2&invt=2&fid=f12&fs={}&fields={}",
+            page, PAGE_SIZE, fs, fields
+        );
+        let resp = client
+            .get(&url)
+            .header("User-Agent", "Mozilla/5.0")
+            .header("Referer", "https://quote.eastmoney.com/")
+            .send()
+            .await
+            .map_err(|e| format!("请求失败: {}", e))?;
+        let json: serde_json::Value = resp.json().await.map_err(|e| format!("解析失败: {}", e))?;
+
+        let total = json["data"]["total"].as_u64().unwrap_or(0) as usize;
+        let diff = json["data"]["diff"].as_array().cloned().unwrap_or_default();
+        if diff.is_empty() {
+            break;
+        }
+        items.extend(diff);
+        if items.len() >= total {
+            break;
+        }
+    }
+    Ok(items)
+}
+
+async fn fetch_securities(sec_type: &str) -> Result<Vec<Security>, String> {
+    let (_, fs) = SECURITY_CATEGORIES
+        .iter()
+        .find(|(t, _)| *t == sec_type)
+        .ok_or_else(|| format!("未知的证券类型: {}", sec_type))?;
+    let items = fetch_clist_all(fs, "f12,f13,f14,f26").await?;
+    Ok(items.iter().filter_map(|item| from_clist(item, sec_type)).collect())
+}
+
+/// 列表中的一条证券记录
+fn from_clist(item: &serde_json::Value, sec_type: &str) -> Option<Security> {
+    let code = item["f12"].as_str()?;
+    let name = item["f14"].as_str().unwrap_or("");
+    let exchange = match item["f13"].as_i64() {
+        Some(1) => "SH",
+        _ if security::infer_exchange(code) == "BJ" && sec_type == "stock" => "BJ",
+        _ => "SZ",
+    };
+    let listing_date = item["f26"]
+        .as_i64()
+        .map(|d| d.to_string())
+        .filter(|d| d.len() == 8)
+        .map(|d| format!("{}-{}-{}", &d[..4], &d[4..6], &d[6..]));
+    Some(Security::new(code, exchange, name, sec_type, listing_date))
+}
+
+/// 当日涨停股池（getTopicZTPool）和炸板股池（getTopicZBPool），价格字段为实际价格 × 1000
+async fn fetch_limit_pool() -> Result<Vec<LimitPoolEntry>, String> {
+    // 非交易日和开盘前取上一个交易日的股池
+    let date = calendar::latest_session().format("%Y%m%d").to_string();
+    let mut entries = Vec::new();
+    for (topic, sort, status) in [("getTopicZTPool", "fbt:asc", "sealed"), ("getTopicZBPool", "fbt:asc", "broken")] {
+        let url = format!(
+            "https://push2ex.eastmoney.com/{}?ut=7eea3edcaed734bea9cbfc24409ed989&dpt=wz.ztzt&Pageindex=0&pagesize=10000&sort={}&date={}",
+            topic, sort, date
+        );
+        let resp = reqwest::Client::new()
+            .get(&url)
+            .header("User-Agent", "Mozilla/5.0")
+            .header("Referer", "https://quote.eastmoney.com/")
+            .send()
+            .await
+            .map_err(|e| format!("请求失败: {}", e))?;
+        let json: serde_json::Value = resp.json().await.map_err(|e| format!("解析失败: {}", e))?;
+
+        // 没有涨停（炸板）股时 data 为空
+        for item in json["data"]["pool"].as_array().into_iter().flatten() {
+            let price = item["p"].as_f64().unwrap_or(0.0) / 1000.0;
+            let sealed = status == "sealed";
+            entries.push(LimitPoolEntry {
+                symbol: item["c"].as_str().unwrap_or("").to_string(),
+                name: item["n"].as_str().unwrap_or("").to_string(),
+                price,
+                change_percent: item["zdp"].as_f64().unwrap_or(0.0),
+                limit_up: if sealed { price } else { item["ztp"].as_f64().unwrap_or(0.0) / 1000.0 },
+                streak: item["lbc"].as_u64().unwrap_or(1) as u32,
+                first_sealed_at: item["fbt"].as_i64().map(format_pool_time),
+                last_sealed_at: item["lbt"].as_i64().map(format_pool_time),
+                open_count: item["zbc"].as_u64().unwrap_or(0) as u32,
+                sealed_amount: if sealed { item["fund"].as_f64().unwrap_or(0.0) } else { 0.0 },
+                amount: item["amount"].as_f64().unwrap_or(0.0),
+                turnover_rate: item["hs"].as_f64().unwrap_or(0.0),
+                industry: item["hybk"].as_str().filter(|s| !s.is_empty()).map(|s| s.to_string()),
+                status: status.to_string(),
+            });
+        }
+    }
+    Ok(entries)
+}
+
+/// 股池中的时间是 HHMMSS 整数（如 92500）
+fn format_pool_time(t: i64) -> String {
+    format!("{:02}:{:02}:{:02}", t / 10000, t / 100 % 100, t % 100)
+}
+
+/// 请求日 K 线
+async fn fetch_klines(symbol: &str, range: KlineRange, adjust: AdjustMode) -> Result<Vec<KlineBar>, String> {
+    let range = match range {
+        KlineRange::Latest(limit) => format!("end=20500101&lmt={}", limit),
+        KlineRange::Since(start) => {
+            format!("beg={}&end=20500101", start.get(..10).unwrap_or(&start).replace('-', ""))
+        }
+    };
+    let url = format!(
+        "https://push2his.eastmoney.com/api/qt/stock/kline/get?secid={}&klt=101&fqt={}&{}&fields1=f1,f2,f3,f4,f5,f6&fields2=f51,f52,f53,f54,f55,f56,f57",
+        secid(symbol), adjust.fqt(), range
+    );
+
+    let client = reqwest::Client::new();
+    let resp = client
+        .get(&url)
+        .header("User-Agent", "Mozilla/5.0")
+        .header("Referer", "https://quote.eastmoney.com/")
+        .send()
+        .await
+        .map_err(|e| format!("K线请求失败: {}", e))?;
+
+    let json: serde_json::Value = resp.json().await.map_err(|e| format!("K线解析失败: {}", e))?;
+
+    let klines = json["data"]["klines"]
+        .as_array()
+        .ok_or("K线数据为空")?;
+
+    Ok(klines
+        .iter()
+        .filter_map(|v| {
+            let line = v.as_str()?;
+            let parts: Vec<&str> = line.split(',').collect();
+            if parts.len() < 7 {
+                return None;
+            }
+            Some(KlineBar {
+                date: parts[0].to_string(),
+                open: parts[1].parse().unwrap_or(0.0),
+                close: parts[2].parse().unwrap_or(0.0),
+                high: parts[3].parse().unwrap_or(0.0),
+                low: parts[4].parse().unwrap_or(0.0),
+                volume: parts[5].parse().unwrap_or(0.0),
+                amount: parts[6].parse().unwrap_or(0.0),
+            })
+        })
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_clist() {
+        let item = serde_json::json!({ "f12": "600289", "f13": 1, "f14": "*ST信通", "f26": 20000525 });
+        let s = from_clist(&item, "stock").unwrap();
+        assert_eq!(s.exchange, "SH");
+        assert_eq!(s.board.as_deref(), Some("主板"));
+        assert!(s.is_st);
+        assert_eq!(s.listing_date.as_deref(), Some("2000-05-25"));
+        assert_eq!(s.pinyin, "STXT");
+
+        let bj = from_clist(&serde_json::json!({ "f12": "830799", "f13": 0, "f14": "艾融软件", "f26": "-" }), "stock").unwrap();
+        assert_eq!(bj.exchange, "BJ");
+        assert_eq!(bj.board.as_deref(), Some("北交所"));
+        assert_eq!(bj.listing_date, None);
+
+        let index = from_clist(&serde_json::json!({ "f12": "000001", "f13": 1, "f14": "上证指数" }), "index").unwrap();
+        assert_eq!(index.board, None);
+    }
+
+    #[test]
+    fn test_ulist_quote_suspended() {
+        let item = serde_json::json!({ "f2": 10.5, "f12": "600000", "f13": 1, "f14": "浦发银行", "f21": 2.1e9 });
+        let quote = ulist_quote(&item, "2025-01-02 10:00:00").unwrap();
+        assert_eq!(quote.price, 10.5);
+        assert!((quote.float_shares - 2e8).abs() < 1.0);
+
+        // 停牌股的价格为 "-"，不能当作 0 元行情
+        let item = serde_json::json!({ "f2": "-", "f12": "600001", "f13": 1, "f14": "停牌股" });
+        assert!(ulist_quote(&item, "2025-01-02 10:00:00").is_none());
+    }
+}
@@ -0,0 +1,542 @@
+//! 离线 fixture 数据源
+//!
+//! 数据来自 JSON 文件（quotes / klines / stocks / boards，均可省略）；文件中没有的股票按代码生成
+//! 确定的 K 线：从 2015-01-05 起每个工作日一根，日收益由代码做种子的伪随机数决定，
+//! 同一代码、同一截止日期每次生成的数据完全相同。实时行情取最后两根 K 线。
+//! 复权方式不影响 fixture 数据。
+
+use super::{KlineRange, MarketDataProvider, ProviderFuture, LIMIT_CANDIDATE_PCT};
+use crate::services::kline::{AdjustMode, KlineBar};
+use crate::services::limit_ladder::{self, LimitPoolEntry};
+use crate::services::market::{Board, StockQuote, StockSearchResult};
+use crate::services::order_book::{OrderBook, OrderBookLevel, TradeTick};
+use crate::services::timeline::{Timeline, TimelinePoint};
+use crate::services::price_limit;
+use crate::services::security::{self, Security};
+use chrono::{Datelike, NaiveDate};
+use serde::Deserialize;
+use std::collections::HashMap;
+
+pub const NAME: &str = "fixture";
+
+/// 生成 K 线的起始日期
+const ORIGIN: (i32, u32, u32) = (2015, 1, 5);
+
+#[derive(Debug, Default, Deserialize)]
+struct FixtureFile {
+    #[serde(default)]
+    quotes: Vec<StockQuote>,
+    #[serde(default)]
+    klines: HashMap<String, Vec<KlineBar>>,
+    #[serde(default)]
+    stocks: Vec<StockSearchResult>,
+    #[serde(default)]
+    boards: Vec<FixtureBoard>,
+}
+
+/// 板块及其成分股
+#[derive(Debug, Clone, Deserialize)]
+struct FixtureBoard {
+    code: String,
+    name: String,
+    #[serde(default)]
+    members: Vec<String>,
+}
+
+pub struct FixtureProvider {
+    quotes: HashMap<String, StockQuote>,
+    klines: HashMap<String, Vec<KlineBar>>,
+    stocks: Vec<StockSearchResult>,
+    boards: Vec<FixtureBoard>,
+    /// 生成 K 线的截止日期
+    end_date: NaiveDate,
+}
+
+impl Default for FixtureProvider {
+    /// 不含 fixture 数据，生成的 K 线截止到今天
+    fn default() -> Self {
+        Self {
+            quotes: HashMap::new(),
+            klines: HashMap::new(),
+            stocks: Vec::new(),
+            boards: Vec::new(),
+            end_date: chrono::Local::now().date_naive(),
+        }
+    }
+}
+
+impl FixtureProvider {
+    /// 从 JSON 文件加载
+    pub fn from_file(path: &str) -> Result<Self, String> {
+        let content =
+            std::fs::read_to_string(path).map_err(|e| format!("读取 fixture 文件 {} 失败: {}", path, e))?;
+        Self::from_json(&content)
+    }
+
+    pub fn from_json(content: &str) -> Result<Self, String> {
+        let file: FixtureFile =
+            serde_json::from_str(content).map_err(|e| format!("解析 fixture 数据失败: {}", e))?;
+        let mut provider = Self {
+            stocks: file.stocks,
+            boards: file.boards,
+            ..Self::default()
+        };
+        for quote in file.quotes {
+            provider.quotes.insert(quote.symbol.clone(), quote);
+        }
+        for (symbol, mut bars) in file.klines {
+            bars.sort_by(|a, b| a.date.cmp(&b.date));
+            provider.klines.insert(symbol, bars);
+        }
+        Ok(provider)
+    }
+
+    /// 指定生成 K 线的截止日期（测试用）
+    pub fn with_end_date(mut self, end_date: NaiveDate) -> Self {
+        self.end_date = end_date;
+        self
+    }
+
+    /// 全部日 K 线：fixture 中有则用 fixture，否则生成
+    fn all_bars(&self, symbol: &str) -> Vec<KlineBar> {
+        match self.klines.get(symbol) {
+            Some(bars) => bars.clone(),
+            None => generate_bars(symbol, self.end_date),
+        }
+    }
+
+    fn name_of(&self, symbol: &str) -> String {
+        self.stocks
+            .iter()
+            .find(|s| s.symbol == symbol)
+            .map(|s| s.name.clone())
+            .unwrap_or_else(|| format!("模拟{}", symbol))
+    }
+
+    fn build_quote(&self, symbol: &str) -> Result<StockQuote, String> {
+        if let Some(quote) = self.quotes.get(symbol) {
+            return Ok(quote.clone());
+        }
+        let bars = self.all_bars(symbol);
+        let last = bars.last().ok_or_else(|| format!("fixture 中没有 {} 的数据", symbol))?;
+        let prev_close = bars
+            .len()
+            .checked_sub(2)
+            .map(|i| bars[i].close)
+            .unwrap_or(last.open);
+        let change = last.close - prev_close;
+        Ok(StockQuote {
+            symbol: symbol.to_string(),
+            name: self.name_of(symbol),
+            price: last.close,
+            change,
+            change_percent: if prev_close > 0.0 { change / prev_close * 100.0 } else { 0.0 },
+            volume: last.volume,
+            high: last.high,
+            low: last.low,
+            open: last.open,
+            prev_close,
+            turnover: last.amount,
+            volume_ratio: 1.0,
+            pe_ratio: 0.0,
+            market_cap: 0.0,
+            float_shares: 1e9,
+            timestamp: format!("{} 15:00:00", last.date),
+            limit_up: None,
+            limit_down: None,
+        })
+    }
+
+    /// fixture 中出现过的全部股票
+    fn universe(&self) -> Vec<String> {
+        let mut symbols: Vec<String> = self
+            .stocks
+            .iter()
+            .map(|s| s.symbol.clone())
+            .chain(self.quotes.keys().cloned())
+            .chain(self.klines.keys().cloned())
+            .collect();
+        symbols.sort();
+        symbols.dedup();
+        symbols
+    }
+}
+
+impl MarketDataProvider for FixtureProvider {
+    fn name(&self) -> &'static str {
+        NAME
+    }
+
+    fn quote<'a>(&'a self, symbol: &'a str) -> ProviderFuture<'a, StockQuote> {
+        Box::pin(async move { self.build_quote(symbol) })
+    }
+
+    fn search<'a>(&'a self, keyword: &'a str) -> ProviderFuture<'a, Vec<StockSearchResult>> {
+        Box::pin(async move {
+            let keyword = keyword.trim();
+            let mut results: Vec<StockSearchResult> = self
+                .stocks
+                .iter()
+                .filter(|s| s.symbol.starts_with(keyword) || s.name.contains(keyword))
+                .take(10)
+                .cloned()
+                .collect();
+            // 不在 fixture 中的六位代码也能搜到（对应生成的数据）
+            if results.is_empty() && keyword.len() == 6 && keyword.chars().all(|c| c.is_ascii_digit()) {
+                results.push(StockSearchResult {
+                    symbol: keyword.to_string(),
+                    name: self.name_of(keyword),
+                    market: if keyword.starts_with('6') { "沪" } else { "深" }.to_string(),
+                });
+            }
+            Ok(results)
+        })
+    }
+
+    fn klines<'a>(
+        &'a self,
+        symbol: &'a str,
+        range: KlineRange,
+        _adjust: AdjustMode,
+    ) -> ProviderFuture<'a, Vec<KlineBar>> {
+        Box::pin(async move {
+            let bars = self.all_bars(symbol);
+            Ok(match range {
+                KlineRange::Latest(limit) => bars[bars.len().saturating_sub(limit)..].to_vec(),
+                KlineRange::Since(start) => {
+                    let start = start.get(..10).unwrap_or(&start).to_string();
+                    bars.into_iter().filter(|b| b.date >= start).collect()
+                }
+            })
+        })
+    }
+
+    fn limit_stocks<'a>(&'a self, limit_type: &'a str) -> ProviderFuture<'a, Vec<StockQuote>> {
+        Box::pin(async move {
+            let up = match limit_type {
+                "up" => true,
+                "down" => false,
+                _ => return Err("无效的类型，请使用 up 或 down".to_string()),
+            };
+            let mut results = Vec::new();
+            for symbol in self.universe() {
+                let quote = self.build_quote(&symbol)?;
+                let include = if up {
+                    quote.change_percent >= LIMIT_CANDIDATE_PCT
+                } else {
+                    quote.change_percent <= -LIMIT_CANDIDATE_PCT
+                };
+                if include {
+                    results.push(quote);
+                }
+            }
+            Ok(results)
+        })
+    }
+
+    /// 以现价为买一、加一分为卖一，各档挂单量由代码决定
+    fn order_book<'a>(&'a self, symbol: &'a str) -> ProviderFuture<'a, OrderBook> {
+        Box::pin(async move {
+            let quote = self.build_quote(symbol)?;
+            let mut next = random_source(&format!("{}:book", symbol));
+            let mut level = |price: f64| OrderBookLevel {
+                price: round2(price),
+                volume: (10.0 + next() * 990.0).round(),
+            };
+            let bids = (0..5).map(|i| level(quote.price - i as f64 * 0.01)).filter(|l| l.price > 0.0).collect();
+            let asks = (1..=5).map(|i| level(quote.price + i as f64 * 0.01)).collect();
+            Ok(OrderBook {
+                symbol: quote.symbol,
+                name: quote.name,
+                price: quote.price,
+                prev_close: quote.prev_close,
+                bids,
+                asks,
+                timestamp: quote.timestamp,
+            })
+        })
+    }
+
+    /// 收盘前每 3 秒一笔，价格在收盘价上下一分钱
+    fn ticks<'a>(&'a self, symbol: &'a str, count: usize) -> ProviderFuture<'a, Vec<TradeTick>> {
+        Box::pin(async move {
+            let quote = self.build_quote(symbol)?;
+            let mut next = random_source(&format!("{}:ticks", symbol));
+            let close_secs = 15 * 3600;
+            let ticks = (0..count.min(1000))
+                .rev()
+                .map(|i| {
+                    let secs = close_secs - i as i64 * 3;
+                    let r = next();
+                    TradeTick {
+                        time: format!("{:02}:{:02}:{:02}", secs / 3600, secs / 60 % 60, secs % 60),
+                        price: round2(quote.price + if r < 0.5 { 0.0 } else { -0.01 }),
+                        volume: (1.0 + next() * 200.0).round(),
+                        side: if r < 0.5 { "buy" } else { "sell" }.to_string(),
+                    }
+                })
+                .collect();
+            Ok(ticks)
+        })
+    }
+
+    /// 由日 K 线生成：价格从开盘价随机游走到收盘价，限制在最高、最低价之间，成交量均摊到每分钟
+    fn timeline<'a>(&'a self, symbol: &'a str, days: usize) -> ProviderFuture<'a, Vec<Timeline>> {
+        Box::pin(async move {
+            let bars = self.all_bars(symbol);
+            let start = bars.len().saturating_sub(days);
+            let times = minute_times();
+            let timelines = (start..bars.len())
+                .map(|i| {
+                    let bar = &bars[i];
+                    let prev_close = if i > 0 { bars[i - 1].close } else { bar.open };
+                    let mut next = random_source(&format!("{}:{}", symbol, bar.date));
+                    let n = times.len();
+                    let mut price = bar.open;
+                    let points = times
+                        .iter()
+                        .enumerate()
+                        .map(|(k, time)| {
+                            if k + 1 == n {
+                                price = bar.close;
+                            } else if k > 0 {
+                                // 向收盘价回归的随机游走
+                                let drift = (bar.close - price) / (n - k) as f64;
+                                let noise = (next() - 0.5) * (bar.high - bar.low) * 0.1;
+                                price = round2((price + drift + noise).clamp(bar.low, bar.high));
+                            }
+                            let volume = (bar.volume / n as f64 * (0.5 + next())).round();
+                            TimelinePoint {
+                                time: time.clone(),
+                                price,
+                                volume,
+                                amount: round2(price * volume * 100.0),
+                                avg_price: 0.0,
+                            }
+                        })
+                        .collect();
+                    Timeline {
+                        date: bar.date.clone(),
+                        prev_close,
+                        points,
+                    }
+                })
+                .collect();
+            Ok(timelines)
+        })
+    }
+
+    /// 按日 K 线计算：收盘封住涨停为封板，最高价触及涨停但收盘打开为炸板；没有分时数据，封板时间为空
+    fn limit_pool(&self) -> ProviderFuture<'_, Vec<LimitPoolEntry>> {
+        Box::pin(async move {
+            let mut entries = Vec::new();
+            for symbol in self.universe() {
+                let bars = self.all_bars(&symbol);
+                let [.., prev, last] = bars.as_slice() else {
+                    continue;
+                };
+                let name = self.name_of(&symbol);
+                let date = NaiveDate::parse_from_str(&last.date, "%Y-%m-%d").unwrap_or(self.end_date);
+                let Some(limit) = price_limit::for_symbol(&symbol, &name, prev.close, date) else {
+                    continue;
+                };
+                let sealed = last.close >= limit.limit_up - 1e-6;
+                if !sealed && last.high < limit.limit_up - 1e-6 {
+                    continue;
+                }
+                let streak = limit_ladder::streak_from_bars(&symbol, &name, &bars[..bars.len() - 1]) + 1;
+                entries.push(LimitPoolEntry {
+                    symbol: symbol.clone(),
+                    name,
+                    price: last.close,
+                    change_percent: (last.close - prev.close) / prev.close * 100.0,
+                    limit_up: limit.limit_up,
+                    streak,
+                    first_sealed_at: None,
+                    last_sealed_at: None,
+                    open_count: if sealed { 0 } else { 1 },
+                    sealed_amount: 0.0,
+                    amount: last.amount,
+                    turnover_rate: 0.0,
+                    industry: None,
+                    status: if sealed { "sealed" } else { "broken" }.to_string(),
+                });
+            }
+            Ok(entries)
+        })
+    }
+
+    fn boards(&self) -> ProviderFuture<'_, Vec<Board>> {
+        Box::pin(async move {
+            Ok(self
+                .boards
+                .iter()
+                .map(|b| Board {
+                    code: b.code.clone(),
+                    name: b.name.clone(),
+                })
+                .collect())
+        })
+    }
+
+    fn board_members<'a>(&'a self, board_code: &'a str) -> ProviderFuture<'a, Vec<String>> {
+        Box::pin(async move {
+            self.boards
+                .iter()
+                .find(|b| b.code.eq_ignore_ascii_case(board_code))
+                .map(|b| b.members.clone())
+                .ok_or_else(|| format!("fixture 中没有板块 {}", board_code))
+        })
+    }
+
+    /// fixture 中的股票全部按 A 股处理，其他类型为空
+    fn securities<'a>(&'a self, sec_type: &'a str) -> ProviderFuture<'a, Vec<Security>> {
+        Box::pin(async move {
+            if sec_type != "stock" {
+                return Ok(Vec::new());
+            }
+            Ok(self
+                .universe()
+                .iter()
+                .map(|code| Security::new(code, security::infer_exchange(code), &self.name_of(code), "stock", None))
+                .collect())
+        })
+    }
+}
+
+/// 按代码生成确定的日 K 线
+fn generate_bars(symbol: &str, end_date: NaiveDate) -> Vec<KlineBar> {
+    let mut next = random_source(symbol);
+
+    let Some(mut date) = NaiveDate::from_ymd_opt(ORIGIN.0, ORIGIN.1, ORIGIN.2) else {
+        return Vec::new();
+    };
+    let mut close = 5.0 + (next() * 95.0 * 100.0).round() / 100.0;
+    let mut bars = Vec::new();
+    while date <= end_date {
+        if date.weekday().num_days_from_monday() < 5 {
+            // 日收益 -4% ~ +4.2%，开盘在前收盘附近
+            let ret = (next() - 0.49) * 0.082;
+            let open = round2(close * (1.0 + (next() - 0.5) * 0.02));
+            let new_close = round2((close * (1.0 + ret)).max(0.01));
+            let high = round2(open.max(new_close) * (1.0 + next() * 0.015));
+            let low = round2(open.min(new_close) * (1.0 - next() * 0.015));
+            let volume = (1e5 + next() * 9e5).round();
+            bars.push(KlineBar {
+                date: date.format("%Y-%m-%d").to_string(),
+                open,
+                close: new_close,
+                high,
+                low,
+                volume,
+                amount: round2(volume * 100.0 * (open + new_close) / 2.0),
+            });
+            close = new_close;
+        }
+        match date.succ_opt() {
+            Some(d) => date = d,
+            None => break,
+        }
+    }
+    bars
+}
+
+/// 分时的分钟：09:30（集合竞价成交）、09:31-11:30、13:01-15:00，共 241 个
+fn minute_times() -> Vec<String> {
+    let minutes = std::iter::once(9 * 60 + 30)
+        .chain(9 * 60 + 31..=11 * 60 + 30)
+        .chain(13 * 60 + 1..=15 * 60);
+    minutes.map(|m| format!("{:02}:{:02}", m / 60, m % 60)).collect()
+}
+
+/// 以 seed 的 FNV-1a 哈希为种子的 xorshift，返回 [0, 1) 的伪随机数
+fn random_source(seed: &str) -> impl FnMut() -> f64 {
+    let mut state = seed
+        .bytes()
+        .fold(0xcbf2_9ce4_8422_2325u64, |h, b| (h ^ b as u64).wrapping_mul(0x0100_0000_01b3));
+    move || {
+        state ^= state << 13;
+        state ^= state >> 7;
+        state ^= state << 17;
+        (state >> 11) as f64 / (1u64 << 53) as f64
+    }
+}
+
+fn round2(v: f64) -> f64 {
+    (v * 100.0).round() / 100.0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn provider() -> FixtureProvider {
+        FixtureProvider::default().with_end_date(NaiveDate::from_ymd_opt(2025, 6, 30).unwrap())
+    }
+
+    #[tokio::test]
+    async fn test_generated_data_is_deterministic() {
+        let p = provider();
+        let a = p.klines("600519", KlineRange::Latest(120), AdjustMode::Forward).await.unwrap();
+        let b = provider().klines("600519", KlineRange::Latest(120), AdjustMode::None).await.unwrap();
+        assert_eq!(a.len(), 120);
+        assert_eq!(a.last().unwrap().date, "2025-06-30");
+        assert_eq!(
+            a.iter().map(|x| x.close).collect::<Vec<_>>(),
+            b.iter().map(|x| x.close).collect::<Vec<_>>()
+        );
+        assert!(a.iter().all(|x| x.low <= x.open.min(x.close) && x.high >= x.open.max(x.close)));
+
+        // 不同范围取到的是同一条序列
+        let since = p.klines("600519", KlineRange::Since("2025-06-02".into()), AdjustMode::Forward).await.unwrap();
+        assert_eq!(since.first().unwrap().date, "2025-06-02");
+        assert_eq!(since.last().unwrap().close, a.last().unwrap().close);
+
+        let quote = p.quote("600519").await.unwrap();
+        assert_eq!(quote.price, a[119].close);
+        assert_eq!(quote.prev_close, a[118].close);
+        assert_ne!(quote.price, p.quote("000001").await.unwrap().price);
+
+        let timelines = p.timeline("600519", 2).await.unwrap();
+        assert_eq!(timelines.len(), 2);
+        assert_eq!(timelines[1].points.len(), 241);
+        assert_eq!(timelines[1].points.last().unwrap().price, quote.price);
+        assert_eq!(timelines[1].prev_close, quote.prev_close);
+    }
+
+    #[tokio::test]
+    async fn test_fixture_file() {
+        let p = FixtureProvider::from_json(
+            r#"{
+                "stocks": [{"symbol": "300750", "name": "宁德时代", "market": "深"}],
+                "boards": [{"code": "BK1033", "name": "电池", "members": ["300750", "002074"]}],
+                "klines": {"300750": [
+                    {"date": "2025-01-03", "open": 100, "close": 120, "high": 120, "low": 99, "volume": 1, "amount": 1},
+                    {"date": "2025-01-02", "open": 100, "close": 100, "high": 101, "low": 99, "volume": 1, "amount": 1}
+                ]}
+            }"#,
+        )
+        .unwrap();
+
+        let hits = p.search("宁德").await.unwrap();
+        assert_eq!(hits[0].symbol, "300750");
+        let up = p.limit_stocks("up").await.unwrap();
+        assert_eq!(up.len(), 1);
+        assert_eq!(up[0].name, "宁德时代");
+        assert!((up[0].change_percent - 20.0).abs() < 1e-9);
+        assert!(p.limit_stocks("down").await.unwrap().is_empty());
+
+        let pool = p.limit_pool().await.unwrap();
+        assert_eq!(pool.len(), 1);
+        assert_eq!((pool[0].streak, pool[0].limit_up, pool[0].status.as_str()), (1, 120.0, "sealed"));
+
+        assert_eq!(p.boards().await.unwrap()[0].name, "电池");
+        assert_eq!(p.board_members("bk1033").await.unwrap(), vec!["300750", "002074"]);
+        assert!(p.board_members("BK0001").await.is_err());
+
+        let securities = p.securities("stock").await.unwrap();
+        assert_eq!(securities.len(), 1);
+        assert_eq!((securities[0].exchange.as_str(), securities[0].pinyin.as_str()), ("SZ", "NDSD"));
+        assert!(p.securities("etf").await.unwrap().is_empty());
+    }
+}
@@ -0,0 +1,217 @@
+//! 行情数据源
+//!
+//! 行情、盘口、成交明细、分时、搜索、K 线、涨跌停列表、板块、证券列表统一通过 MarketDataProvider 获取，
+//! `market` / `kline` 中的函数转发到当前数据源。默认使用东方财富；fixture 数据源返回确定的离线数据，
+//! 用于在没有网络时测试调度器、提醒和 TDX 指标。数据源在设置中选择。
+
+pub mod eastmoney;
+pub mod fixture;
+
+use crate::db::Database;
+use crate::services::kline::{AdjustMode, KlineBar};
+use crate::services::limit_ladder::LimitPoolEntry;
+use crate::services::market::{BatchQuotes, Board, QuoteError, StockQuote, StockSearchResult};
+use crate::services::order_book::{OrderBook, TradeTick};
+use crate::services::security::Security;
+use crate::services::timeline::Timeline;
+use serde::{Deserialize, Serialize};
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::{Arc, RwLock};
+
+/// 涨跌停候选的最小涨跌幅（%），低于 ST 股 5% 的限制，留出四舍五入的余量
+pub const LIMIT_CANDIDATE_PCT: f64 = 4.5;
+
+/// 数据源方法的返回值
+pub type ProviderFuture<'a, T> = Pin<Box<dyn Future<Output = Result<T, String>> + Send + 'a>>;
+
+/// K 线范围
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum KlineRange {
+    /// 最近 N 根
+    Latest(usize),
+    /// 从某日（YYYY-MM-DD）至今
+    Since(String),
+}
+
+/// 行情数据源
+pub trait MarketDataProvider: Send + Sync {
+    /// 数据源名称（与设置中的取值一致）
+    fn name(&self) -> &'static str;
+
+    /// 单股实时行情
+    fn quote<'a>(&'a self, symbol: &'a str) -> ProviderFuture<'a, StockQuote>;
+
+    /// 批量行情，symbol 与请求的代码一致，获取失败的股票记入 errors。默认逐只请求，数据源有批量接口时应覆盖
+    fn batch_quotes<'a>(&'a self, symbols: &'a [String]) -> ProviderFuture<'a, BatchQuotes> {
+        Box::pin(async move {
+            let mut result = BatchQuotes::default();
+            for symbol in symbols {
+                match self.quote(symbol).await {
+                    Ok(q) => result.quotes.push(StockQuote {
+                        symbol: symbol.clone(),
+                        ..q
+                    }),
+                    Err(error) => result.errors.push(QuoteError {
+                        symbol: symbol.clone(),
+                        error,
+                    }),
+                }
+            }
+            Ok(result)
+        })
+    }
+
+    /// 按代码、名称搜索股票
+    fn search<'a>(&'a self, keyword: &'a str) -> ProviderFuture<'a, Vec<StockSearchResult>>;
+
+    /// 日 K 线
+    fn klines<'a>(
+        &'a self,
+        symbol: &'a str,
+        range: KlineRange,
+        adjust: AdjustMode,
+    ) -> ProviderFuture<'a, Vec<KlineBar>>;
+
+    /// 涨停（up）/ 跌停（down）候选：涨跌幅绝对值不低于 LIMIT_CANDIDATE_PCT 的股票，
+    /// `market::fetch_limit_stocks` 再按精确的涨跌停价筛选
+    fn limit_stocks<'a>(&'a self, limit_type: &'a str) -> ProviderFuture<'a, Vec<StockQuote>>;
+
+    /// 五档盘口
+    fn order_book<'a>(&'a self, symbol: &'a str) -> ProviderFuture<'a, OrderBook> {
+        let name = self.name();
+        Box::pin(async move { Err(format!("行情数据源 {} 不支持五档盘口: {}", name, symbol)) })
+    }
+
+    /// 最近 count 笔成交（分笔），按时间先后排列
+    fn ticks<'a>(&'a self, symbol: &'a str, _count: usize) -> ProviderFuture<'a, Vec<TradeTick>> {
+        let name = self.name();
+        Box::pin(async move { Err(format!("行情数据源 {} 不支持成交明细: {}", name, symbol)) })
+    }
+
+    /// 最近 days 个交易日的分时，按日期先后排列；股票和基金的均价由调用方按成交额重新累计，指数使用数据源的均价
+    fn timeline<'a>(&'a self, symbol: &'a str, _days: usize) -> ProviderFuture<'a, Vec<Timeline>> {
+        let name = self.name();
+        Box::pin(async move { Err(format!("行情数据源 {} 不支持分时数据: {}", name, symbol)) })
+    }
+
+    /// 最近一个交易日（calendar::latest_session）的涨停股池和炸板股池（连板数、封板时间、炸板次数、封单金额）
+    fn limit_pool(&self) -> ProviderFuture<'_, Vec<LimitPoolEntry>> {
+        let name = self.name();
+        Box::pin(async move { Err(format!("行情数据源 {} 不支持涨停股池", name)) })
+    }
+
+    /// 全部行业板块和概念板块（行业在前）
+    fn boards(&self) -> ProviderFuture<'_, Vec<Board>> {
+        let name = self.name();
+        Box::pin(async move { Err(format!("行情数据源 {} 不支持板块列表", name)) })
+    }
+
+    /// 板块成分股代码
+    fn board_members<'a>(&'a self, board_code: &'a str) -> ProviderFuture<'a, Vec<String>> {
+        let name = self.name();
+        Box::pin(async move { Err(format!("行情数据源 {} 不支持板块成分股: {}", name, board_code)) })
+    }
+
+    /// 某一类型（stock / b_share / etf / index）的全部证券，用于同步证券主表
+    fn securities<'a>(&'a self, sec_type: &'a str) -> ProviderFuture<'a, Vec<Security>> {
+        let name = self.name();
+        Box::pin(async move { Err(format!("行情数据源 {} 不支持证券列表: {}", name, sec_type)) })
+    }
+}
+
+/// 设置中的数据源配置
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct ProviderConfig {
+    /// eastmoney / fixture
+    pub provider: String,
+    /// fixture 数据文件（JSON），为空时全部使用生成的数据
+    #[serde(default)]
+    pub fixture_path: Option<String>,
+}
+
+impl ProviderConfig {
+    /// 按配置创建数据源
+    pub fn build(&self) -> Result<Arc<dyn MarketDataProvider>, String> {
+        match self.provider.trim() {
+            "" | eastmoney::NAME => Ok(Arc::new(eastmoney::EastmoneyProvider)),
+            fixture::NAME => {
+                let provider = match self.fixture_path.as_deref().map(str::trim) {
+                    Some(path) if !path.is_empty() => fixture::FixtureProvider::from_file(path)?,
+                    _ => fixture::FixtureProvider::default(),
+                };
+                Ok(Arc::new(provider))
+            }
+            other => Err(format!("未知的行情数据源: {}，请使用 eastmoney / fixture", other)),
+        }
+    }
+}
+
+static CURRENT: once_cell::sync::Lazy<RwLock<(ProviderConfig, Arc<dyn MarketDataProvider>)>> =
+    once_cell::sync::Lazy::new(|| {
+        RwLock::new((
+            ProviderConfig {
+                provider: eastmoney::NAME.to_string(),
+                fixture_path: None,
+            },
+            Arc::new(eastmoney::EastmoneyProvider),
+        ))
+    });
+
+/// 当前数据源
+pub fn current() -> Arc<dyn MarketDataProvider> {
+    match CURRENT.read() {
+        Ok(current) => Arc::clone(&current.1),
+        Err(poisoned) => Arc::clone(&poisoned.into_inner().1),
+    }
+}
+
+/// 当前数据源配置
+pub fn current_config() -> ProviderConfig {
+    match CURRENT.read() {
+        Ok(current) => current.0.clone(),
+        Err(poisoned) => poisoned.into_inner().0.clone(),
+    }
+}
+
+/// 切换数据源
+pub fn select(config: ProviderConfig) -> Result<(), String> {
+    let provider = config.build()?;
+    let mut current = CURRENT.write().map_err(|e| e.to_string())?;
+    *current = (config, provider);
+    Ok(())
+}
+
+/// app_setting 中保存数据源配置的键
+const SETTING_KEY: &str = "market_provider";
+
+/// 切换数据源并保存，下次启动时在调度器和证券同步之前恢复
+pub fn select_and_save(db: &Database, config: ProviderConfig) -> Result<(), String> {
+    select(config.clone())?;
+    let value = serde_json::to_string(&config).map_err(|e| e.to_string())?;
+    let conn = db.conn.lock().map_err(|e| e.to_string())?;
+    conn.execute(
+        "INSERT OR REPLACE INTO app_setting (key, value, updated_at) VALUES (?1, ?2, ?3)",
+        rusqlite::params![SETTING_KEY, value, chrono::Utc::now().to_rfc3339()],
+    )
+    .map_err(|e| e.to_string())?;
+    Ok(())
+}
+
+/// 启动时恢复保存的数据源，未保存过时保持默认的东方财富
+pub fn load(db: &Database) -> Result<(), String> {
+    let value: Option<String> = {
+        let conn = db.conn.lock().map_err(|e| e.to_string())?;
+        let mut stmt = conn
+            .prepare("SELECT value FROM app_setting WHERE key = ?1")
+            .map_err(|e| e.to_string())?;
+        let mut rows = stmt
+            .query_map([SETTING_KEY], |row| row.get::<_, String>(0))
+            .map_err(|e| e.to_string())?;
+        rows.next().transpose().map_err(|e| e.to_string())?
+    };
+    match value {
+        Some(value) => select(serde_json::from_str(&value).map_err(|e| format!("数据源配置无效: {}", e))?),
+        None => Ok(()),
+    }
+}
@@ -0,0 +1,274 @@
+//! 个股相对基准（指数或其他股票）的滚动贝塔、相关系数和相对强弱
+//!
+//! 以个股自身的 K 线日期为准对齐基准：个股停牌日本身不在序列中，复牌后第一根 K 线的收益
+//! 与基准在同一区间（停牌前最后一个交易日 → 复牌日）的收益配对；基准缺少某个日期时
+//! （基准股票停牌），涉及该日期的收益对记为缺失，滚动窗口内跳过，有效样本不足时结果为 None。
+
+use crate::services::kline::KlineBar;
+use serde::Serialize;
+use std::collections::HashMap;
+
+/// 返回的近期序列长度
+const RECENT_POINTS: usize = 20;
+
+/// 将 other 的收盘价按 base 的日期对齐，base 有而 other 没有的日期为 None
+pub fn align_closes(base: &[KlineBar], other: &[KlineBar]) -> Vec<Option<f64>> {
+    let by_date: HashMap<&str, f64> = other.iter().map(|b| (b.date.as_str(), b.close)).collect();
+    base.iter()
+        .map(|b| by_date.get(b.date.as_str()).copied())
+        .collect()
+}
+
+/// 基准有 K 线而个股没有的日期（个股停牌日），仅统计个股首尾日期之间
+pub fn suspended_dates(base: &[KlineBar], benchmark: &[KlineBar]) -> Vec<String> {
+    let (Some(first), Some(last)) = (base.first(), base.last()) else {
+        return Vec::new();
+    };
+    let own: std::collections::HashSet<&str> = base.iter().map(|b| b.date.as_str()).collect();
+    benchmark
+        .iter()
+        .filter(|b| b.date >= first.date && b.date <= last.date && !own.contains(b.date.as_str()))
+        .map(|b| b.date.clone())
+        .collect()
+}
+
+/// 相邻两个有效值之间的收益，首根或任一端缺失时为 None
+fn returns(closes: &[Option<f64>]) -> Vec<Option<f64>> {
+    (0..closes.len())
+        .map(|i| match (i.checked_sub(1).and_then(|p| closes[p]), closes[i]) {
+            (Some(prev), Some(cur)) if prev > 0.0 => Some(cur / prev - 1.0),
+            _ => None,
+        })
+        .collect()
+}
+
+/// 窗口内有效样本至少 2 个且不少于窗口的一半
+fn enough_samples(valid: usize, window: usize) -> bool {
+    valid >= 2 && valid * 2 >= window
+}
+
+/// 对最近 window 个位置上同时有效的 (x, y) 对应用 f
+fn rolling_pairs(
+    x: &[Option<f64>],
+    y: &[Option<f64>],
+    window: usize,
+    f: impl Fn(&[(f64, f64)]) -> Option<f64>,
+) -> Vec<Option<f64>> {
+    (0..x.len())
+        .map(|i| {
+            if window == 0 || i + 1 < window {
+                return None;
+            }
+            let pairs: Vec<(f64, f64)> = (i + 1 - window..=i)
+                .filter_map(|j| Some((x[j]?, y[j]?)))
+                .collect();
+            if enough_samples(pairs.len(), window) {
+                f(&pairs)
+            } else {
+                None
+            }
+        })
+        .collect()
+}
+
+/// (协方差, x 方差, y 方差)
+fn moments(pairs: &[(f64, f64)]) -> (f64, f64, f64) {
+    let n = pairs.len() as f64;
+    let mx = pairs.iter().map(|p| p.0).sum::<f64>() / n;
+    let my = pairs.iter().map(|p| p.1).sum::<f64>() / n;
+    let cov = pairs.iter().map(|p| (p.0 - mx) * (p.1 - my)).sum::<f64>() / n;
+    let vx = pairs.iter().map(|p| (p.0 - mx).powi(2)).sum::<f64>() / n;
+    let vy = pairs.iter().map(|p| (p.1 - my).powi(2)).sum::<f64>() / n;
+    (cov, vx, vy)
+}
+
+/// 两个序列的滚动相关系数
+pub fn rolling_correlation(x: &[Option<f64>], y: &[Option<f64>], window: usize) -> Vec<Option<f64>> {
+    rolling_pairs(x, y, window, |pairs| {
+        let (cov, vx, vy) = moments(pairs);
+        let denom = (vx * vy).sqrt();
+        (denom > f64::EPSILON).then(|| cov / denom)
+    })
+}
+
+/// 个股日收益相对基准日收益的滚动贝塔
+pub fn rolling_beta(base: &[f64], benchmark: &[Option<f64>], window: usize) -> Vec<Option<f64>> {
+    let base: Vec<Option<f64>> = base.iter().map(|c| Some(*c)).collect();
+    rolling_pairs(&returns(&base), &returns(benchmark), window, |pairs| {
+        let (cov, _, var) = moments(pairs);
+        (var > f64::EPSILON).then(|| cov / var)
+    })
+}
+
+/// 个股日收益与基准日收益的滚动相关系数
+pub fn return_correlation(base: &[f64], benchmark: &[Option<f64>], window: usize) -> Vec<Option<f64>> {
+    let base: Vec<Option<f64>> = base.iter().map(|c| Some(*c)).collect();
+    rolling_correlation(&returns(&base), &returns(benchmark), window)
+}
+
+/// 相对强弱：(个股 N 根涨幅 / 基准同期涨幅) × 100，大于 100 表示跑赢基准
+pub fn relative_strength(base: &[f64], benchmark: &[Option<f64>], window: usize) -> Vec<Option<f64>> {
+    (0..base.len())
+        .map(|i| {
+            let p = i.checked_sub(window)?;
+            let (b0, b1) = (benchmark[p]?, benchmark[i]?);
+            if base[p] <= 0.0 || b0 <= 0.0 || b1 <= 0.0 {
+                return None;
+            }
+            Some((base[i] / base[p]) / (b1 / b0) * 100.0)
+        })
+        .collect()
+}
+
+/// 某一日的相对指标
+#[derive(Debug, Clone, Serialize)]
+pub struct RelativePoint {
+    pub date: String,
+    pub beta: Option<f64>,
+    pub correlation: Option<f64>,
+    pub relative_strength: Option<f64>,
+}
+
+/// 个股相对基准的分析结果
+#[derive(Debug, Clone, Serialize)]
+pub struct RelativeReport {
+    pub window: usize,
+    pub bars: usize,
+    /// 窗口内个股涨幅（%）
+    pub symbol_return: Option<f64>,
+    /// 同期基准涨幅（%）
+    pub benchmark_return: Option<f64>,
+    pub beta: Option<f64>,
+    pub correlation: Option<f64>,
+    pub relative_strength: Option<f64>,
+    /// 个股停牌（基准有 K 线而个股没有）的日期
+    pub suspended_dates: Vec<String>,
+    /// 基准缺失（个股有 K 线而基准没有）的日期
+    pub benchmark_missing_dates: Vec<String>,
+    /// 最近的逐日序列
+    pub recent: Vec<RelativePoint>,
+}
+
+pub fn analyze(symbol_bars: &[KlineBar], benchmark_bars: &[KlineBar], window: usize) -> RelativeReport {
+    let closes: Vec<f64> = symbol_bars.iter().map(|b| b.close).collect();
+    let aligned = align_closes(symbol_bars, benchmark_bars);
+    let beta = rolling_beta(&closes, &aligned, window);
+    let correlation = return_correlation(&closes, &aligned, window);
+    let rs = relative_strength(&closes, &aligned, window);
+
+    let last = closes.len().checked_sub(1);
+    let period_return = |series: &[Option<f64>]| {
+        let i = last?;
+        let (p0, p1) = (series[i.checked_sub(window)?]?, series[i]?);
+        (p0 > 0.0).then(|| (p1 / p0 - 1.0) * 100.0)
+    };
+    let own: Vec<Option<f64>> = closes.iter().map(|c| Some(*c)).collect();
+
+    let recent = (closes.len().saturating_sub(RECENT_POINTS)..closes.len())
+        .map(|i| RelativePoint {
+            date: symbol_bars[i].date.clone(),
+            beta: beta[i],
+            correlation: correlation[i],
+            relative_strength: rs[i],
+        })
+        .collect();
+
+    RelativeReport {
+        window,
+        bars: closes.len(),
+        symbol_return: period_return(&own),
+        benchmark_return: period_return(&aligned),
+        beta: last.and_then(|i| beta[i]),
+        correlation: last.and_then(|i| correlation[i]),
+        relative_strength: last.and_then(|i| rs[i]),
+        suspended_dates: suspended_dates(symbol_bars, benchmark_bars),
+        benchmark_missing_dates: symbol_bars
+            .iter()
+            .zip(&aligned)
+            .filter(|(_, v)| v.is_none())
+            .map(|(b, _)| b.date.clone())
+            .collect(),
+        recent,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn bars(points: &[(&str, f64)]) -> Vec<KlineBar> {
+        points
+            .iter()
+            .map(|(date, c)| KlineBar {
+                date: date.to_string(),
+                open: *c,
+                close: *c,
+                high: *c,
+                low: *c,
+                volume: 1000.0,
+                amount: c * 1000.0,
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_beta_with_suspension() {
+        // 个股 01-03、01-04 停牌：复牌日收益与基准 01-02 → 01-05 的收益配对
+        let index = bars(&[
+            ("2025-01-01", 100.0),
+            ("2025-01-02", 101.0),
+            ("2025-01-03", 102.0),
+            ("2025-01-04", 103.0),
+            ("2025-01-05", 104.0),
+            ("2025-01-06", 102.0),
+        ]);
+        let stock = bars(&[
+            ("2025-01-01", 10.0),
+            ("2025-01-02", 10.2),
+            ("2025-01-05", 10.6),
+            ("2025-01-06", 10.3),
+        ]);
+        let report = analyze(&stock, &index, 3);
+        assert_eq!(report.suspended_dates, vec!["2025-01-03", "2025-01-04"]);
+        assert!(report.benchmark_missing_dates.is_empty());
+        assert!(report.beta.is_some());
+        assert!(report.correlation.unwrap() > 0.9);
+        assert_eq!(report.recent.len(), 4);
+    }
+
+    #[test]
+    fn test_beta_of_leveraged_series() {
+        // 个股日收益恒为基准的 2 倍
+        let mut index = Vec::new();
+        let mut stock = Vec::new();
+        let (mut ic, mut sc) = (100.0, 10.0);
+        for (i, r) in [0.01, -0.02, 0.015, 0.0, -0.01, 0.02].iter().enumerate() {
+            ic *= 1.0 + r;
+            sc *= 1.0 + 2.0 * r;
+            let date = format!("2025-01-{:02}", i + 1);
+            index.push((date.clone(), ic));
+            stock.push((date, sc));
+        }
+        let to_bars = |v: &[(String, f64)]| -> Vec<KlineBar> {
+            bars(&v.iter().map(|(d, c)| (d.as_str(), *c)).collect::<Vec<_>>())
+        };
+        let report = analyze(&to_bars(&stock), &to_bars(&index), 4);
+        assert!((report.beta.unwrap() - 2.0).abs() < 1e-9);
+        assert!((report.correlation.unwrap() - 1.0).abs() < 1e-9);
+        assert!(report.relative_strength.is_some());
+    }
+
+    #[test]
+    fn test_missing_benchmark_dates() {
+        let other = bars(&[("2025-01-01", 10.0), ("2025-01-03", 11.0)]);
+        let stock = bars(&[("2025-01-01", 5.0), ("2025-01-02", 5.5), ("2025-01-03", 6.0)]);
+        let aligned = align_closes(&stock, &other);
+        assert_eq!(aligned, vec![Some(10.0), None, Some(11.0)]);
+        // 缺失日期前后的收益都无法计算
+        assert_eq!(returns(&aligned), vec![None, None, None]);
+        let report = analyze(&stock, &other, 2);
+        assert_eq!(report.benchmark_missing_dates, vec!["2025-01-02"]);
+        assert!(report.beta.is_none());
+        assert!((report.relative_strength.unwrap() - 6.0 / 5.0 / 1.1 * 100.0).abs() < 1e-9);
+    }
+}
@@ -1,29 +1,111 @@
 use crate::db::Database;
-use crate::services::{kline, market, tdx};
+use crate::services::tdx::chips::ChipModel;
+use crate::services::tdx::evaluator::UserIndicator;
+use crate::services::{align, batch, calendar, indicator_signal, kline, market, order_book, price_limit, symbol_source, tdx, timeline};
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 use std::path::PathBuf;
-use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
 use tauri::{AppHandle, Emitter};
 
+#[derive(Clone)]
 pub struct Scheduler {
     db: Arc<Database>,
     app_handle: AppHandle,
     app_data_dir: PathBuf,
+    /// 指标检查进行中（上一轮未结束时跳过本轮）
+    indicators_running: Arc<AtomicBool>,
+    /// 上一次检查时各股票的买一档（价位、量、交易日），用于买一量骤减提醒
+    bid1_history: Arc<Mutex<HashMap<String, order_book::Bid1Snapshot>>>,
 }
 
-/// 判断当前是否为 A 股交易时间（北京时间 9:30-11:30, 13:00-15:00, 周一到周五）
-fn is_market_hours() -> bool {
-    use chrono::{Datelike, Timelike, Utc};
-    let now = Utc::now() + chrono::Duration::hours(8);
-    let weekday = now.weekday().num_days_from_monday();
-    if weekday >= 5 {
-        return false;
+/// 指标检查中同时进行的行情请求 / 公式计算数
+const MAX_CONCURRENT_REQUESTS: usize = 8;
+/// 单个行情请求或公式计算的超时
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(8);
+/// 一轮指标检查的总超时，超时后放弃本轮，下个周期重新开始
+const INDICATOR_CHECK_TIMEOUT: Duration = Duration::from_secs(120);
+
+/// 离开作用域时清除运行标记（正常结束、出错、超时或 panic 均会清除）
+struct RunningGuard(Arc<AtomicBool>);
+
+impl Drop for RunningGuard {
+    fn drop(&mut self) {
+        self.0.store(false, Ordering::SeqCst);
+    }
+}
+
+/// 本周期到期的指标
+struct IndicatorJob {
+    id: String,
+    name: String,
+    formula_source: String,
+    task_id: Option<String>,
+    last_signal: Option<String>,
+    symbols: Vec<String>,
+    adjust_mode: kline::AdjustMode,
+    chip_model: Option<ChipModel>,
+    fill_policy: align::FillPolicy,
+    user_indicators: Arc<HashMap<String, UserIndicator>>,
+    bar_count: usize,
+}
+
+/// 买一量相对上一次检查的变化
+struct Bid1Drop {
+    previous: f64,
+    current: f64,
+    percent: f64,
+}
+
+/// 大卖单提醒用的成交明细（每只股票最近 DEFAULT_TICK_COUNT 笔）
+async fn fetch_tick_map(symbols: Vec<String>) -> HashMap<String, Vec<order_book::TradeTick>> {
+    let tasks = symbols
+        .iter()
+        .cloned()
+        .map(|symbol| async move { order_book::fetch_ticks(&symbol, order_book::DEFAULT_TICK_COUNT).await })
+        .collect();
+    let results = batch::run_bounded(tasks, MAX_CONCURRENT_REQUESTS, REQUEST_TIMEOUT).await;
+    let mut map = HashMap::new();
+    for (symbol, result) in symbols.into_iter().zip(results) {
+        match result {
+            Ok(ticks) => {
+                map.insert(symbol, ticks);
+            }
+            Err(e) => eprintln!("获取 {} 成交明细失败: {}", symbol, e),
+        }
+    }
+    map
+}
+
+/// 均价线提醒和 Agent Plan 条件用的分时指标
+async fn fetch_timeline_stats(symbols: Vec<String>) -> HashMap<String, timeline::TimelineStats> {
+    let tasks = symbols
+        .iter()
+        .cloned()
+        .map(|symbol| async move { timeline::fetch_stats(&symbol).await })
+        .collect();
+    let results = batch::run_bounded(tasks, MAX_CONCURRENT_REQUESTS, REQUEST_TIMEOUT).await;
+    let mut map = HashMap::new();
+    for (symbol, result) in symbols.into_iter().zip(results) {
+        match result {
+            Ok(stats) => {
+                map.insert(symbol, stats);
+            }
+            Err(e) => eprintln!("获取 {} 分时数据失败: {}", symbol, e),
+        }
     }
-    let hour = now.hour();
-    let minute = now.minute();
-    let time_mins = hour * 60 + minute;
+    map
+}
 
-    (570..=690).contains(&time_mins) || (780..=900).contains(&time_mins)
+/// 计划的条件是否用到分时字段（用到时 fetch_data 步骤附带分时指标）
+fn plan_uses_timeline(plan: &AgentPlan) -> bool {
+    plan.steps
+        .iter()
+        .filter(|s| s.step_type == "condition_check")
+        .flat_map(|s| s.config["conditions"].as_array().into_iter().flatten())
+        .any(|c| c["field"].as_str().is_some_and(|f| timeline::CONDITION_FIELDS.contains(&f)))
 }
 
 #[derive(serde::Serialize, Clone)]
@@ -90,20 +172,36 @@ struct AgentPlanVisionPayload {
 
 impl Scheduler {
     pub fn new(db: Arc<Database>, app_handle: AppHandle, app_data_dir: PathBuf) -> Self {
-        Self { db, app_handle, app_data_dir }
+        Self {
+            db,
+            app_handle,
+            app_data_dir,
+            indicators_running: Arc::new(AtomicBool::new(false)),
+            bid1_history: Arc::new(Mutex::new(HashMap::new())),
+        }
     }
 
     pub async fn run(&self) {
         loop {
-            let market_open = is_market_hours();
+            // 集合竞价和连续竞价时段，节假日和午休不轮询
+            let market_open = calendar::is_market_open();
 
             if market_open {
                 if let Err(e) = self.check_alerts().await {
                     eprintln!("检查提醒失败: {}", e);
                 }
 
-                if let Err(e) = self.check_indicators().await {
-                    eprintln!("检查指标失败: {}", e);
+                // 指标检查可能超过一个周期，放到后台执行，不阻塞提醒和 Agent Plan
+                if !self.indicators_running.swap(true, Ordering::SeqCst) {
+                    let scheduler = self.clone();
+                    tokio::spawn(async move {
+                        let _running = RunningGuard(Arc::clone(&scheduler.indicators_running));
+                        match tokio::time::timeout(INDICATOR_CHECK_TIMEOUT, scheduler.check_indicators()).await {
+                            Ok(Err(e)) => eprintln!("检查指标失败: {}", e),
+                            Err(_) => eprintln!("检查指标超时（{} 秒），本轮放弃", INDICATOR_CHECK_TIMEOUT.as_secs()),
+                            Ok(Ok(())) => {}
+                        }
+                    });
                 }
             }
 
@@ -209,7 +307,7 @@ impl Scheduler {
             }
             "daily" => {
                 let trigger_time = plan.schedule.trigger_time.as_deref().unwrap_or("09:30");
-                let beijing_now = now + chrono::Duration::hours(8);
+                let beijing_now = calendar::beijing(now);
                 let current_time = beijing_now.format("%H:%M").to_string();
 
                 if current_time != trigger_time {
@@ -220,9 +318,7 @@ impl Scheduler {
                 match &plan.execution_state.last_executed_at {
                     Some(last) => {
                         if let Ok(last_time) = chrono::DateTime::parse_from_rfc3339(last) {
-                            let last_beijing = last_time + chrono::Duration::hours(8);
-                            last_beijing.format("%Y-%m-%d").to_string()
-                                != beijing_now.format("%Y-%m-%d").to_string()
+                            calendar::beijing(last_time.with_timezone(&chrono::Utc)).date() != beijing_now.date()
                         } else {
                             true
                         }
@@ -254,8 +350,31 @@ impl Scheduler {
                         })
                         .unwrap_or_else(|| plan.stock_symbols.clone());
 
-                    let quotes = market::fetch_batch_quotes(&symbols).await?;
-                    step_results[&step.id] = serde_json::to_value(&quotes).unwrap_or_default();
+                    let batch = market::fetch_batch_quotes(&symbols).await?;
+                    for e in &batch.errors {
+                        eprintln!("Plan {} 获取 {} 行情失败: {}", task_id, e.symbol, e.error);
+                    }
+                    let mut quotes = serde_json::to_value(&batch.quotes).unwrap_or_default();
+                    if plan_uses_timeline(plan) {
+                        let symbols: Vec<String> = batch.quotes.iter().map(|q| q.symbol.clone()).collect();
+                        // 按市场.代码对应，sh000001（上证指数）与 000001（平安银行）不会混淆
+                        let stats: HashMap<String, timeline::TimelineStats> = fetch_timeline_stats(symbols)
+                            .await
+                            .into_iter()
+                            .map(|(symbol, stats)| (market::secid(&symbol), stats))
+                            .collect();
+                        for quote in quotes.as_array_mut().into_iter().flatten() {
+                            let Some(s) = quote["symbol"].as_str().and_then(|symbol| stats.get(&market::secid(symbol))) else {
+                                continue;
+                            };
+                            if let (Some(obj), Ok(serde_json::Value::Object(fields))) =
+                                (quote.as_object_mut(), serde_json::to_value(s))
+                            {
+                                obj.extend(fields);
+                            }
+                        }
+                    }
+                    step_results[&step.id] = quotes;
                 }
                 "condition_check" => {
                     condition_met = self.evaluate_conditions(&step.config, &step_results);
@@ -374,7 +493,7 @@ impl Scheduler {
         };
         let logic = config["logic"].as_str().unwrap_or("any");
 
-        // 从 step_results 中提取行情数据（来自前面的 fetch_data 步骤）
+        // 从 step_results 中提取行情数据（来自前面的 fetch_data 步骤），按市场.代码索引
         let mut quotes_map: std::collections::HashMap<String, &serde_json::Value> =
             std::collections::HashMap::new();
 
@@ -382,7 +501,7 @@ impl Scheduler {
             if let Some(arr) = result.as_array() {
                 for quote in arr {
                     if let Some(symbol) = quote["symbol"].as_str() {
-                        quotes_map.insert(symbol.to_string(), quote);
+                        quotes_map.insert(market::secid(symbol), quote);
                     }
                 }
             }
@@ -396,7 +515,7 @@ impl Scheduler {
                 let operator = cond["operator"].as_str().unwrap_or("gt");
                 let threshold = cond["value"].as_f64().unwrap_or(0.0);
 
-                let quote = match quotes_map.get(symbol) {
+                let quote = match quotes_map.get(&market::secid(symbol)) {
                     Some(q) => q,
                     None => return false,
                 };
@@ -489,7 +608,7 @@ impl Scheduler {
             let conn = self.db.conn.lock().map_err(|e| e.to_string())?;
             let mut stmt = conn
                 .prepare(
-                    "SELECT id, name, formula_source, stock_symbols, task_id, check_interval_secs, last_checked, last_signal
+                    "SELECT id, name, formula_source, stock_symbols, task_id, check_interval_secs, last_checked, last_signal, adjust_mode, chip_model, fill_policy, symbol_source
                      FROM indicator WHERE is_active = 1 AND market_hours_only = 1",
                 )
                 .map_err(|e| e.to_string())?;
@@ -505,6 +624,10 @@ impl Scheduler {
                         row.get::<_, i64>(5)?,      // check_interval_secs
                         row.get::<_, Option<String>>(6)?, // last_checked
                         row.get::<_, Option<String>>(7)?, // last_signal
+                        row.get::<_, String>(8)?,   // adjust_mode
+                        row.get::<_, Option<String>>(9)?, // chip_model JSON
+                        row.get::<_, String>(10)?,  // fill_policy
+                        row.get::<_, Option<String>>(11)?, // symbol_source JSON
                     ))
                 })
                 .map_err(|e| e.to_string())?
@@ -519,7 +642,7 @@ impl Scheduler {
             let conn = self.db.conn.lock().map_err(|e| e.to_string())?;
             let mut stmt = conn
                 .prepare(
-                    "SELECT id, name, formula_source, stock_symbols, task_id, check_interval_secs, last_checked, last_signal
+                    "SELECT id, name, formula_source, stock_symbols, task_id, check_interval_secs, last_checked, last_signal, adjust_mode, chip_model, fill_policy, symbol_source
                      FROM indicator WHERE is_active = 1 AND market_hours_only = 0",
                 )
                 .map_err(|e| e.to_string())?;
@@ -534,6 +657,10 @@ impl Scheduler {
                     row.get::<_, i64>(5)?,
                     row.get::<_, Option<String>>(6)?,
                     row.get::<_, Option<String>>(7)?,
+                    row.get::<_, String>(8)?,
+                    row.get::<_, Option<String>>(9)?,
+                    row.get::<_, String>(10)?,
+                    row.get::<_, Option<String>>(11)?,
                 ))
             })
             .map_err(|e| e.to_string())?
@@ -547,82 +674,208 @@ impl Scheduler {
 
         let now = chrono::Utc::now();
 
-        for (id, name, formula_source, symbols_json, task_id, interval_secs, last_checked, last_signal) in &all_indicators {
+        // 1. 到期的指标及其本周期的股票
+        let mut due: Vec<IndicatorJob> = Vec::new();
+        for (id, name, formula_source, symbols_json, task_id, interval_secs, last_checked, last_signal, adjust_mode, chip_model, fill_policy, symbol_source) in all_indicators {
             // 检查间隔
-            if let Some(last) = last_checked {
+            if let Some(last) = &last_checked {
                 if let Ok(last_time) = chrono::DateTime::parse_from_rfc3339(last) {
-                    if now.signed_duration_since(last_time).num_seconds() < *interval_secs {
+                    if now.signed_duration_since(last_time).num_seconds() < interval_secs {
                         continue;
                     }
                 }
             }
 
-            let symbols: Vec<String> = serde_json::from_str(symbols_json).unwrap_or_default();
+            // 动态来源（自选股、涨停股、板块成分股）每个周期重新解析
+            let fixed: Vec<String> = serde_json::from_str(&symbols_json).unwrap_or_default();
+            let symbol_source = symbol_source.as_ref().and_then(|s| serde_json::from_str(s).ok());
+            let (symbols, source_error) =
+                symbol_source::resolve_symbols(&self.db, &fixed, symbol_source.as_ref()).await;
+            if let Some(e) = source_error {
+                eprintln!("指标 {}: {}", name, e);
+            }
+            let user_indicators = if tdx::referenced_user_indicators(&formula_source).is_empty() {
+                Default::default()
+            } else {
+                self.db.indicator_formulas().unwrap_or_default()
+            };
+            let bar_count = tdx::bars_to_fetch(&formula_source, &user_indicators);
+
+            due.push(IndicatorJob {
+                id,
+                name,
+                formula_source,
+                task_id,
+                last_signal,
+                symbols,
+                adjust_mode: kline::AdjustMode::parse(&adjust_mode).unwrap_or_default(),
+                chip_model: chip_model.as_ref().and_then(|s| serde_json::from_str(s).ok()),
+                fill_policy: align::FillPolicy::parse(&fill_policy).unwrap_or_default(),
+                user_indicators: Arc::new(user_indicators),
+                bar_count,
+            });
+        }
+        if due.is_empty() {
+            return Ok(());
+        }
 
-            for symbol in &symbols {
-                let bars = match kline::fetch_daily_klines(symbol, 300).await {
-                    Ok(b) => b,
-                    Err(e) => {
-                        eprintln!("获取 {} K线失败: {}", symbol, e);
-                        continue;
-                    }
-                };
+        // 2. 多个指标共用的股票只请求一次 K 线（取最大根数，各指标再截取末尾）
+        let mut requests: HashMap<(String, kline::AdjustMode), usize> = HashMap::new();
+        for job in &due {
+            for symbol in &job.symbols {
+                let count = requests.entry((symbol.clone(), job.adjust_mode)).or_insert(0);
+                *count = (*count).max(job.bar_count);
+            }
+        }
+        let keys: Vec<(String, kline::AdjustMode)> = requests.keys().cloned().collect();
+        let fetches = keys
+            .iter()
+            .map(|(symbol, adjust)| {
+                let (symbol, adjust, limit) = (symbol.clone(), *adjust, requests[&(symbol.clone(), *adjust)]);
+                async move { kline::fetch_daily_klines(&symbol, limit, adjust).await }
+            })
+            .collect();
+        let mut klines: HashMap<(String, kline::AdjustMode), Vec<kline::KlineBar>> = HashMap::new();
+        for (key, result) in keys.into_iter().zip(batch::run_bounded(fetches, MAX_CONCURRENT_REQUESTS, REQUEST_TIMEOUT).await) {
+            match result {
+                Ok(bars) => {
+                    klines.insert(key, bars);
+                }
+                Err(e) => eprintln!("获取 {} K线失败: {}", key.0, e),
+            }
+        }
 
-                let eval_result = match tdx::evaluate_formula(&formula_source, &bars) {
-                    Ok(r) => r,
-                    Err(e) => {
-                        eprintln!("计算指标 {} 公式失败: {}", name, e);
-                        continue;
-                    }
+        // 交易日历整个周期只生成一次
+        let trading_days = klines
+            .values()
+            .filter_map(|b| b.first())
+            .map(|b| b.date.as_str())
+            .min()
+            .map(align::trading_calendar)
+            .unwrap_or_default();
+
+        // 3. 并发准备求值选项（筹码、基准 K 线）并计算
+        let mut targets: Vec<(usize, String, Vec<kline::KlineBar>)> = Vec::new();
+        let mut evaluations = Vec::new();
+        for (index, job) in due.iter().enumerate() {
+            for symbol in &job.symbols {
+                let Some(all_bars) = klines.get(&(symbol.clone(), job.adjust_mode)) else {
+                    continue;
                 };
+                let raw = &all_bars[all_bars.len().saturating_sub(job.bar_count)..];
+                let aligned = align::align(raw, &trading_days, job.fill_policy);
+
+                let (symbol_owned, formula, adjust, chip_model, user_indicators) = (
+                    symbol.clone(),
+                    job.formula_source.clone(),
+                    job.adjust_mode,
+                    job.chip_model,
+                    Arc::clone(&job.user_indicators),
+                );
+                let bars = aligned.bars.clone();
+                evaluations.push(async move {
+                    let mut options = tdx::prepare_options(
+                        &symbol_owned,
+                        &formula,
+                        &bars,
+                        adjust,
+                        chip_model,
+                        (*user_indicators).clone(),
+                    )
+                    .await;
+                    options.stale = aligned.stale;
+                    // 公式计算是 CPU 密集的同步代码，放到阻塞线程池
+                    tokio::task::spawn_blocking(move || tdx::evaluate_formula(&formula, &bars, &options))
+                        .await
+                        .map_err(|e| e.to_string())?
+                        .map_err(|e| e.to_string())
+                });
+                targets.push((index, symbol.clone(), aligned.bars));
+            }
+        }
+        let results = batch::run_bounded(evaluations, MAX_CONCURRENT_REQUESTS, REQUEST_TIMEOUT).await;
+
+        // 4. 逐个处理信号（写库、通知）
+        for ((index, symbol, bars), result) in targets.into_iter().zip(results) {
+            let job = &due[index];
+            let (id, name) = (&job.id, &job.name);
+            let eval_result = match result {
+                Ok(r) => r,
+                Err(e) => {
+                    eprintln!("计算指标 {} 公式失败（{}）: {}", name, symbol, e);
+                    continue;
+                }
+            };
 
-                // 检查信号
-                for signal in &eval_result.signals {
-                    if !signal.triggered {
-                        continue;
-                    }
-
-                    // 去重: 与 last_signal 比较
-                    let today = (now + chrono::Duration::hours(8))
-                        .format("%Y-%m-%d")
-                        .to_string();
-                    let signal_key = format!("{}:{}:{}", symbol, signal.text, today);
+            for signal in &eval_result.signals {
+                if !signal.triggered {
+                    continue;
+                }
+                // 停牌中：信号基于停牌前的价格，不提醒
+                if signal.stale {
+                    continue;
+                }
 
-                    if let Some(ls) = last_signal {
-                        if ls == &signal_key {
-                            continue; // 同日同信号不重复
+                let today = calendar::today().format("%Y-%m-%d").to_string();
+                let bar_date = bars
+                    .last()
+                    .map(|b| b.date.get(..10).unwrap_or(&b.date).to_string())
+                    .unwrap_or_else(|| today.clone());
+                let signal_key = format!("{}:{}:{}", symbol, signal.text, today);
+
+                // 写入信号历史，同一根 K 线上的同一信号已记录过则不重复提醒
+                let signal_id = match indicator_signal::record(
+                    &self.db,
+                    id,
+                    &symbol,
+                    &signal.text,
+                    signal.value,
+                    &bar_date,
+                    &now.to_rfc3339(),
+                ) {
+                    Ok(Some(signal_id)) => signal_id,
+                    Ok(None) => continue,
+                    Err(e) => {
+                        eprintln!("写入指标信号失败: {}", e);
+                        // 写入失败时退回 last_signal 去重
+                        if job.last_signal.as_deref() == Some(signal_key.as_str()) {
+                            continue;
                         }
+                        uuid::Uuid::new_v4().to_string()
                     }
+                };
 
-                    // 触发信号
-                    let _ = self.app_handle.emit(
-                        "indicator-signal-triggered",
-                        serde_json::json!({
-                            "indicator_id": id,
-                            "indicator_name": name,
-                            "symbol": symbol,
-                            "signal_text": signal.text,
-                            "signal_value": signal.value,
-                            "task_id": task_id,
-                            "date": today,
-                        }),
-                    );
+                // 触发信号
+                let _ = self.app_handle.emit(
+                    "indicator-signal-triggered",
+                    serde_json::json!({
+                        "signal_id": signal_id,
+                        "indicator_id": id,
+                        "indicator_name": name,
+                        "symbol": symbol,
+                        "signal_text": signal.text,
+                        "signal_value": signal.value,
+                        "task_id": job.task_id,
+                        "date": bar_date,
+                    }),
+                );
 
-                    // 更新 last_signal
-                    if let Ok(conn) = self.db.conn.lock() {
-                        let _ = conn.execute(
-                            "UPDATE indicator SET last_signal = ?1, last_checked = ?2, updated_at = ?2 WHERE id = ?3",
-                            rusqlite::params![signal_key, now.to_rfc3339(), id],
-                        );
-                    }
+                // 更新 last_signal
+                if let Ok(conn) = self.db.conn.lock() {
+                    let _ = conn.execute(
+                        "UPDATE indicator SET last_signal = ?1, last_checked = ?2, updated_at = ?2 WHERE id = ?3",
+                        rusqlite::params![signal_key, now.to_rfc3339(), id],
+                    );
                 }
             }
+        }
 
-            // 更新 last_checked（即使无信号）
-            if let Ok(conn) = self.db.conn.lock() {
+        // 更新 last_checked（即使无信号）
+        if let Ok(conn) = self.db.conn.lock() {
+            for job in &due {
                 let _ = conn.execute(
                     "UPDATE indicator SET last_checked = ?1, updated_at = ?1 WHERE id = ?2",
-                    rusqlite::params![now.to_rfc3339(), id],
+                    rusqlite::params![now.to_rfc3339(), job.id],
                 );
             }
         }
@@ -672,15 +925,47 @@ impl Scheduler {
         let symbols: Vec<String> = symbols_set.into_iter().collect();
 
         // 批量获取行情
-        let quotes = market::fetch_batch_quotes(&symbols).await?;
+        let batch = market::fetch_batch_quotes(&symbols).await?;
         let quote_map: std::collections::HashMap<String, &market::StockQuote> =
-            quotes.iter().map(|q| (q.symbol.clone(), q)).collect();
+            batch.quotes.iter().map(|q| (q.symbol.clone(), q)).collect();
+        let error_map: std::collections::HashMap<&str, &str> = batch
+            .errors
+            .iter()
+            .map(|e| (e.symbol.as_str(), e.error.as_str()))
+            .collect();
+
+        // 盘口类提醒：每只股票只请求一次盘口 / 成交明细
+        let mut book_symbols = std::collections::BTreeSet::new();
+        let mut tick_symbols = std::collections::BTreeSet::new();
+        let mut timeline_symbols = std::collections::BTreeSet::new();
+        for (_, _, symbol, _, condition_json, _) in &alerts {
+            let condition: serde_json::Value = serde_json::from_str(condition_json).unwrap_or_default();
+            match condition["type"].as_str() {
+                Some("bid1_collapse") => book_symbols.insert(symbol.clone()),
+                Some("big_sell_order") => tick_symbols.insert(symbol.clone()),
+                Some("below_vwap" | "above_vwap") => timeline_symbols.insert(symbol.clone()),
+                _ => false,
+            };
+        }
+        let bid1_drops = self.check_bid1_volumes(book_symbols.into_iter().collect()).await;
+        let now = calendar::now();
+        let tick_map = fetch_tick_map(tick_symbols.into_iter().collect()).await;
+        // 分时均价只在连续竞价时段判断，集合竞价、休市和收盘后的分时不再变化
+        let timeline_map = if calendar::phase_at(now) == calendar::MarketPhase::Continuous {
+            fetch_timeline_stats(timeline_symbols.into_iter().collect()).await
+        } else {
+            HashMap::new()
+        };
 
         // 逐条评估
         for (id, _task_id, symbol, _alert_type, condition_json, _last_triggered) in &alerts {
             let quote = match quote_map.get(symbol) {
                 Some(q) => q,
-                None => continue,
+                None => {
+                    let error = error_map.get(symbol.as_str()).copied().unwrap_or("未返回行情");
+                    eprintln!("提醒 {} 无法检查，{} 行情缺失: {}", id, symbol, error);
+                    continue;
+                }
             };
 
             let condition: serde_json::Value =
@@ -695,15 +980,54 @@ impl Scheduler {
                 "change_above" => quote.change_percent >= threshold,
                 "change_below" => quote.change_percent <= -threshold.abs(),
                 "volume_ratio" => quote.volume_ratio >= threshold,
+                "limit_up" => price_limit::is_limit_up(quote),
+                "limit_down" => price_limit::is_limit_down(quote),
+                "limit_break" => price_limit::is_limit_broken(quote),
+                // 距涨停价不超过 threshold%（按涨停价计算）
+                "near_limit_up" => quote
+                    .limit_up
+                    .is_some_and(|p| quote.price > 0.0 && (p - quote.price) / p * 100.0 <= threshold),
+                // 最近一分钟内单笔不少于 threshold 手的主动卖单
+                "big_sell_order" => tick_map
+                    .get(symbol)
+                    .and_then(|ticks| order_book::big_sell_order(ticks, threshold, now))
+                    .is_some(),
+                // 同一买一价位上，买一量比上一次检查减少 threshold% 以上
+                "bid1_collapse" => bid1_drops.get(symbol).is_some_and(|d| d.percent >= threshold),
+                // 价格连续 threshold 分钟在分时均价线下方 / 上方
+                "below_vwap" => timeline_map
+                    .get(symbol)
+                    .is_some_and(|t| t.minutes_below_vwap as f64 >= threshold.max(1.0)),
+                "above_vwap" => timeline_map
+                    .get(symbol)
+                    .is_some_and(|t| t.minutes_above_vwap as f64 >= threshold.max(1.0)),
                 _ => false,
             };
 
             if triggered {
                 let title = format!("{} {}", quote.name, quote.symbol);
-                let body = format!(
+                let mut body = format!(
                     "{}\n当前价格: {:.2} 涨跌幅: {:.2}%",
                     message, quote.price, quote.change_percent
                 );
+                match alert_type {
+                    "big_sell_order" => {
+                        if let Some(tick) = tick_map.get(symbol).and_then(|t| order_book::big_sell_order(t, threshold, now)) {
+                            body.push_str(&format!("\n{} 卖出 {:.0} 手 @ {:.2}", tick.time, tick.volume, tick.price));
+                        }
+                    }
+                    "bid1_collapse" => {
+                        if let Some(d) = bid1_drops.get(symbol) {
+                            body.push_str(&format!("\n买一量 {:.0} → {:.0} 手（-{:.1}%）", d.previous, d.current, d.percent));
+                        }
+                    }
+                    "below_vwap" | "above_vwap" => {
+                        if let Some(t) = timeline_map.get(symbol) {
+                            body.push_str(&format!("\n分时均价: {:.2} 偏离: {:.2}%", t.vwap, t.vwap_deviation));
+                        }
+                    }
+                    _ => {}
+                }
 
                 let _ = self.app_handle.emit(
                     "alert-triggered",
@@ -732,6 +1056,48 @@ impl Scheduler {
         Ok(())
     }
 
+    /// 获取盘口并与上一次的买一档比较，返回同一交易日、同一买一价位上有上一次记录的股票。
+    /// 新交易日的第一次检查先清空上一交易日的记录
+    async fn check_bid1_volumes(&self, symbols: Vec<String>) -> HashMap<String, Bid1Drop> {
+        let tasks = symbols
+            .iter()
+            .cloned()
+            .map(|symbol| async move { order_book::fetch_order_book(&symbol).await })
+            .collect();
+        let books = batch::run_bounded(tasks, MAX_CONCURRENT_REQUESTS, REQUEST_TIMEOUT).await;
+
+        let today = calendar::today();
+        let mut drops = HashMap::new();
+        let mut history = match self.bid1_history.lock() {
+            Ok(history) => history,
+            Err(poisoned) => poisoned.into_inner(),
+        };
+        history.retain(|_, previous| previous.trading_day == today);
+        for (symbol, book) in symbols.into_iter().zip(books) {
+            let book = match book {
+                Ok(book) => book,
+                Err(e) => {
+                    eprintln!("获取 {} 五档盘口失败: {}", symbol, e);
+                    continue;
+                }
+            };
+            let current = book.bid1(today);
+            if let Some(previous) = history.insert(symbol.clone(), current) {
+                if let Some(percent) = order_book::bid1_drop_percent(&previous, &current) {
+                    drops.insert(
+                        symbol,
+                        Bid1Drop {
+                            previous: previous.volume,
+                            current: current.volume,
+                            percent,
+                        },
+                    );
+                }
+            }
+        }
+        drops
+    }
+
     fn check_scheduled_tasks(&self) -> Result<(), String> {
         let conn = self.db.conn.lock().map_err(|e| e.to_string())?;
 
@@ -758,8 +1124,7 @@ impl Scheduler {
             results
         };
 
-        let now = chrono::Utc::now() + chrono::Duration::hours(8);
-        let current_time = now.format("%H:%M").to_string();
+        let current_time = calendar::now().format("%H:%M").to_string();
 
         for (task_id, stock_symbols_json, schedule_config_json) in &tasks {
             let config: serde_json::Value =
@@ -0,0 +1,476 @@
+//! 证券主表
+//!
+//! 从行情数据源同步沪深京 A 股、B 股、ETF 和指数的代码、交易所、板块、类型、ST 标记、
+//! 上市日期和拼音首字母，存入 security 表并在内存中按代码索引。
+//! 行情请求的市场代码（secid）由主表确定，主表中没有的代码才按号段推断。
+//! 股票搜索也在内存索引上完成（代码前缀、拼音首字母、名称模糊匹配），不依赖网络。
+
+use crate::db::Database;
+use crate::services::market::StockSearchResult;
+use crate::services::{pinyin, provider};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::sync::RwLock;
+
+/// 主表超过该时长（小时）未同步时，启动时重新同步
+pub const SYNC_MAX_AGE_HOURS: i64 = 24;
+
+/// 同步的证券类型
+pub const SEC_TYPES: &[&str] = &["stock", "b_share", "etf", "index"];
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Security {
+    pub code: String,
+    /// SH / SZ / BJ
+    pub exchange: String,
+    pub name: String,
+    /// 主板 / 创业板 / 科创板 / 北交所，ETF 和指数为空
+    pub board: Option<String>,
+    /// stock / b_share / etf / index
+    pub sec_type: String,
+    pub is_st: bool,
+    /// YYYY-MM-DD
+    pub listing_date: Option<String>,
+    /// 简称拼音首字母
+    pub pinyin: String,
+}
+
+/// 内存索引
+#[derive(Default)]
+struct Index {
+    securities: Vec<Security>,
+    /// 代码 → securities 下标，同一代码可能同时是深市股票和沪市指数（如 000001）
+    by_code: HashMap<String, Vec<usize>>,
+}
+
+impl Index {
+    fn new(securities: Vec<Security>) -> Self {
+        let mut by_code: HashMap<String, Vec<usize>> = HashMap::new();
+        for (i, s) in securities.iter().enumerate() {
+            by_code.entry(s.code.clone()).or_default().push(i);
+        }
+        Self { securities, by_code }
+    }
+
+    fn listings(&self, code: &str) -> Vec<&Security> {
+        self.by_code
+            .get(code)
+            .map(|ids| ids.iter().map(|&i| &self.securities[i]).collect())
+            .unwrap_or_default()
+    }
+}
+
+static INDEX: once_cell::sync::Lazy<RwLock<Index>> = once_cell::sync::Lazy::new(|| RwLock::new(Index::default()));
+
+fn read_index() -> std::sync::RwLockReadGuard<'static, Index> {
+    match INDEX.read() {
+        Ok(index) => index,
+        Err(poisoned) => poisoned.into_inner(),
+    }
+}
+
+/// 主表中没有的代码按号段推断交易所
+pub fn infer_exchange(code: &str) -> &'static str {
+    if code.starts_with("92") || code.starts_with('8') || code.starts_with('4') {
+        "BJ"
+    } else if code.starts_with('6') || code.starts_with('5') || code.starts_with('9') || code.starts_with('7') {
+        "SH"
+    } else {
+        "SZ"
+    }
+}
+
+/// 主表中没有的代码按号段推断证券类型：B 股 900/200，ETF 51/15/56/58，其余按股票处理
+pub fn infer_sec_type(code: &str) -> &'static str {
+    if code.starts_with("900") || code.starts_with("200") {
+        "b_share"
+    } else if ["51", "15", "56", "58"].iter().any(|p| code.starts_with(p)) {
+        "etf"
+    } else {
+        "stock"
+    }
+}
+
+/// 股票所属板块
+pub fn board_of(exchange: &str, code: &str) -> &'static str {
+    match exchange {
+        "BJ" => "北交所",
+        "SH" if code.starts_with("688") || code.starts_with("689") => "科创板",
+        "SZ" if code.starts_with("30") => "创业板",
+        _ => "主板",
+    }
+}
+
+/// 代码所在的交易所：优先股票/基金，其次指数；主表中没有时按号段推断
+pub fn exchange_of(code: &str) -> String {
+    pick(&read_index().listings(code))
+        .map(|e| e.to_string())
+        .unwrap_or_else(|| infer_exchange(code).to_string())
+}
+
+fn pick<'a>(listings: &[&'a Security]) -> Option<&'a str> {
+    listings
+        .iter()
+        .find(|s| s.sec_type != "index")
+        .or_else(|| listings.first())
+        .map(|s| s.exchange.as_str())
+}
+
+impl Security {
+    /// 由代码、交易所、简称生成主表记录，板块、ST 标记和拼音首字母由此推出
+    pub fn new(code: &str, exchange: &str, name: &str, sec_type: &str, listing_date: Option<String>) -> Self {
+        Self {
+            code: code.to_string(),
+            exchange: exchange.to_string(),
+            name: name.to_string(),
+            board: matches!(sec_type, "stock" | "b_share").then(|| board_of(exchange, code).to_string()),
+            sec_type: sec_type.to_string(),
+            is_st: name.to_uppercase().contains("ST"),
+            listing_date,
+            pinyin: pinyin::initials(name),
+        }
+    }
+}
+
+/// 从数据库加载内存索引
+pub fn load(db: &Database) -> Result<usize, String> {
+    let conn = db.conn.lock().map_err(|e| e.to_string())?;
+    let mut stmt = conn
+        .prepare("SELECT code, exchange, name, board, sec_type, is_st, listing_date, pinyin FROM security")
+        .map_err(|e| e.to_string())?;
+    let securities = stmt
+        .query_map([], row_to_security)
+        .map_err(|e| e.to_string())?
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(|e| e.to_string())?;
+
+    let count = securities.len();
+    *INDEX.write().map_err(|e| e.to_string())? = Index::new(securities);
+    Ok(count)
+}
+
+fn row_to_security(row: &rusqlite::Row) -> rusqlite::Result<Security> {
+    Ok(Security {
+        code: row.get(0)?,
+        exchange: row.get(1)?,
+        name: row.get(2)?,
+        board: row.get(3)?,
+        sec_type: row.get(4)?,
+        is_st: row.get::<_, i64>(5)? != 0,
+        listing_date: row.get(6)?,
+        pinyin: row.get(7)?,
+    })
+}
+
+/// 主表为空或超过 SYNC_MAX_AGE_HOURS 未同步
+pub fn needs_sync(db: &Database) -> bool {
+    let Ok(conn) = db.conn.lock() else {
+        return false;
+    };
+    let last: Option<String> = conn
+        .query_row("SELECT MAX(updated_at) FROM security", [], |row| row.get(0))
+        .unwrap_or(None);
+    match last.and_then(|t| chrono::DateTime::parse_from_rfc3339(&t).ok()) {
+        Some(t) => chrono::Utc::now().signed_duration_since(t).num_hours() >= SYNC_MAX_AGE_HOURS,
+        None => true,
+    }
+}
+
+/// 从当前数据源同步全部证券，返回条数。某一类别请求失败时保留该类别的旧数据
+pub async fn sync(db: &Database) -> Result<usize, String> {
+    let source = provider::current();
+    let mut securities = Vec::new();
+    let mut synced_types = Vec::new();
+    for sec_type in SEC_TYPES {
+        match source.securities(sec_type).await {
+            Ok(items) => {
+                securities.extend(items);
+                synced_types.push(*sec_type);
+            }
+            Err(e) => eprintln!("同步证券主表（{}）失败: {}", sec_type, e),
+        }
+    }
+    if synced_types.is_empty() {
+        return Err("同步证券主表失败".to_string());
+    }
+
+    {
+        let mut conn = db.conn.lock().map_err(|e| e.to_string())?;
+        let tx = conn.transaction().map_err(|e| e.to_string())?;
+        let now = chrono::Utc::now().to_rfc3339();
+        for sec_type in &synced_types {
+            tx.execute("DELETE FROM security WHERE sec_type = ?1", rusqlite::params![sec_type])
+                .map_err(|e| e.to_string())?;
+        }
+        {
+            let mut stmt = tx
+                .prepare(
+                    "INSERT OR REPLACE INTO security (code, exchange, name, board, sec_type, is_st, listing_date, pinyin, updated_at)
+                     VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9)",
+                )
+                .map_err(|e| e.to_string())?;
+            for s in &securities {
+                stmt.execute(rusqlite::params![
+                    s.code, s.exchange, s.name, s.board, s.sec_type, s.is_st as i64, s.listing_date, s.pinyin, now
+                ])
+                .map_err(|e| e.to_string())?;
+            }
+        }
+        tx.commit().map_err(|e| e.to_string())?;
+    }
+
+    load(db)?;
+    Ok(securities.len())
+}
+
+/// 按代码查询（可带 sh/sz/bj 前缀），同一代码有多条时优先股票/基金
+pub fn get(db: &Database, symbol: &str) -> Result<Option<Security>, String> {
+    let lower = symbol.trim().to_lowercase();
+    let (exchange, code) = match lower.get(..2) {
+        Some(p @ ("sh" | "sz" | "bj")) => (Some(p.to_uppercase()), &lower[2..]),
+        _ => (None, lower.as_str()),
+    };
+
+    let conn = db.conn.lock().map_err(|e| e.to_string())?;
+    let mut stmt = conn
+        .prepare(
+            "SELECT code, exchange, name, board, sec_type, is_st, listing_date, pinyin FROM security
+             WHERE code = ?1 AND (?2 IS NULL OR exchange = ?2)
+             ORDER BY sec_type = 'index'",
+        )
+        .map_err(|e| e.to_string())?;
+    let security = stmt.query_row(rusqlite::params![code, exchange], row_to_security).ok();
+    Ok(security)
+}
+
+/// 在内存索引中按代码查询（可带 sh/sz/bj 前缀），同一代码有多条时优先股票/基金
+pub fn lookup(symbol: &str) -> Option<Security> {
+    let lower = symbol.trim().to_lowercase();
+    let (exchange, code) = match lower.get(..2) {
+        Some(p @ ("sh" | "sz" | "bj")) => (Some(p.to_uppercase()), &lower[2..]),
+        _ => (None, lower.as_str()),
+    };
+    let index = read_index();
+    let listings = index.listings(code);
+    match exchange {
+        Some(exchange) => listings.into_iter().find(|s| s.exchange == exchange).cloned(),
+        None => listings
+            .iter()
+            .find(|s| s.sec_type != "index")
+            .or_else(|| listings.first())
+            .map(|s| (*s).clone()),
+    }
+}
+
+/// 主表是否已加载（为空时搜索退回数据源的在线接口）
+pub fn is_loaded() -> bool {
+    !read_index().securities.is_empty()
+}
+
+/// 本地搜索：代码前缀、拼音首字母（如 gzmt）、名称，均支持按顺序的模糊匹配。
+/// 指数的代码带交易所前缀（如 sh000001），避免与同代码的股票混淆
+pub fn search(keyword: &str, limit: usize) -> Vec<StockSearchResult> {
+    let index = read_index();
+    rank(&index.securities, keyword, limit)
+        .into_iter()
+        .map(search_result)
+        .collect()
+}
+
+/// 按匹配分数排序的前 limit 条，分数相同时股票优先，名称短的优先
+fn rank<'a>(securities: &'a [Security], keyword: &str, limit: usize) -> Vec<&'a Security> {
+    let query = normalize_query(keyword);
+    if query.is_empty() {
+        return Vec::new();
+    }
+    let mut hits: Vec<(u32, &Security)> = securities
+        .iter()
+        .filter_map(|s| score(s, &query).map(|score| (score, s)))
+        .collect();
+    hits.sort_by(|(a, x), (b, y)| {
+        b.cmp(a)
+            .then_with(|| type_rank(&x.sec_type).cmp(&type_rank(&y.sec_type)))
+            .then_with(|| x.name.chars().count().cmp(&y.name.chars().count()))
+            .then_with(|| x.code.cmp(&y.code))
+    });
+    hits.into_iter().take(limit).map(|(_, s)| s).collect()
+}
+
+fn search_result(s: &Security) -> StockSearchResult {
+    StockSearchResult {
+        symbol: if s.sec_type == "index" {
+            format!("{}{}", s.exchange.to_lowercase(), s.code)
+        } else {
+            s.code.clone()
+        },
+        name: s.name.clone(),
+        market: match s.exchange.as_str() {
+            "SH" => "沪",
+            "BJ" => "京",
+            _ => "深",
+        }
+        .to_string(),
+    }
+}
+
+fn type_rank(sec_type: &str) -> u8 {
+    match sec_type {
+        "stock" => 0,
+        "etf" => 1,
+        "index" => 2,
+        _ => 3,
+    }
+}
+
+/// 去掉空白和 sh/sz/bj 前缀，字母转大写
+fn normalize_query(keyword: &str) -> String {
+    let q: String = keyword.chars().filter(|c| !c.is_whitespace()).collect::<String>().to_uppercase();
+    match q.get(..2) {
+        Some("SH" | "SZ" | "BJ") if q[2..].chars().next().is_some_and(|c| c.is_ascii_digit()) => q[2..].to_string(),
+        _ => q,
+    }
+}
+
+/// 匹配分数，不匹配为 None
+fn score(s: &Security, query: &str) -> Option<u32> {
+    let name = s.name.to_uppercase();
+    let all_digits = query.chars().all(|c| c.is_ascii_digit());
+
+    if all_digits {
+        return if s.code == query {
+            Some(1000)
+        } else if s.code.starts_with(query) {
+            Some(900)
+        } else if s.code.contains(query) {
+            Some(500)
+        } else {
+            None
+        };
+    }
+
+    if name == query {
+        Some(950)
+    } else if s.pinyin == query {
+        Some(920)
+    } else if name.starts_with(query) {
+        Some(850)
+    } else if s.pinyin.starts_with(query) {
+        Some(820)
+    } else if name.contains(query) {
+        Some(700)
+    } else if s.pinyin.contains(query) {
+        Some(650)
+    } else {
+        // 按顺序出现的模糊匹配（如 "hs300" 匹配 "沪深300"、"茅台酒" 不匹配），跨度越小分数越高
+        subsequence_span(&name, query)
+            .or_else(|| subsequence_span(&s.pinyin, query))
+            .map(|span| 400u32.saturating_sub(10 * (span - query.chars().count()) as u32))
+    }
+}
+
+/// query 的字符在 text 中按顺序出现时，返回首尾跨度（字符数）
+fn subsequence_span(text: &str, query: &str) -> Option<usize> {
+    let text: Vec<char> = text.chars().collect();
+    let mut best: Option<usize> = None;
+    for start in 0..text.len() {
+        let mut q = query.chars().peekable();
+        if q.peek() != Some(&text[start]) {
+            continue;
+        }
+        let mut end = start;
+        for (i, c) in text.iter().enumerate().skip(start) {
+            if q.peek() == Some(c) {
+                q.next();
+                end = i;
+                if q.peek().is_none() {
+                    break;
+                }
+            }
+        }
+        if q.peek().is_none() {
+            let span = end - start + 1;
+            best = Some(best.map_or(span, |b| b.min(span)));
+        }
+    }
+    best
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_infer_and_board() {
+        assert_eq!(infer_exchange("600519"), "SH");
+        assert_eq!(infer_exchange("399001"), "SZ");
+        assert_eq!(infer_exchange("159915"), "SZ");
+        assert_eq!(infer_exchange("510300"), "SH");
+        assert_eq!(infer_exchange("830799"), "BJ");
+        assert_eq!(infer_exchange("920002"), "BJ");
+        assert_eq!(board_of("SH", "688981"), "科创板");
+        assert_eq!(board_of("SZ", "300750"), "创业板");
+        assert_eq!(board_of("SZ", "000001"), "主板");
+
+        // 000001 同时是平安银行和上证指数，行情默认取股票
+        let index = Index::new(sample());
+        let listings = index.listings("000001");
+        assert_eq!(pick(&listings), Some("SZ"));
+        assert_eq!(pick(&listings[..1]), Some("SH"));
+    }
+
+    fn security(code: &str, exchange: &str, name: &str, sec_type: &str) -> Security {
+        Security {
+            code: code.to_string(),
+            exchange: exchange.to_string(),
+            name: name.to_string(),
+            board: None,
+            sec_type: sec_type.to_string(),
+            is_st: false,
+            listing_date: None,
+            pinyin: pinyin::initials(name),
+        }
+    }
+
+    fn sample() -> Vec<Security> {
+        vec![
+            security("000001", "SH", "上证指数", "index"),
+            security("000001", "SZ", "平安银行", "stock"),
+            security("600519", "SH", "贵州茅台", "stock"),
+            security("601318", "SH", "中国平安", "stock"),
+            security("510300", "SH", "沪深300ETF", "etf"),
+            security("000300", "SH", "沪深300", "index"),
+        ]
+    }
+
+    fn search_in(securities: &[Security], keyword: &str) -> Vec<String> {
+        rank(securities, keyword, 10)
+            .into_iter()
+            .map(|s| format!("{}{}", s.exchange, s.code))
+            .collect()
+    }
+
+    #[test]
+    fn test_search_scoring() {
+        let all = sample();
+        assert_eq!(search_in(&all, "gzmt"), vec!["SH600519"]);
+        assert_eq!(search_in(&all, "茅台"), vec!["SH600519"]);
+        assert_eq!(search_in(&all, "6005"), vec!["SH600519"]);
+        // 同代码股票在前
+        assert_eq!(search_in(&all, "000001"), vec!["SZ000001", "SH000001"]);
+        assert_eq!(search_in(&all, "sh600519"), vec!["SH600519"]);
+        // 平安：名称包含优先于拼音
+        assert_eq!(search_in(&all, "平安")[..2], ["SZ000001", "SH601318"]);
+        assert_eq!(search_in(&all, "zgpa"), vec!["SH601318"]);
+        // 拼音完全匹配优先；模糊匹配按顺序出现即可
+        assert_eq!(search_in(&all, "hs300"), vec!["SH000300", "SH510300"]);
+        assert_eq!(search_in(&all, "hs3e"), vec!["SH510300"]);
+        assert!(search_in(&all, "tmgz").is_empty());
+        assert!(search_in(&all, " ").is_empty());
+        // 分数相同时 ETF 排在指数前，limit 截断
+        assert_eq!(search_in(&all, "沪深"), vec!["SH510300", "SH000300"]);
+        assert_eq!(rank(&all, "沪深", 1).len(), 1);
+
+        let symbols: Vec<String> = rank(&all, "000001", 10).into_iter().map(|s| search_result(s).symbol).collect();
+        assert_eq!(symbols, vec!["000001", "sh000001"]);
+    }
+}
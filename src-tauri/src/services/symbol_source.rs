@@ -0,0 +1,161 @@
+//! 指标监控的动态股票来源
+//!
+//! 指标除固定的 stock_symbols 外，还可以绑定一个股票来源（自选股、今日涨停、板块成分股），
+//! 调度器每个周期重新解析，自选股变动后无需修改指标即可纳入监控。
+
+use crate::db::Database;
+use crate::services::market;
+use serde::{Deserialize, Serialize};
+use std::time::Duration;
+
+/// 单个指标每周期最多监控的股票数
+pub const MAX_SYMBOLS: usize = 200;
+/// 解析来源的超时（涨跌停列表、板块成分股需要请求数据源）
+pub const RESOLVE_TIMEOUT: Duration = Duration::from_secs(15);
+
+/// 股票来源
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum SymbolSource {
+    /// 全部自选股
+    Watchlist,
+    /// 今日涨停股
+    LimitUp,
+    /// 今日跌停股
+    LimitDown,
+    /// 行业/概念板块成分股，board 为东方财富板块代码（如 BK0477）或板块名称；
+    /// code 为创建来源时解析出的板块代码，之后每个周期直接按代码获取成分股
+    Board {
+        board: String,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        code: Option<String>,
+    },
+}
+
+impl SymbolSource {
+    /// 来源的中文描述
+    pub fn label(&self) -> String {
+        match self {
+            SymbolSource::Watchlist => "自选股".to_string(),
+            SymbolSource::LimitUp => "今日涨停".to_string(),
+            SymbolSource::LimitDown => "今日跌停".to_string(),
+            SymbolSource::Board { board, .. } => format!("板块 {}", board),
+        }
+    }
+}
+
+/// 保存来源前解析板块代码（板块名称到代码的映射需要下载全部板块列表，不应在每个周期重复）
+pub async fn prepare(source: SymbolSource) -> Result<SymbolSource, String> {
+    match source {
+        SymbolSource::Board { board, code: None } => {
+            let code = if is_board_code(&board) {
+                board.to_uppercase()
+            } else {
+                market::find_board(&board).await?.code
+            };
+            Ok(SymbolSource::Board { board, code: Some(code) })
+        }
+        other => Ok(other),
+    }
+}
+
+fn is_board_code(board: &str) -> bool {
+    board.get(..2).is_some_and(|p| p.eq_ignore_ascii_case("BK"))
+}
+
+/// 解析来源当前包含的股票代码
+pub async fn resolve(db: &Database, source: &SymbolSource) -> Result<Vec<String>, String> {
+    match source {
+        SymbolSource::Watchlist => db.watchlist_symbols(),
+        SymbolSource::LimitUp | SymbolSource::LimitDown => {
+            let limit_type = if *source == SymbolSource::LimitUp { "up" } else { "down" };
+            let quotes = market::fetch_limit_stocks(limit_type).await?;
+            Ok(quotes.into_iter().map(|q| q.symbol).collect())
+        }
+        SymbolSource::Board { code: Some(code), .. } => market::fetch_board_members(code).await,
+        // 早期保存、没有代码的来源
+        SymbolSource::Board { board, code: None } => match prepare(source.clone()).await? {
+            SymbolSource::Board { code: Some(code), .. } => market::fetch_board_members(&code).await,
+            _ => Err(format!("未找到板块: {}", board)),
+        },
+    }
+}
+
+/// 指标本周期监控的股票：固定股票在前，来源中的股票去重后追加，最多 MAX_SYMBOLS 只。
+/// 来源解析失败或超过 RESOLVE_TIMEOUT 时只返回固定股票和错误信息
+pub async fn resolve_symbols(
+    db: &Database,
+    fixed: &[String],
+    source: Option<&SymbolSource>,
+) -> (Vec<String>, Option<String>) {
+    let Some(source) = source else {
+        return (merge(fixed, &[]), None);
+    };
+    let resolved = tokio::time::timeout(RESOLVE_TIMEOUT, resolve(db, source))
+        .await
+        .unwrap_or_else(|_| Err(format!("超时（{} 秒）", RESOLVE_TIMEOUT.as_secs())));
+    match resolved {
+        Ok(dynamic) => (merge(fixed, &dynamic), None),
+        Err(e) => (
+            merge(fixed, &[]),
+            Some(format!("解析股票来源（{}）失败: {}", source.label(), e)),
+        ),
+    }
+}
+
+fn merge(fixed: &[String], dynamic: &[String]) -> Vec<String> {
+    let mut symbols: Vec<String> = Vec::new();
+    for s in fixed.iter().chain(dynamic) {
+        let s = s.trim();
+        if !s.is_empty() && !symbols.iter().any(|x| x == s) {
+            symbols.push(s.to_string());
+        }
+        if symbols.len() >= MAX_SYMBOLS {
+            break;
+        }
+    }
+    symbols
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_source_json() {
+        let source: SymbolSource = serde_json::from_str(r#"{"type":"board","board":"半导体"}"#).unwrap();
+        assert_eq!(source, SymbolSource::Board { board: "半导体".to_string(), code: None });
+        let resolved = SymbolSource::Board {
+            board: "半导体".to_string(),
+            code: Some("BK1036".to_string()),
+        };
+        assert_eq!(
+            serde_json::to_string(&resolved).unwrap(),
+            r#"{"type":"board","board":"半导体","code":"BK1036"}"#
+        );
+        assert_eq!(
+            serde_json::to_string(&SymbolSource::LimitUp).unwrap(),
+            r#"{"type":"limit_up"}"#
+        );
+    }
+
+    #[test]
+    fn test_merge() {
+        let fixed = vec!["600519".to_string(), "000001".to_string()];
+        let dynamic = vec!["000001".to_string(), " 300750 ".to_string(), "".to_string()];
+        assert_eq!(merge(&fixed, &dynamic), vec!["600519", "000001", "300750"]);
+
+        let many: Vec<String> = (0..MAX_SYMBOLS + 10).map(|i| format!("{:06}", i)).collect();
+        assert_eq!(merge(&[], &many).len(), MAX_SYMBOLS);
+    }
+
+    #[tokio::test]
+    async fn test_prepare_board_code() {
+        let source = SymbolSource::Board { board: "bk0477".to_string(), code: None };
+        assert_eq!(
+            prepare(source).await.unwrap(),
+            SymbolSource::Board { board: "bk0477".to_string(), code: Some("BK0477".to_string()) }
+        );
+        assert_eq!(prepare(SymbolSource::Watchlist).await.unwrap(), SymbolSource::Watchlist);
+    }
+}
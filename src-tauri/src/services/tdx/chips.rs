@@ -0,0 +1,280 @@
+//! 筹码分布（成本分布）近似计算
+//!
+//! 每根 K 线按换手率 t = 成交量 / 流通股本 × 衰减系数 让旧筹码衰减，
+//! 新筹码按当日价格区间分布：chips = chips × (1 - t) + t × dist(low, high, 均价)。
+//! 分布以价格档位上的流通盘占比表示，总和恒为 1。
+
+use crate::services::kline::KlineBar;
+use serde::{Deserialize, Serialize};
+
+/// 东方财富 K 线成交量单位为手
+pub const SHARES_PER_LOT: f64 = 100.0;
+
+/// 依赖筹码分布的公式函数
+pub const FUNCTIONS: &[&str] = &["COST", "WINNER", "LWINNER", "PWINNER"];
+
+/// 价格档位数量（覆盖全部 K 线的最低价到最高价）
+const BUCKETS: usize = 200;
+
+/// 当日新增筹码在价格区间上的分布形态
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ChipDistribution {
+    /// 在最低价与最高价之间均匀分布
+    Uniform,
+    /// 三角分布，峰值在当日均价（成交额 / 成交量）
+    #[default]
+    Triangular,
+}
+
+/// 筹码衰减模型
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ChipModel {
+    pub distribution: ChipDistribution,
+    /// 历史换手衰减系数（通达信默认 1.0），换手率乘以该系数后作为旧筹码的衰减比例
+    pub decay: f64,
+}
+
+impl Default for ChipModel {
+    fn default() -> Self {
+        Self {
+            distribution: ChipDistribution::Triangular,
+            decay: 1.0,
+        }
+    }
+}
+
+/// 逐根 K 线的筹码分布
+pub struct Chips {
+    low: f64,
+    step: f64,
+    /// 每根 K 线收盘后的筹码分布
+    snapshots: Vec<Vec<f64>>,
+    /// 每根 K 线上旧筹码的保留比例 (1 - t)
+    keep: Vec<f64>,
+}
+
+impl Chips {
+    pub fn compute(bars: &[KlineBar], float_shares: f64, model: &ChipModel) -> Result<Self, String> {
+        if float_shares <= 0.0 {
+            return Err("筹码分布需要有效的流通股本".to_string());
+        }
+
+        let low = bars.iter().map(|b| b.low).fold(f64::MAX, f64::min);
+        let high = bars.iter().map(|b| b.high).fold(f64::MIN, f64::max);
+        let step = if high > low {
+            (high - low) / BUCKETS as f64
+        } else {
+            0.01
+        };
+
+        let mut chips = Chips {
+            low,
+            step,
+            snapshots: Vec::with_capacity(bars.len()),
+            keep: Vec::with_capacity(bars.len()),
+        };
+
+        let mut current = vec![0.0; BUCKETS];
+        for (i, bar) in bars.iter().enumerate() {
+            // 第一根 K 线之前的历史未知，视为全部筹码在当日换手
+            let t = if i == 0 {
+                1.0
+            } else {
+                (bar.volume * SHARES_PER_LOT / float_shares * model.decay).clamp(0.0, 1.0)
+            };
+            let today = chips.day_distribution(bar, model.distribution);
+            for (c, d) in current.iter_mut().zip(&today) {
+                *c = *c * (1.0 - t) + d * t;
+            }
+            chips.snapshots.push(current.clone());
+            chips.keep.push(1.0 - t);
+        }
+
+        Ok(chips)
+    }
+
+    /// COST(pct): 获利盘比例为 pct% 时的成本价
+    pub fn cost(&self, pct: f64) -> Vec<f64> {
+        let target = (pct / 100.0).clamp(0.0, 1.0);
+        self.snapshots
+            .iter()
+            .map(|dist| {
+                let total: f64 = dist.iter().sum();
+                let mut cum = 0.0;
+                for (b, w) in dist.iter().enumerate() {
+                    let w = w / total;
+                    if w > 0.0 && cum + w >= target {
+                        return self.bucket_low(b) + self.step * (target - cum) / w;
+                    }
+                    cum += w;
+                }
+                self.bucket_low(BUCKETS)
+            })
+            .collect()
+    }
+
+    /// WINNER(price): 成本低于 price 的筹码占流通盘比例（0~1）
+    pub fn winner(&self, prices: &[f64]) -> Vec<f64> {
+        self.snapshots
+            .iter()
+            .zip(prices)
+            .map(|(dist, price)| self.ratio_below(dist, *price))
+            .collect()
+    }
+
+    /// LWINNER(n, price): 最近 n 根 K 线内形成的筹码中获利部分占流通盘比例
+    pub fn lwinner(&self, n: usize, prices: &[f64]) -> Vec<f64> {
+        (0..self.snapshots.len())
+            .map(|i| {
+                let old = self.old_distribution(i, n);
+                let recent: Vec<f64> = self.snapshots[i]
+                    .iter()
+                    .zip(&old)
+                    .map(|(total, old)| (total - old).max(0.0))
+                    .collect();
+                self.ratio_below(&recent, prices[i])
+            })
+            .collect()
+    }
+
+    /// PWINNER(n, price): n 根 K 线之前形成的筹码中获利部分占流通盘比例
+    pub fn pwinner(&self, n: usize, prices: &[f64]) -> Vec<f64> {
+        (0..self.snapshots.len())
+            .map(|i| self.ratio_below(&self.old_distribution(i, n), prices[i]))
+            .collect()
+    }
+
+    /// 第 i 根 K 线时，n 根之前已存在的筹码（经过之后每日衰减）
+    fn old_distribution(&self, i: usize, n: usize) -> Vec<f64> {
+        if n == 0 {
+            return self.snapshots[i].clone();
+        }
+        if i < n {
+            return vec![0.0; BUCKETS];
+        }
+        let survived: f64 = self.keep[i + 1 - n..=i].iter().product();
+        self.snapshots[i - n].iter().map(|w| w * survived).collect()
+    }
+
+    fn ratio_below(&self, dist: &[f64], price: f64) -> f64 {
+        dist.iter()
+            .enumerate()
+            .map(|(b, w)| {
+                let covered = ((price - self.bucket_low(b)) / self.step).clamp(0.0, 1.0);
+                w * covered
+            })
+            .sum()
+    }
+
+    fn bucket_low(&self, b: usize) -> f64 {
+        self.low + b as f64 * self.step
+    }
+
+    fn bucket_mid(&self, b: usize) -> f64 {
+        self.low + (b as f64 + 0.5) * self.step
+    }
+
+    /// 当日新增筹码的分布，总和为 1
+    fn day_distribution(&self, bar: &KlineBar, shape: ChipDistribution) -> Vec<f64> {
+        let mut dist = vec![0.0; BUCKETS];
+        let avg = if bar.volume > 0.0 && bar.amount > 0.0 {
+            (bar.amount / (bar.volume * SHARES_PER_LOT)).clamp(bar.low, bar.high)
+        } else {
+            (bar.high + bar.low + bar.close) / 3.0
+        };
+
+        for (b, d) in dist.iter_mut().enumerate() {
+            let p = self.bucket_mid(b);
+            if p < bar.low || p > bar.high {
+                continue;
+            }
+            *d = match shape {
+                ChipDistribution::Uniform => 1.0,
+                ChipDistribution::Triangular => {
+                    if p <= avg {
+                        if avg > bar.low {
+                            (p - bar.low) / (avg - bar.low)
+                        } else {
+                            1.0
+                        }
+                    } else if bar.high > avg {
+                        (bar.high - p) / (bar.high - avg)
+                    } else {
+                        1.0
+                    }
+                }
+            };
+        }
+
+        let total: f64 = dist.iter().sum();
+        if total > 0.0 {
+            dist.iter_mut().for_each(|d| *d /= total);
+        } else {
+            // 振幅小于一个档位（如一字板）：全部落在收盘价所在档位
+            let b = (((bar.close - self.low) / self.step) as usize).min(BUCKETS - 1);
+            dist[b] = 1.0;
+        }
+        dist
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn bar(low: f64, high: f64, volume: f64) -> KlineBar {
+        let mid = (low + high) / 2.0;
+        KlineBar {
+            date: "2025-01-01".to_string(),
+            open: mid,
+            close: mid,
+            high,
+            low,
+            volume,
+            amount: mid * volume * SHARES_PER_LOT,
+        }
+    }
+
+    #[test]
+    fn test_full_turnover_replaces_chips() {
+        // 第二天换手 100%，筹码全部转移到 20~22 区间
+        let bars = vec![bar(10.0, 12.0, 1000.0), bar(20.0, 22.0, 1000.0)];
+        let chips = Chips::compute(&bars, 100_000.0, &ChipModel::default()).unwrap();
+        let winner = chips.winner(&[11.0, 19.0]);
+        assert!((winner[0] - 0.5).abs() < 0.05);
+        assert!(winner[1].abs() < 1e-9);
+        let cost = chips.cost(50.0);
+        assert!((cost[1] - 21.0).abs() < 0.1);
+    }
+
+    #[test]
+    fn test_lwinner_plus_pwinner_equals_winner() {
+        let bars = vec![
+            bar(10.0, 12.0, 300.0),
+            bar(11.0, 13.0, 300.0),
+            bar(12.0, 14.0, 300.0),
+            bar(13.0, 15.0, 300.0),
+        ];
+        let model = ChipModel {
+            distribution: ChipDistribution::Uniform,
+            decay: 1.0,
+        };
+        let chips = Chips::compute(&bars, 1_000_000.0, &model).unwrap();
+        let prices = vec![12.5; 4];
+        let w = chips.winner(&prices);
+        let lw = chips.lwinner(2, &prices);
+        let pw = chips.pwinner(2, &prices);
+        for i in 0..4 {
+            assert!((lw[i] + pw[i] - w[i]).abs() < 1e-9);
+        }
+        assert!(pw[3] > 0.0 && lw[3] < w[3]);
+    }
+
+    #[test]
+    fn test_requires_float_shares() {
+        let bars = vec![bar(10.0, 12.0, 1000.0)];
+        assert!(Chips::compute(&bars, 0.0, &ChipModel::default()).is_err());
+    }
+}
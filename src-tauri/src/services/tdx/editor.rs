@@ -0,0 +1,616 @@
+//! 公式编辑器辅助：补全、悬停说明、参数提示
+//!
+//! 输入为公式源码和光标位置（字符偏移，非字节偏移）。源码通常是编辑中的不完整公式，
+//! 因此这里只做容错的轻量扫描，不依赖完整的词法/语法分析。
+
+use super::library;
+use serde::Serialize;
+
+/// 公式函数说明
+pub struct FuncDoc {
+    pub name: &'static str,
+    /// 中文别名
+    pub aliases: &'static [&'static str],
+    /// 参数名，可选参数用方括号表示
+    pub params: &'static [&'static str],
+    pub description: &'static str,
+}
+
+/// 内置变量说明
+pub struct VarDoc {
+    pub name: &'static str,
+    pub aliases: &'static [&'static str],
+    pub description: &'static str,
+}
+
+/// 求值引擎支持的函数（与 Evaluator::eval_func 保持一致）
+pub const FUNCTIONS: &[FuncDoc] = &[
+    func("MA", &["X", "N"], "简单移动平均"),
+    func("EMA", &["X", "N"], "指数移动平均"),
+    func("SMA", &["X", "N", "M"], "加权移动平均，M 为权重：Y = (M×X + (N−M)×Y') / N"),
+    func("REF", &["X", "N"], "引用 N 周期前的值"),
+    func("LLV", &["X", "N"], "N 周期内最低值"),
+    func("HHV", &["X", "N"], "N 周期内最高值"),
+    func("IF", &["COND", "A", "B"], "条件成立取 A，否则取 B"),
+    func("MAX", &["A", "B"], "逐根取较大值"),
+    func("MIN", &["A", "B"], "逐根取较小值"),
+    func("ABS", &["X"], "绝对值"),
+    func("CROSS", &["A", "B"], "A 从下方上穿 B"),
+    func("SUM", &["X", "N"], "最近 N 周期求和，N = 0 时从第一根累加"),
+    func("COUNT", &["COND", "N"], "最近 N 周期条件成立的次数"),
+    func("EVERY", &["COND", "N"], "最近 N 周期条件是否全部成立"),
+    func("EXIST", &["COND", "N"], "最近 N 周期条件是否至少成立一次"),
+    func("BARSLAST", &["COND"], "上一次条件成立距今的周期数"),
+    func("AVEDEV", &["X", "N"], "N 周期平均绝对偏差"),
+    func("STD", &["X", "N"], "N 周期标准差"),
+    func("SLOPE", &["X", "N"], "N 周期线性回归斜率"),
+    func("INTPART", &["X"], "取整数部分"),
+    FuncDoc {
+        name: "DOJI",
+        aliases: &["十字星"],
+        params: &["[TOL]"],
+        description: "十字星：实体不超过振幅的 TOL（默认 0.1），可省略括号",
+    },
+    FuncDoc {
+        name: "HAMMER",
+        aliases: &["锤子线"],
+        params: &["[RATIO]", "[TOL]"],
+        description: "锤子线：下影线至少为实体 RATIO 倍（默认 2），上影线不超过振幅 TOL（默认 0.1）",
+    },
+    FuncDoc {
+        name: "ENGULFING",
+        aliases: &["吞没"],
+        params: &["[DIR]", "[TOL]"],
+        description: "吞没形态：DIR 1 看涨（默认）、-1 看跌、0 任一方向",
+    },
+    FuncDoc {
+        name: "MORNINGSTAR",
+        aliases: &["早晨之星"],
+        params: &["[TOL]"],
+        description: "早晨之星：星线实体不超过首根实体的 TOL（默认 0.3）",
+    },
+    FuncDoc {
+        name: "THREECROWS",
+        aliases: &["三只乌鸦"],
+        params: &["[TOL]"],
+        description: "三只乌鸦：连续三根阴线逐级走低，收盘距最低价不超过振幅 TOL（默认 0.3）",
+    },
+    FuncDoc {
+        name: "GAP",
+        aliases: &["跳空缺口"],
+        params: &["[DIR]", "[PCT]"],
+        description: "跳空缺口：DIR 1 向上（默认）、-1 向下，PCT 为最小缺口幅度（%）",
+    },
+    func("COST", &["PCT"], "获利盘比例为 PCT% 时的成本价（需要流通股本）"),
+    func("WINNER", &["PRICE"], "以 PRICE 计算的获利盘比例，0~1（需要流通股本）"),
+    func("LWINNER", &["N", "PRICE"], "近 N 周期形成的筹码中获利部分占流通盘比例"),
+    func("PWINNER", &["N", "PRICE"], "N 周期之前形成的筹码中获利部分占流通盘比例"),
+    func("BETA", &["'CODE'", "N"], "日收益相对基准（如 'sh000300'）的 N 周期贝塔"),
+    func("CORR", &["'CODE'", "N"], "日收益与基准的 N 周期相关系数"),
+    func("RS", &["'CODE'", "N"], "相对强弱：N 周期涨幅 / 基准同期涨幅 × 100，大于 100 跑赢基准"),
+    func("RELATE", &["X", "Y", "N"], "X 与 Y 的 N 周期相关系数"),
+];
+
+/// DRAWTEXT 是语句而非函数，单独说明
+pub const DRAWTEXT: FuncDoc = FuncDoc {
+    name: "DRAWTEXT",
+    aliases: &[],
+    params: &["COND", "PRICE", "'TEXT'"],
+    description: "信号语句：最后一根 K 线上 COND 成立时触发提醒，PRICE 为信号价格",
+};
+
+pub const VARIABLES: &[VarDoc] = &[
+    var("CLOSE", &["C"], "收盘价"),
+    var("OPEN", &["O"], "开盘价"),
+    var("HIGH", &["H"], "最高价"),
+    var("LOW", &["L"], "最低价"),
+    var("VOLUME", &["VOL", "V"], "成交量（手）"),
+    var("AMOUNT", &["AMO"], "成交额（元）"),
+    var("DATE", &[], "日期，格式为 (年-1900)×10000+月×100+日，如 1250102"),
+    var("TIME", &[], "时间 HHMMSS，日线为 0"),
+    var("YEAR", &[], "年份"),
+    var("MONTH", &[], "月份 1~12"),
+    var("DAY", &[], "日 1~31"),
+    var("WEEKDAY", &[], "星期，0 为周日，1~5 为周一至周五"),
+    var("PERIOD", &[], "周期类型，日线为 5"),
+    var("BARPOS", &[], "当前 K 线位置，从 1 开始"),
+    var("CURRBARSCOUNT", &[], "到最后一根 K 线的距离，最后一根为 1"),
+    var("ISLASTBAR", &[], "是否最后一根 K 线"),
+];
+
+const KEYWORDS: &[&str] = &["AND", "OR", "NOT"];
+
+const fn func(
+    name: &'static str,
+    params: &'static [&'static str],
+    description: &'static str,
+) -> FuncDoc {
+    FuncDoc {
+        name,
+        aliases: &[],
+        params,
+        description,
+    }
+}
+
+const fn var(name: &'static str, aliases: &'static [&'static str], description: &'static str) -> VarDoc {
+    VarDoc {
+        name,
+        aliases,
+        description,
+    }
+}
+
+impl FuncDoc {
+    pub fn signature(&self) -> String {
+        format!("{}({})", self.name, self.params.join(", "))
+    }
+
+    fn matches(&self, name: &str) -> bool {
+        self.name == name || self.aliases.contains(&name)
+    }
+}
+
+/// 补全候选
+#[derive(Debug, Clone, Serialize)]
+pub struct CompletionItem {
+    pub label: String,
+    /// function / variable / user_variable / indicator / indicator_output / keyword
+    pub kind: &'static str,
+    /// 签名或简要说明
+    pub detail: String,
+    pub documentation: String,
+    pub insert_text: String,
+}
+
+/// 补全结果，候选替换 [from, 光标) 范围内已输入的前缀
+#[derive(Debug, Clone, Serialize)]
+pub struct Completions {
+    pub from: usize,
+    pub items: Vec<CompletionItem>,
+}
+
+/// 悬停说明
+#[derive(Debug, Clone, Serialize)]
+pub struct HoverInfo {
+    pub name: String,
+    pub kind: &'static str,
+    pub detail: String,
+    pub documentation: String,
+    /// 所指标识符的字符范围 [start, end)
+    pub start: usize,
+    pub end: usize,
+}
+
+/// 参数提示
+#[derive(Debug, Clone, Serialize)]
+pub struct SignatureHelp {
+    pub name: String,
+    pub signature: String,
+    pub documentation: String,
+    pub params: Vec<String>,
+    /// 光标所在参数的下标（从 0 开始）
+    pub active_param: usize,
+}
+
+/// 轻量扫描得到的片段
+#[derive(Debug, Clone, PartialEq)]
+enum Piece {
+    Word { text: String, start: usize, end: usize },
+    Punct(char),
+}
+
+/// 公式中定义的变量
+struct Definition {
+    name: String,
+    kind: &'static str,
+    text: String,
+}
+
+/// 扫描 [0, end) 范围的结果
+struct Scan {
+    pieces: Vec<Piece>,
+    /// 扫描终点是否位于注释或字符串内
+    in_literal: bool,
+}
+
+fn is_word_char(c: char) -> bool {
+    c.is_alphanumeric() || c == '_' || c == '.'
+}
+
+fn scan(chars: &[char], end: usize) -> Scan {
+    let mut pieces = Vec::new();
+    let mut i = 0;
+    while i < end {
+        let c = chars[i];
+        if c == '{' || c == '\'' {
+            let close = if c == '{' { '}' } else { '\'' };
+            match chars[i + 1..end].iter().position(|&x| x == close) {
+                Some(p) => i += p + 2,
+                None => {
+                    return Scan {
+                        pieces,
+                        in_literal: true,
+                    }
+                }
+            }
+        } else if is_word_char(c) {
+            let start = i;
+            while i < end && is_word_char(chars[i]) {
+                i += 1;
+            }
+            pieces.push(Piece::Word {
+                text: chars[start..i].iter().collect(),
+                start,
+                end: i,
+            });
+        } else {
+            if !c.is_whitespace() {
+                pieces.push(Piece::Punct(c));
+            }
+            i += 1;
+        }
+    }
+    Scan {
+        pieces,
+        in_literal: false,
+    }
+}
+
+/// 语句开头的 `NAME :=` / `NAME :` 定义
+fn definitions(chars: &[char], pieces: &[Piece]) -> Vec<Definition> {
+    let mut defs = Vec::new();
+    for (i, piece) in pieces.iter().enumerate() {
+        let Piece::Word { text, start, .. } = piece else {
+            continue;
+        };
+        let at_statement_start = i == 0 || pieces[i - 1] == Piece::Punct(';');
+        if !at_statement_start || pieces.get(i + 1) != Some(&Piece::Punct(':')) {
+            continue;
+        }
+        let kind = if pieces.get(i + 2) == Some(&Piece::Punct('=')) {
+            "assign"
+        } else {
+            "output"
+        };
+        let stmt_end = chars[*start..]
+            .iter()
+            .position(|&c| c == ';')
+            .map(|p| start + p)
+            .unwrap_or(chars.len());
+        defs.push(Definition {
+            name: text.clone(),
+            kind,
+            text: chars[*start..stmt_end].iter().collect::<String>().trim().to_string(),
+        });
+    }
+    defs
+}
+
+/// 光标处的补全候选：内置函数、内置变量、之前定义的变量、内置指标及其输出
+pub fn complete(source: &str, offset: usize) -> Completions {
+    let chars: Vec<char> = source.chars().collect();
+    let cursor = offset.min(chars.len());
+    let scanned = scan(&chars, cursor);
+    if scanned.in_literal {
+        return Completions {
+            from: cursor,
+            items: Vec::new(),
+        };
+    }
+
+    let (prefix, from) = match scanned.pieces.last() {
+        Some(Piece::Word { text, start, end }) if *end == cursor => (text.to_uppercase(), *start),
+        _ => (String::new(), cursor),
+    };
+    let mut items = Vec::new();
+
+    // NAME. 之后补全指标输出
+    if let Some((indicator, partial)) = prefix.split_once('.') {
+        if let Some(builtin) = library::find(indicator) {
+            let mut outputs = super::formula_outputs(builtin.source);
+            outputs.extend(builtin.aliases.iter().map(|(alias, _)| alias.to_string()));
+            for output in outputs.into_iter().filter(|o| o.starts_with(partial)) {
+                items.push(CompletionItem {
+                    label: format!("{}.{}", builtin.name, output),
+                    kind: "indicator_output",
+                    detail: indicator_signature(builtin, &output),
+                    documentation: builtin.description.to_string(),
+                    insert_text: format!("{}.{}", builtin.name, output),
+                });
+            }
+        }
+        return Completions { from, items };
+    }
+
+    let matches = |name: &str| name.to_uppercase().starts_with(&prefix);
+
+    for def in definitions(&chars, &scanned.pieces) {
+        // 正在输入的定义本身不作为候选
+        if matches(&def.name) && def.name.to_uppercase() != prefix {
+            items.push(CompletionItem {
+                label: def.name.clone(),
+                kind: "user_variable",
+                detail: def.text.clone(),
+                documentation: String::new(),
+                insert_text: def.name,
+            });
+        }
+    }
+    for f in FUNCTIONS.iter().chain(std::iter::once(&DRAWTEXT)) {
+        for name in std::iter::once(&f.name).chain(f.aliases) {
+            if matches(name) {
+                items.push(CompletionItem {
+                    label: name.to_string(),
+                    kind: "function",
+                    detail: f.signature(),
+                    documentation: f.description.to_string(),
+                    insert_text: format!("{}(", name),
+                });
+            }
+        }
+    }
+    for v in VARIABLES {
+        for name in std::iter::once(&v.name).chain(v.aliases) {
+            if matches(name) {
+                items.push(CompletionItem {
+                    label: name.to_string(),
+                    kind: "variable",
+                    detail: v.name.to_string(),
+                    documentation: v.description.to_string(),
+                    insert_text: name.to_string(),
+                });
+            }
+        }
+    }
+    for builtin in library::BUILTINS {
+        if matches(builtin.name) {
+            items.push(CompletionItem {
+                label: builtin.name.to_string(),
+                kind: "indicator",
+                detail: format!("{}.输出", builtin.name),
+                documentation: builtin.description.to_string(),
+                insert_text: format!("{}.", builtin.name),
+            });
+        }
+    }
+    for keyword in KEYWORDS {
+        if !prefix.is_empty() && matches(keyword) {
+            items.push(CompletionItem {
+                label: keyword.to_string(),
+                kind: "keyword",
+                detail: String::new(),
+                documentation: String::new(),
+                insert_text: format!("{} ", keyword),
+            });
+        }
+    }
+
+    Completions { from, items }
+}
+
+/// 光标所在标识符的说明
+pub fn hover(source: &str, offset: usize) -> Option<HoverInfo> {
+    let chars: Vec<char> = source.chars().collect();
+    let cursor = offset.min(chars.len());
+    let mut start = cursor;
+    while start > 0 && is_word_char(chars[start - 1]) {
+        start -= 1;
+    }
+    let mut end = cursor;
+    while end < chars.len() && is_word_char(chars[end]) {
+        end += 1;
+    }
+    if start == end || scan(&chars, start).in_literal {
+        return None;
+    }
+
+    let word: String = chars[start..end].iter().collect();
+    let upper = word.to_uppercase();
+    let info = |kind, detail: String, documentation: String| HoverInfo {
+        name: word.clone(),
+        kind,
+        detail,
+        documentation,
+        start,
+        end,
+    };
+
+    if let Some((indicator, output)) = upper.split_once('.') {
+        let builtin = library::find(indicator)?;
+        return Some(info(
+            "indicator_output",
+            indicator_signature(builtin, &builtin.resolve_output(output)),
+            builtin.description.to_string(),
+        ));
+    }
+    if let Some(def) = definitions(&chars, &scan(&chars, chars.len()).pieces)
+        .into_iter()
+        .find(|d| d.name.to_uppercase() == upper)
+    {
+        return Some(info("user_variable", def.text, format!("公式中定义的{}变量", kind_label(def.kind))));
+    }
+    if upper == DRAWTEXT.name {
+        return Some(info("function", DRAWTEXT.signature(), DRAWTEXT.description.to_string()));
+    }
+    if let Some(f) = FUNCTIONS.iter().find(|f| f.matches(&upper)) {
+        return Some(info("function", f.signature(), f.description.to_string()));
+    }
+    if let Some(v) = VARIABLES
+        .iter()
+        .find(|v| v.name == upper || v.aliases.contains(&upper.as_str()))
+    {
+        return Some(info("variable", v.name.to_string(), v.description.to_string()));
+    }
+    if let Some(builtin) = library::find(&upper) {
+        let outputs = super::formula_outputs(builtin.source).join(", ");
+        return Some(info(
+            "indicator",
+            format!("{}.输出，可用输出: {}", builtin.name, outputs),
+            builtin.description.to_string(),
+        ));
+    }
+    None
+}
+
+/// 光标位于函数调用括号内时的参数提示
+pub fn signature_help(source: &str, offset: usize) -> Option<SignatureHelp> {
+    let chars: Vec<char> = source.chars().collect();
+    let cursor = offset.min(chars.len());
+    let scanned = scan(&chars, cursor);
+    if scanned.in_literal {
+        return None;
+    }
+
+    // 未闭合的调用栈：(函数名, 已输入的逗号数)
+    let mut calls: Vec<(Option<String>, usize)> = Vec::new();
+    let mut prev: Option<&Piece> = None;
+    for piece in &scanned.pieces {
+        match piece {
+            Piece::Punct('(') => {
+                let name = match prev {
+                    Some(Piece::Word { text, .. }) => Some(text.to_uppercase()),
+                    _ => None,
+                };
+                calls.push((name, 0));
+            }
+            Piece::Punct(')') => {
+                calls.pop();
+            }
+            Piece::Punct(',') => {
+                if let Some(call) = calls.last_mut() {
+                    call.1 += 1;
+                }
+            }
+            Piece::Punct(';') => calls.clear(),
+            _ => {}
+        }
+        prev = Some(piece);
+    }
+
+    let (name, active_param) = calls.pop()?;
+    let name = name?;
+
+    if let Some((indicator, output)) = name.split_once('.') {
+        let builtin = library::find(indicator)?;
+        return Some(SignatureHelp {
+            signature: indicator_signature(builtin, &builtin.resolve_output(output)),
+            documentation: builtin.description.to_string(),
+            params: builtin
+                .params
+                .iter()
+                .map(|(p, default)| format!("{}={}", p, default))
+                .collect(),
+            active_param,
+            name,
+        });
+    }
+
+    let f = FUNCTIONS
+        .iter()
+        .chain(std::iter::once(&DRAWTEXT))
+        .find(|f| f.matches(&name))?;
+    Some(SignatureHelp {
+        signature: f.signature(),
+        documentation: f.description.to_string(),
+        params: f.params.iter().map(|p| p.to_string()).collect(),
+        active_param,
+        name,
+    })
+}
+
+/// 如 `MACD.DIF(SHORT=12, LONG=26, MID=9)`
+fn indicator_signature(builtin: &library::BuiltinIndicator, output: &str) -> String {
+    let params: Vec<String> = builtin
+        .params
+        .iter()
+        .map(|(p, default)| format!("{}={}", p, default))
+        .collect();
+    format!("{}.{}({})", builtin.name, output, params.join(", "))
+}
+
+fn kind_label(kind: &str) -> &'static str {
+    if kind == "assign" {
+        "中间"
+    } else {
+        "输出"
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::services::kline::KlineBar;
+    use crate::services::tdx::evaluate_formula;
+    use crate::services::tdx::evaluator::EvalOptions;
+
+    #[test]
+    fn test_catalog_matches_evaluator() {
+        let bars: Vec<KlineBar> = (0..5)
+            .map(|i| KlineBar {
+                date: format!("2025-01-{:02}", i + 1),
+                open: 10.0,
+                close: 10.5,
+                high: 11.0,
+                low: 9.5,
+                volume: 1000.0,
+                amount: 10500.0,
+            })
+            .collect();
+        for f in FUNCTIONS {
+            let source = format!("X : {}(C);", f.name);
+            if let Err(e) = evaluate_formula(&source, &bars, &EvalOptions::default()) {
+                assert!(!e.message.contains("不支持的函数"), "{}: {}", f.name, e);
+            }
+        }
+        for v in VARIABLES {
+            for name in std::iter::once(&v.name).chain(v.aliases) {
+                let source = format!("X : {};", name);
+                assert!(evaluate_formula(&source, &bars, &EvalOptions::default()).is_ok(), "{}", name);
+            }
+        }
+    }
+
+    #[test]
+    fn test_complete() {
+        let source = "MA5 := MA(C, 5);\nX : M";
+        let result = complete(source, source.chars().count());
+        assert_eq!(result.from, source.chars().count() - 1);
+        let labels: Vec<&str> = result.items.iter().map(|i| i.label.as_str()).collect();
+        assert!(labels.contains(&"MA5"));
+        assert!(labels.contains(&"MA"));
+        assert!(labels.contains(&"MONTH"));
+        assert!(labels.contains(&"MACD"));
+        assert!(!labels.contains(&"CLOSE"));
+
+        let source = "X : BOLL.U";
+        let labels: Vec<String> = complete(source, source.len())
+            .items
+            .into_iter()
+            .map(|i| i.label)
+            .collect();
+        assert_eq!(labels, vec!["BOLL.UB", "BOLL.UPPER"]);
+
+        // 字符串和注释内不补全
+        assert!(complete("{ M", 3).items.is_empty());
+    }
+
+    #[test]
+    fn test_hover_and_signature() {
+        let source = "十字星 AND MA5 > REF(C, 1);\nMA5 := MA(C, 5);";
+        let h = hover(source, 1).unwrap();
+        assert_eq!(h.detail, "DOJI([TOL])");
+        assert_eq!((h.start, h.end), (0, 3));
+        assert_eq!(hover(source, 9).unwrap().kind, "user_variable");
+        assert_eq!(hover(source, 18).unwrap().detail, "CLOSE");
+        assert_eq!(hover("X : KDJ.J;", 5).unwrap().detail, "KDJ.J(N=9, M1=3, M2=3)");
+
+        let help = signature_help("X : IF(C > MA(C, 5), 1, ", 24).unwrap();
+        assert_eq!(help.name, "IF");
+        assert_eq!(help.active_param, 2);
+        let help = signature_help("X : IF(C > MA(C, ", 17).unwrap();
+        assert_eq!((help.name.as_str(), help.active_param), ("MA", 1));
+        assert_eq!(signature_help("X : MACD.DIF(5, ", 16).unwrap().params[1], "LONG=26");
+        assert!(signature_help("X : MA(C, 5);", 13).is_none());
+    }
+}
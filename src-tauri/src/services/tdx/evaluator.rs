@@ -3,12 +3,31 @@
 /// 所有变量都是 Series（Vec<f64>，每根 K 线一个值）
 /// DRAWTEXT 仅检查最后一根 K 线上条件是否为 true（值 > 0.5）
 
+use super::chips::{ChipModel, Chips};
+use super::limits::{self, LimitError, LimitKind};
 use super::parser::{BinOp, Expr, Statement, UnOp};
-use crate::services::kline::KlineBar;
+use super::{library, patterns};
+use crate::services::kline::{AdjustMode, KlineBar};
+use crate::services::relative;
+use chrono::{Datelike, NaiveDate, NaiveDateTime, Timelike};
+use std::cell::{OnceCell, RefCell};
 use std::collections::HashMap;
+use std::time::{Duration, Instant};
 
 /// Series: 每根 K 线对应一个值
-type Series = Vec<f64>;
+pub(super) type Series = Vec<f64>;
+
+/// 通达信 PERIOD 取值：0..=11 依次为 1/5/15/30/60 分钟、日、周、月、多分钟、多日、季、年
+const PERIOD_DAILY: f64 = 5.0;
+
+/// 可省略括号调用的函数（所有参数均有默认值）
+const PAREN_OPTIONAL_FUNCS: &[&str] = &[
+    "DOJI", "十字星", "HAMMER", "锤子线", "ENGULFING", "吞没", "MORNINGSTAR", "早晨之星",
+    "THREECROWS", "三只乌鸦", "GAP", "跳空缺口",
+];
+
+/// 以其他股票/指数为基准的函数，第一个参数为代码字符串，如 BETA('sh000300', 20)
+pub const SYMBOL_FUNCTIONS: &[&str] = &["BETA", "CORR", "RS"];
 
 /// DRAWTEXT 信号
 #[derive(Debug, Clone, serde::Serialize)]
@@ -16,6 +35,11 @@ pub struct Signal {
     pub text: String,
     pub triggered: bool,
     pub value: f64, // price_expr 在最后一根 K 线上的值
+    /// 最后一根 K 线为停牌日，价格已过时（提醒时应忽略）
+    pub stale: bool,
+    /// 条件在每根 K 线上的值（用于历史信号统计，不序列化）
+    #[serde(skip)]
+    pub history: Series,
 }
 
 /// 求值结果
@@ -23,24 +47,90 @@ pub struct Signal {
 pub struct EvalResult {
     pub outputs: HashMap<String, Vec<f64>>,
     pub signals: Vec<Signal>,
+    /// 计算所用 K 线的复权方式
+    pub adjust_mode: AdjustMode,
+    /// 价格为停牌填充值或已过时的 K 线日期
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub stale_dates: Vec<String>,
+}
+
+/// 可被引用的用户指标：公式及其保存的 K 线设置
+#[derive(Debug, Clone, Default)]
+pub struct UserIndicator {
+    pub source: String,
+    /// 引用方必须使用相同复权方式的 K 线
+    pub adjust_mode: AdjustMode,
+    /// 计算被引用指标中的筹码函数时使用其自身的模型
+    pub chip_model: ChipModel,
+}
+
+/// 求值选项（K 线之外的行情上下文）
+#[derive(Debug, Clone, Default)]
+pub struct EvalOptions {
+    /// 所用 K 线的复权方式，原样记录到 EvalResult
+    pub adjust_mode: AdjustMode,
+    /// 流通股本（股），COST / WINNER 等筹码函数需要
+    pub float_shares: Option<f64>,
+    /// 筹码分布衰减模型
+    pub chip_model: ChipModel,
+    /// 可被 NAME.OUTPUT 引用的用户指标公式（键为大写名称）
+    pub user_indicators: HashMap<String, UserIndicator>,
+    /// BETA / CORR / RS 引用的基准 K 线（键为公式中的代码）
+    pub reference_bars: HashMap<String, Vec<KlineBar>>,
+    /// 与 K 线等长的停牌标记（见 align::AlignedBars::stale），为空表示未按交易日历对齐
+    pub stale: Vec<bool>,
 }
 
 pub struct Evaluator {
-    bars: Vec<KlineBar>,
-    len: usize,
-    vars: HashMap<String, Series>,
+    pub(super) bars: Vec<KlineBar>,
+    pub(super) len: usize,
+    pub(super) vars: HashMap<String, Series>,
+    options: EvalOptions,
+    /// 当前引用链（大写指标名），用于检测循环引用
+    ref_stack: Vec<String>,
+    /// 已计算的指标引用输出，键为 "名称(参数…)"
+    ref_cache: RefCell<HashMap<String, HashMap<String, Series>>>,
+    /// 求值开始时间，引用的指标沿用同一起点，共享耗时上限
+    started: Instant,
+    /// 求值因超出资源限制失败时的超限信息
+    limit: RefCell<Option<LimitError>>,
+    /// 筹码分布，首次调用筹码函数时计算，同一次求值内共用
+    chips: OnceCell<Chips>,
 }
 
 impl Evaluator {
-    pub fn new(bars: Vec<KlineBar>) -> Self {
+    pub fn new(bars: Vec<KlineBar>, options: EvalOptions) -> Self {
         let len = bars.len();
         Self {
             bars,
             len,
             vars: HashMap::new(),
+            options,
+            ref_stack: Vec::new(),
+            ref_cache: RefCell::new(HashMap::new()),
+            started: Instant::now(),
+            limit: RefCell::new(None),
+            chips: OnceCell::new(),
         }
     }
 
+    /// 求值失败的原因是超出资源限制时，取出超限信息
+    pub fn take_limit(&self) -> Option<LimitError> {
+        self.limit.borrow_mut().take()
+    }
+
+    /// 记录超限信息，返回错误文本
+    fn exceeded(&self, e: LimitError) -> String {
+        let message = e.to_string();
+        *self.limit.borrow_mut() = Some(e);
+        message
+    }
+
+    /// 预置常量参数（内置指标的 N、M 等）
+    pub fn set_param(&mut self, name: &str, value: f64) {
+        self.vars.insert(name.to_uppercase(), vec![value; self.len]);
+    }
+
     pub fn evaluate(&mut self, stmts: &[Statement]) -> Result<EvalResult, String> {
         if self.len == 0 {
             return Err("K线数据为空".to_string());
@@ -57,11 +147,13 @@ impl Evaluator {
                 Statement::Assign { name, expr } => {
                     let series = self.eval_expr(expr)?;
                     self.vars.insert(name.clone(), series);
+                    self.check_memory(outputs.len())?;
                 }
                 Statement::Output { name, expr } => {
                     let series = self.eval_expr(expr)?;
                     self.vars.insert(name.clone(), series.clone());
                     outputs.insert(name.clone(), series);
+                    self.check_memory(outputs.len())?;
                 }
                 Statement::DrawText {
                     condition,
@@ -78,20 +170,36 @@ impl Evaluator {
                         text: text.clone(),
                         triggered: last_cond > 0.5,
                         value: last_price,
+                        stale: self.options.stale.last().copied().unwrap_or(false),
+                        history: cond_series,
                     });
                 }
             }
         }
 
-        Ok(EvalResult { outputs, signals })
+        let stale_dates = self
+            .bars
+            .iter()
+            .zip(&self.options.stale)
+            .filter(|(_, stale)| **stale)
+            .map(|(b, _)| b.date.clone())
+            .collect();
+
+        Ok(EvalResult {
+            outputs,
+            signals,
+            adjust_mode: self.options.adjust_mode,
+            stale_dates,
+        })
     }
 
-    fn init_builtin_vars(&mut self) {
+    pub(super) fn init_builtin_vars(&mut self) {
         let close: Series = self.bars.iter().map(|b| b.close).collect();
         let open: Series = self.bars.iter().map(|b| b.open).collect();
         let high: Series = self.bars.iter().map(|b| b.high).collect();
         let low: Series = self.bars.iter().map(|b| b.low).collect();
         let volume: Series = self.bars.iter().map(|b| b.volume).collect();
+        let amount: Series = self.bars.iter().map(|b| b.amount).collect();
 
         self.vars.insert("CLOSE".to_string(), close.clone());
         self.vars.insert("C".to_string(), close);
@@ -104,18 +212,58 @@ impl Evaluator {
         self.vars.insert("VOLUME".to_string(), volume.clone());
         self.vars.insert("V".to_string(), volume.clone());
         self.vars.insert("VOL".to_string(), volume);
+        self.vars.insert("AMOUNT".to_string(), amount.clone());
+        self.vars.insert("AMO".to_string(), amount);
+
+        // 时间相关：DATE 为通达信格式 (年-1900)*10000+月*100+日，如 2025-01-02 → 1250102
+        let datetimes: Vec<Option<NaiveDateTime>> =
+            self.bars.iter().map(|b| parse_bar_datetime(&b.date)).collect();
+        let date_part = |f: fn(&NaiveDateTime) -> f64| -> Series {
+            datetimes.iter().map(|d| d.as_ref().map(f).unwrap_or(0.0)).collect()
+        };
+        let date = date_part(|d| {
+            ((d.year() - 1900) * 10000 + d.month() as i32 * 100 + d.day() as i32) as f64
+        });
+        let time = date_part(|d| (d.hour() * 10000 + d.minute() * 100 + d.second()) as f64);
+        let year = date_part(|d| d.year() as f64);
+        let month = date_part(|d| d.month() as f64);
+        let day = date_part(|d| d.day() as f64);
+        let weekday = date_part(|d| d.weekday().num_days_from_sunday() as f64);
+
+        self.vars.insert("DATE".to_string(), date);
+        self.vars.insert("TIME".to_string(), time);
+        self.vars.insert("YEAR".to_string(), year);
+        self.vars.insert("MONTH".to_string(), month);
+        self.vars.insert("DAY".to_string(), day);
+        self.vars.insert("WEEKDAY".to_string(), weekday);
+
+        // 位置相关：BARPOS 从 1 开始，CURRBARSCOUNT 在最后一根 K 线上为 1
+        let len = self.len;
+        let barpos: Series = (1..=len).map(|i| i as f64).collect();
+        let currbarscount: Series = (0..len).map(|i| (len - i) as f64).collect();
+        let islastbar: Series = (0..len).map(|i| bool_to_f64(i + 1 == len)).collect();
+
+        self.vars.insert("PERIOD".to_string(), vec![PERIOD_DAILY; len]);
+        self.vars.insert("BARPOS".to_string(), barpos);
+        self.vars.insert("CURRBARSCOUNT".to_string(), currbarscount);
+        self.vars.insert("ISLASTBAR".to_string(), islastbar);
     }
 
-    fn eval_expr(&self, expr: &Expr) -> Result<Series, String> {
+    pub(super) fn eval_expr(&self, expr: &Expr) -> Result<Series, String> {
+        self.check_time()?;
         match expr {
             Expr::Number(n) => Ok(vec![*n; self.len]),
             Expr::Str(_) => Ok(vec![0.0; self.len]),
             Expr::Variable(name) => {
                 let upper = name.to_uppercase();
-                self.vars
-                    .get(&upper)
-                    .cloned()
-                    .ok_or_else(|| format!("未定义的变量: {}", name))
+                match self.vars.get(&upper) {
+                    Some(series) => Ok(series.clone()),
+                    // 形态函数全部参数可选，允许省略括号，如 `十字星`
+                    None if PAREN_OPTIONAL_FUNCS.contains(&upper.as_str()) => {
+                        self.eval_func(name, &[])
+                    }
+                    None => Err(format!("未定义的变量: {}", name)),
+                }
             }
             Expr::BinaryOp { op, left, right } => {
                 let l = self.eval_expr(left)?;
@@ -127,7 +275,109 @@ impl Evaluator {
                 self.eval_unary_op(*op, &s)
             }
             Expr::FuncCall { name, args } => self.eval_func(name, args),
+            Expr::IndicatorRef {
+                indicator,
+                output,
+                args,
+            } => self.eval_indicator_ref(indicator, output, args),
+        }
+    }
+
+    /// 计算 NAME.OUTPUT：先查内置指标库，再查用户指标。
+    /// 用户指标按其保存的筹码模型计算，复权方式与当前 K 线不一致时报错
+    fn eval_indicator_ref(&self, indicator: &str, output: &str, args: &[Expr]) -> Result<Series, String> {
+        let upper = indicator.to_uppercase();
+        if self.ref_stack.contains(&upper) {
+            return Err(format!(
+                "指标循环引用: {} -> {}",
+                self.ref_stack.join(" -> "),
+                upper
+            ));
+        }
+
+        let (source, params, target, chip_model) = if let Some(builtin) = library::find(&upper) {
+            if args.len() > builtin.params.len() {
+                return Err(format!(
+                    "指标 {} 最多 {} 个参数，实际传入 {} 个",
+                    upper,
+                    builtin.params.len(),
+                    args.len()
+                ));
+            }
+            let mut params = Vec::with_capacity(builtin.params.len());
+            for (i, (name, default)) in builtin.params.iter().enumerate() {
+                params.push((*name, self.opt_const(args, i, *default)?));
+            }
+            (
+                builtin.source.to_string(),
+                params,
+                builtin.resolve_output(output),
+                self.options.chip_model,
+            )
+        } else if let Some(user) = self.options.user_indicators.get(&upper) {
+            if !args.is_empty() {
+                return Err(format!("用户指标 {} 不支持传入参数", indicator));
+            }
+            if user.adjust_mode != self.options.adjust_mode {
+                return Err(format!(
+                    "用户指标 {} 使用 {} 复权，与当前公式的 {} 复权不一致",
+                    indicator,
+                    user.adjust_mode.as_str(),
+                    self.options.adjust_mode.as_str()
+                ));
+            }
+            (user.source.clone(), Vec::new(), output.to_uppercase(), user.chip_model)
+        } else {
+            return Err(format!("未找到指标: {}", indicator));
+        };
+
+        let key = format!(
+            "{}({})",
+            upper,
+            params
+                .iter()
+                .map(|(_, v)| v.to_string())
+                .collect::<Vec<_>>()
+                .join(",")
+        );
+
+        if !self.ref_cache.borrow().contains_key(&key) {
+            let stmts = super::parse_checked(&source).map_err(|e| {
+                *self.limit.borrow_mut() = e.limit;
+                format!("指标 {} 解析失败: {}", upper, e.message)
+            })?;
+            let mut options = self.options.clone();
+            options.chip_model = chip_model;
+            let mut sub = Evaluator::new(self.bars.clone(), options);
+            sub.started = self.started;
+            sub.ref_stack = self.ref_stack.clone();
+            sub.ref_stack.push(upper.clone());
+            for (name, value) in &params {
+                sub.set_param(name, *value);
+            }
+            let result = sub.evaluate(&stmts).map_err(|e| {
+                *self.limit.borrow_mut() = sub.take_limit();
+                format!("指标 {} 计算失败: {}", upper, e)
+            })?;
+            self.ref_cache.borrow_mut().insert(key.clone(), result.outputs);
         }
+
+        let cache = self.ref_cache.borrow();
+        let outputs = &cache[&key];
+        outputs
+            .iter()
+            .find(|(name, _)| name.to_uppercase() == target)
+            .map(|(_, series)| series.clone())
+            .ok_or_else(|| {
+                let mut names: Vec<&str> = outputs.keys().map(|k| k.as_str()).collect();
+                names.sort();
+                format!(
+                    "指标 {} 没有输出 {}，可用输出: {}",
+                    upper,
+                    output,
+                    names.join(", ")
+                )
+            })
     }
 
     fn eval_binary_op(&self, op: BinOp, left: &Series, right: &Series) -> Result<Series, String> {
@@ -137,6 +387,8 @@ impl Evaluator {
         for i in 0..len {
             let l = left.get(i).copied().unwrap_or(0.0);
             let r = right.get(i).copied().unwrap_or(0.0);
+            // 比较和逻辑运算遇到未定义值（NaN）时结果仍未定义，而不是当作不成立
+            let undefined = l.is_nan() || r.is_nan();
             result[i] = match op {
                 BinOp::Add => l + r,
                 BinOp::Sub => l - r,
@@ -148,11 +400,13 @@ impl Evaluator {
                         l / r
                     }
                 }
+                _ if undefined => f64::NAN,
                 BinOp::Gt => bool_to_f64(l > r),
                 BinOp::Lt => bool_to_f64(l < r),
                 BinOp::Ge => bool_to_f64(l >= r),
                 BinOp::Le => bool_to_f64(l <= r),
                 BinOp::Eq => bool_to_f64((l - r).abs() < f64::EPSILON),
+                BinOp::Ne => bool_to_f64((l - r).abs() >= f64::EPSILON),
                 BinOp::And => bool_to_f64(l > 0.5 && r > 0.5),
                 BinOp::Or => bool_to_f64(l > 0.5 || r > 0.5),
             };
@@ -166,6 +420,7 @@ impl Evaluator {
             .iter()
             .map(|v| match op {
                 UnOp::Neg => -v,
+                UnOp::Not if v.is_nan() => f64::NAN,
                 UnOp::Not => bool_to_f64(*v <= 0.5),
             })
             .collect())
@@ -177,39 +432,39 @@ impl Evaluator {
             "MA" => {
                 self.check_args(&upper, args, 2)?;
                 let data = self.eval_expr(&args[0])?;
-                let period = self.eval_const(&args[1])? as usize;
+                let period = self.eval_period(&upper, &args[1])?;
                 Ok(calc_ma(&data, period))
             }
             "EMA" => {
                 self.check_args(&upper, args, 2)?;
                 let data = self.eval_expr(&args[0])?;
-                let period = self.eval_const(&args[1])? as usize;
+                let period = self.eval_period(&upper, &args[1])?;
                 Ok(calc_ema(&data, period))
             }
             "SMA" => {
                 // SMA(data, period, weight)
                 self.check_args(&upper, args, 3)?;
                 let data = self.eval_expr(&args[0])?;
-                let period = self.eval_const(&args[1])? as usize;
+                let period = self.eval_period(&upper, &args[1])?;
                 let weight = self.eval_const(&args[2])?;
                 Ok(calc_sma(&data, period, weight))
             }
             "REF" => {
                 self.check_args(&upper, args, 2)?;
                 let data = self.eval_expr(&args[0])?;
-                let n = self.eval_const(&args[1])? as usize;
+                let n = self.eval_period(&upper, &args[1])?;
                 Ok(calc_ref(&data, n))
             }
             "LLV" => {
                 self.check_args(&upper, args, 2)?;
                 let data = self.eval_expr(&args[0])?;
-                let period = self.eval_const(&args[1])? as usize;
+                let period = self.eval_period(&upper, &args[1])?;
                 Ok(calc_llv(&data, period))
             }
             "HHV" => {
                 self.check_args(&upper, args, 2)?;
                 let data = self.eval_expr(&args[0])?;
-                let period = self.eval_const(&args[1])? as usize;
+                let period = self.eval_period(&upper, &args[1])?;
                 Ok(calc_hhv(&data, period))
             }
             "IF" => {
@@ -249,18 +504,25 @@ impl Evaluator {
                 let b = self.eval_expr(&args[1])?;
                 Ok(calc_cross(&a, &b))
             }
+            "SUM" => {
+                // SUM(data, period): 最近 period 根求和，period = 0 时从第一根累加
+                self.check_args(&upper, args, 2)?;
+                let data = self.eval_expr(&args[0])?;
+                let period = self.eval_period(&upper, &args[1])?;
+                Ok(calc_sum(&data, period))
+            }
             "COUNT" => {
                 // COUNT(cond, period): 统计最近 period 根 K 线条件成立次数
                 self.check_args(&upper, args, 2)?;
                 let cond = self.eval_expr(&args[0])?;
-                let period = self.eval_const(&args[1])? as usize;
+                let period = self.eval_period(&upper, &args[1])?;
                 Ok(calc_count(&cond, period))
             }
             "EVERY" => {
                 // EVERY(cond, period): 最近 period 根全部满足
                 self.check_args(&upper, args, 2)?;
                 let cond = self.eval_expr(&args[0])?;
-                let period = self.eval_const(&args[1])? as usize;
+                let period = self.eval_period(&upper, &args[1])?;
                 let count = calc_count(&cond, period);
                 Ok(count
                     .iter()
@@ -277,28 +539,28 @@ impl Evaluator {
                 // AVEDEV(data, period): 平均绝对偏差
                 self.check_args(&upper, args, 2)?;
                 let data = self.eval_expr(&args[0])?;
-                let period = self.eval_const(&args[1])? as usize;
+                let period = self.eval_period(&upper, &args[1])?;
                 Ok(calc_avedev(&data, period))
             }
             "STD" => {
                 // STD(data, period): 标准差
                 self.check_args(&upper, args, 2)?;
                 let data = self.eval_expr(&args[0])?;
-                let period = self.eval_const(&args[1])? as usize;
+                let period = self.eval_period(&upper, &args[1])?;
                 Ok(calc_std(&data, period))
             }
             "SLOPE" => {
                 // SLOPE(data, period): 线性回归斜率
                 self.check_args(&upper, args, 2)?;
                 let data = self.eval_expr(&args[0])?;
-                let period = self.eval_const(&args[1])? as usize;
+                let period = self.eval_period(&upper, &args[1])?;
                 Ok(calc_slope(&data, period))
             }
             "EXIST" => {
                 // EXIST(cond, period): 是否存在满足条件的 K 线
                 self.check_args(&upper, args, 2)?;
                 let cond = self.eval_expr(&args[0])?;
-                let period = self.eval_const(&args[1])? as usize;
+                let period = self.eval_period(&upper, &args[1])?;
                 let count = calc_count(&cond, period);
                 Ok(count.iter().map(|c| bool_to_f64(*c > 0.5)).collect())
             }
@@ -307,10 +569,135 @@ impl Evaluator {
                 let data = self.eval_expr(&args[0])?;
                 Ok(data.iter().map(|v| v.trunc()).collect())
             }
+            // ── K 线形态（可选参数为容差） ──
+            "DOJI" | "十字星" => {
+                self.check_args_range(&upper, args, 0, 1)?;
+                let tol = self.opt_const(args, 0, patterns::DOJI_TOLERANCE)?;
+                Ok(patterns::doji(&self.bars, tol))
+            }
+            "HAMMER" | "锤子线" => {
+                // HAMMER([ratio, tol]): 下影线/实体倍数，上影线/振幅容差
+                self.check_args_range(&upper, args, 0, 2)?;
+                let ratio = self.opt_const(args, 0, patterns::HAMMER_RATIO)?;
+                let tol = self.opt_const(args, 1, patterns::HAMMER_TOLERANCE)?;
+                Ok(patterns::hammer(&self.bars, ratio, tol))
+            }
+            "ENGULFING" | "吞没" => {
+                // ENGULFING([dir, tol]): dir 1=看涨 -1=看跌 0=任一
+                self.check_args_range(&upper, args, 0, 2)?;
+                let direction = self.opt_const(args, 0, 1.0)?;
+                let tol = self.opt_const(args, 1, 0.0)?;
+                Ok(patterns::engulfing(&self.bars, direction, tol))
+            }
+            "MORNINGSTAR" | "早晨之星" => {
+                self.check_args_range(&upper, args, 0, 1)?;
+                let tol = self.opt_const(args, 0, patterns::MORNING_STAR_TOLERANCE)?;
+                Ok(patterns::morning_star(&self.bars, tol))
+            }
+            "THREECROWS" | "三只乌鸦" => {
+                self.check_args_range(&upper, args, 0, 1)?;
+                let tol = self.opt_const(args, 0, patterns::THREE_CROWS_TOLERANCE)?;
+                Ok(patterns::three_crows(&self.bars, tol))
+            }
+            "GAP" | "跳空缺口" => {
+                // GAP([dir, pct]): dir 1=向上 -1=向下，pct 为最小缺口幅度（%）
+                self.check_args_range(&upper, args, 0, 2)?;
+                let direction = self.opt_const(args, 0, 1.0)?;
+                let min_pct = self.opt_const(args, 1, 0.0)?;
+                Ok(patterns::gap(&self.bars, direction, min_pct))
+            }
+            // ── 筹码分布 ──
+            "COST" => {
+                // COST(pct): 获利盘为 pct% 时的成本价
+                self.check_args(&upper, args, 1)?;
+                let pct = self.eval_const(&args[0])?;
+                Ok(self.chips(&upper)?.cost(pct))
+            }
+            "WINNER" => {
+                // WINNER(price): 获利盘比例（0~1）
+                self.check_args(&upper, args, 1)?;
+                let price = self.eval_expr(&args[0])?;
+                Ok(self.chips(&upper)?.winner(&price))
+            }
+            "LWINNER" | "PWINNER" => {
+                // LWINNER(N, price) / PWINNER(N, price): 近 N 日 / N 日前筹码的获利比例
+                self.check_args(&upper, args, 2)?;
+                let n = self.eval_period(&upper, &args[0])?;
+                let price = self.eval_expr(&args[1])?;
+                let chips = self.chips(&upper)?;
+                Ok(if upper == "LWINNER" {
+                    chips.lwinner(n, &price)
+                } else {
+                    chips.pwinner(n, &price)
+                })
+            }
+            // ── 相对基准 ──
+            "BETA" | "CORR" | "RS" => {
+                // BETA('sh000300', N): 日收益相对基准的贝塔；CORR: 日收益相关系数；
+                // RS: N 根涨幅相对基准的强弱（>100 跑赢）。按日期对齐，停牌缺失的样本跳过，
+                // 样本不足时为 NaN（未定义），参与比较后仍为 NaN，不会触发信号
+                self.check_args(&upper, args, 2)?;
+                let benchmark = self.reference_closes(&upper, &args[0])?;
+                let n = self.eval_period(&upper, &args[1])?;
+                let closes: Series = self.bars.iter().map(|b| b.close).collect();
+                let result = match upper.as_str() {
+                    "BETA" => relative::rolling_beta(&closes, &benchmark, n),
+                    "CORR" => relative::return_correlation(&closes, &benchmark, n),
+                    _ => relative::relative_strength(&closes, &benchmark, n),
+                };
+                Ok(result.into_iter().map(|v| v.unwrap_or(f64::NAN)).collect())
+            }
+            "RELATE" => {
+                // RELATE(X, Y, N): 两个序列的 N 周期相关系数
+                self.check_args(&upper, args, 3)?;
+                let x: Vec<Option<f64>> = self.eval_expr(&args[0])?.into_iter().map(Some).collect();
+                let y: Vec<Option<f64>> = self.eval_expr(&args[1])?.into_iter().map(Some).collect();
+                let n = self.eval_period(&upper, &args[2])?;
+                Ok(relative::rolling_correlation(&x, &y, n)
+                    .into_iter()
+                    .map(|v| v.unwrap_or(0.0))
+                    .collect())
+            }
+            "YEAR" | "MONTH" | "DAY" | "WEEKDAY" | "DATE" | "TIME" => {
+                // 兼容 YEAR() 写法，等同于内置变量 YEAR
+                self.check_args(&upper, args, 0)?;
+                Ok(self.vars[upper.as_str()].clone())
+            }
             _ => Err(format!("不支持的函数: {}", name)),
         }
     }
 
+    fn chips(&self, func: &str) -> Result<&Chips, String> {
+        if let Some(chips) = self.chips.get() {
+            return Ok(chips);
+        }
+        let float_shares = self
+            .options
+            .float_shares
+            .ok_or_else(|| format!("函数 {} 需要流通股本数据，行情服务未返回", func))?;
+        let chips = Chips::compute(&self.bars, float_shares, &self.options.chip_model)?;
+        Ok(self.chips.get_or_init(|| chips))
+    }
+
+    /// 基准收盘价，按本股 K 线日期对齐
+    fn reference_closes(&self, func: &str, arg: &Expr) -> Result<Vec<Option<f64>>, String> {
+        let code = match arg {
+            Expr::Str(code) => code,
+            _ => {
+                return Err(format!(
+                    "函数 {} 的第一个参数应为基准代码字符串，如 'sh000300'",
+                    func
+                ))
+            }
+        };
+        let bars = self
+            .options
+            .reference_bars
+            .get(code)
+            .ok_or_else(|| format!("函数 {} 缺少基准 {} 的 K 线数据", func, code))?;
+        Ok(relative::align_closes(&self.bars, bars))
+    }
+
     fn check_args(&self, name: &str, args: &[Expr], expected: usize) -> Result<(), String> {
         if args.len() != expected {
             return Err(format!(
@@ -323,6 +710,66 @@ impl Evaluator {
         Ok(())
     }
 
+    fn check_args_range(
+        &self,
+        name: &str,
+        args: &[Expr],
+        min: usize,
+        max: usize,
+    ) -> Result<(), String> {
+        if args.len() < min || args.len() > max {
+            return Err(format!(
+                "函数 {} 需要 {}~{} 个参数，实际传入 {} 个",
+                name,
+                min,
+                max,
+                args.len()
+            ));
+        }
+        Ok(())
+    }
+
+    /// 可选常量参数，未传入时取默认值
+    fn opt_const(&self, args: &[Expr], idx: usize, default: f64) -> Result<f64, String> {
+        match args.get(idx) {
+            Some(expr) => self.eval_const(expr),
+            None => Ok(default),
+        }
+    }
+
+    /// 周期参数：超出上限时报错，负数按 0 处理
+    fn eval_period(&self, func: &str, expr: &Expr) -> Result<usize, String> {
+        let period = self.eval_const(expr)?;
+        limits::check_period(func, period).map_err(|e| self.exceeded(e))?;
+        Ok(period as usize)
+    }
+
+    fn check_time(&self) -> Result<(), String> {
+        let elapsed = self.started.elapsed();
+        if elapsed > Duration::from_millis(limits::MAX_EVAL_MS) {
+            return Err(self.exceeded(LimitError::new(
+                LimitKind::Time,
+                limits::MAX_EVAL_MS as f64,
+                elapsed.as_millis() as f64,
+            )));
+        }
+        Ok(())
+    }
+
+    /// 估算变量、输出和指标引用缓存占用的内存
+    fn check_memory(&self, outputs: usize) -> Result<(), String> {
+        let cached: usize = self.ref_cache.borrow().values().map(|o| o.len()).sum();
+        let bytes = (self.vars.len() + outputs + cached) * self.len * std::mem::size_of::<f64>();
+        if bytes > limits::MAX_MEMORY_BYTES {
+            return Err(self.exceeded(LimitError::new(
+                LimitKind::Memory,
+                limits::MAX_MEMORY_BYTES as f64,
+                bytes as f64,
+            )));
+        }
+        Ok(())
+    }
+
     fn eval_const(&self, expr: &Expr) -> Result<f64, String> {
         match expr {
             Expr::Number(n) => Ok(*n),
@@ -337,6 +784,19 @@ impl Evaluator {
 
 // ── 计算函数 ──
 
+/// 解析 K 线日期，支持 "2025-01-02" 和分钟线的 "2025-01-02 10:30[:00]"
+fn parse_bar_datetime(s: &str) -> Option<NaiveDateTime> {
+    let s = s.trim();
+    NaiveDateTime::parse_from_str(s, "%Y-%m-%d %H:%M:%S")
+        .or_else(|_| NaiveDateTime::parse_from_str(s, "%Y-%m-%d %H:%M"))
+        .ok()
+        .or_else(|| {
+            NaiveDate::parse_from_str(s, "%Y-%m-%d")
+                .ok()
+                .and_then(|d| d.and_hms_opt(0, 0, 0))
+        })
+}
+
 fn bool_to_f64(b: bool) -> f64 {
     if b {
         1.0
@@ -447,7 +907,15 @@ fn calc_if(cond: &[f64], a: &[f64], b: &[f64]) -> Series {
     cond.iter()
         .zip(a.iter())
         .zip(b.iter())
-        .map(|((c, x), y)| if *c > 0.5 { *x } else { *y })
+        .map(|((c, x), y)| {
+            if c.is_nan() {
+                f64::NAN
+            } else if *c > 0.5 {
+                *x
+            } else {
+                *y
+            }
+        })
         .collect()
 }
 
@@ -462,6 +930,19 @@ fn calc_cross(a: &[f64], b: &[f64]) -> Series {
     result
 }
 
+fn calc_sum(data: &[f64], period: usize) -> Series {
+    let mut result = Vec::with_capacity(data.len());
+    let mut acc = 0.0;
+    for (i, v) in data.iter().enumerate() {
+        acc += v;
+        if period > 0 && i >= period {
+            acc -= data[i - period];
+        }
+        result.push(acc);
+    }
+    result
+}
+
 fn calc_count(cond: &[f64], period: usize) -> Series {
     let len = cond.len();
     let mut result = vec![0.0; len];
@@ -583,12 +1064,19 @@ mod tests {
             .collect()
     }
 
+    fn user_indicator(source: &str) -> UserIndicator {
+        UserIndicator {
+            source: source.to_string(),
+            ..Default::default()
+        }
+    }
+
     fn eval_source(source: &str, bars: &[KlineBar]) -> EvalResult {
         let mut t = Tokenizer::new(source);
         let tokens = t.tokenize().unwrap();
         let mut p = Parser::new(tokens);
         let stmts = p.parse().unwrap();
-        let mut e = Evaluator::new(bars.to_vec());
+        let mut e = Evaluator::new(bars.to_vec(), EvalOptions::default());
         e.evaluate(&stmts).unwrap()
     }
 
@@ -659,6 +1147,186 @@ mod tests {
         assert_eq!(result.signals.len(), 1);
     }
 
+    #[test]
+    fn test_market_context_vars() {
+        // 2025-01-01 为周三，2025-01-03 为周五
+        let bars = make_bars(&[10.0, 20.0, 30.0]);
+        let result = eval_source(
+            "D : DATE;\nW : WEEKDAY;\nP : BARPOS;\nN : CURRBARSCOUNT;\nLAST : ISLASTBAR;\nA : AMOUNT;\nY : YEAR();",
+            &bars,
+        );
+        assert_eq!(result.outputs["D"][0], 1250101.0);
+        assert_eq!(result.outputs["W"], vec![3.0, 4.0, 5.0]);
+        assert_eq!(result.outputs["P"], vec![1.0, 2.0, 3.0]);
+        assert_eq!(result.outputs["N"], vec![3.0, 2.0, 1.0]);
+        assert_eq!(result.outputs["LAST"], vec![0.0, 0.0, 1.0]);
+        assert_eq!(result.outputs["A"][2], 300000.0);
+        assert_eq!(result.outputs["Y"][0], 2025.0);
+    }
+
+    #[test]
+    fn test_no_signal_on_friday() {
+        let bars = make_bars(&[10.0, 20.0, 30.0]);
+        let result = eval_source("DRAWTEXT(C > REF(C, 1) AND WEEKDAY <> 5, LOW, '非周五上涨');", &bars);
+        assert!(!result.signals[0].triggered);
+    }
+
+    #[test]
+    fn test_pattern_functions() {
+        let bars = make_bars(&[10.0, 20.0, 30.0]);
+        let result = eval_source("G : 跳空缺口;\nG2 : GAP(1, 100);\nD : DOJI(0.5);", &bars);
+        // make_bars: 每根 low = close - 1，高于前一根 high = close' + 1
+        assert_eq!(result.outputs["G"], vec![0.0, 1.0, 1.0]);
+        assert_eq!(result.outputs["G2"], vec![0.0, 0.0, 0.0]);
+        assert_eq!(result.outputs["D"], vec![1.0, 1.0, 1.0]);
+    }
+
+    #[test]
+    fn test_chip_functions_need_float_shares() {
+        let bars = make_bars(&[10.0, 20.0, 30.0]);
+        let stmts = crate::services::tdx::parse_formula("W : WINNER(C);").unwrap();
+        let err = Evaluator::new(bars.clone(), EvalOptions::default())
+            .evaluate(&stmts)
+            .unwrap_err();
+        assert!(err.contains("流通股本"));
+
+        let options = EvalOptions {
+            float_shares: Some(1_000_000.0),
+            ..Default::default()
+        };
+        let result = Evaluator::new(bars, options).evaluate(&stmts).unwrap();
+        assert!(result.outputs["W"].iter().all(|w| (0.0..=1.0).contains(w)));
+    }
+
+    #[test]
+    fn test_builtin_indicator_reference() {
+        let closes: Vec<f64> = (1..=40).map(|i| 10.0 + (i as f64 * 0.3).sin()).collect();
+        let bars = make_bars(&closes);
+        let result = eval_source(
+            "X : MACD.DIF;\nY : EMA(C, 12) - EMA(C, 26);\nZ : MACD.DIF(5, 10, 3) - (EMA(C, 5) - EMA(C, 10));\nU : BOLL.UPPER - BOLL.UB;",
+            &bars,
+        );
+        assert_eq!(result.outputs["X"], result.outputs["Y"]);
+        assert!(result.outputs["Z"].iter().all(|v| v.abs() < 1e-9));
+        assert!(result.outputs["U"].iter().all(|v| v.abs() < 1e-9));
+    }
+
+    #[test]
+    fn test_user_indicator_reference() {
+        let bars = make_bars(&[10.0, 20.0, 30.0]);
+        let stmts = crate::services::tdx::parse_formula("X : 我的均线.M2 + SUM(C, 0);").unwrap();
+        let mut options = EvalOptions::default();
+        options.user_indicators.insert("我的均线".to_string(), user_indicator("M2 : MA(C, 2);"));
+        let result = Evaluator::new(bars.clone(), options.clone()).evaluate(&stmts).unwrap();
+        assert_eq!(result.outputs["X"], vec![20.0, 45.0, 85.0]);
+
+        // 循环引用
+        options.user_indicators.insert("A".to_string(), user_indicator("X : B.X;"));
+        options.user_indicators.insert("B".to_string(), user_indicator("X : A.X;"));
+        let stmts = crate::services::tdx::parse_formula("Y : A.X;").unwrap();
+        let err = Evaluator::new(bars.clone(), options.clone()).evaluate(&stmts).unwrap_err();
+        assert!(err.contains("循环引用"));
+
+        // 被引用指标保存的复权方式与当前 K 线不同
+        options.user_indicators.insert(
+            "后复权线".to_string(),
+            UserIndicator {
+                adjust_mode: AdjustMode::Backward,
+                ..user_indicator("M : C;")
+            },
+        );
+        let stmts = crate::services::tdx::parse_formula("Y : 后复权线.M;").unwrap();
+        let err = Evaluator::new(bars, options).evaluate(&stmts).unwrap_err();
+        assert!(err.contains("复权不一致"));
+    }
+
+    #[test]
+    fn test_variable_period_limit() {
+        let bars = make_bars(&[10.0, 11.0, 12.0]);
+        let stmts = crate::services::tdx::parse_formula("N := 1e9; X : MA(C, N);").unwrap();
+        let mut e = Evaluator::new(bars.clone(), EvalOptions::default());
+        let err = e.evaluate(&stmts).unwrap_err();
+        assert!(err.contains("资源限制") && err.contains("MA"));
+        let limit = e.take_limit().unwrap();
+        assert_eq!((limit.kind, limit.context.as_deref()), (LimitKind::Period, Some("MA")));
+
+        // 内置指标参数同样受限，超限信息从引用的指标传回
+        let err = crate::services::tdx::evaluate_formula("X : MACD.DIF(1e9);", &bars, &EvalOptions::default())
+            .unwrap_err();
+        assert!(err.message.contains("资源限制"));
+        assert_eq!(err.limit.map(|l| l.kind), Some(LimitKind::Period));
+    }
+
+    #[test]
+    fn test_relative_functions() {
+        // 个股每日涨跌幅为基准的 2 倍
+        let (mut stock, mut index) = (vec![10.0], vec![100.0]);
+        for r in [0.02, -0.01, 0.03, 0.01] {
+            stock.push(stock.last().unwrap() * (1.0 + 2.0 * r));
+            index.push(index.last().unwrap() * (1.0 + r));
+        }
+        let bars = make_bars(&stock);
+        let index = make_bars(&index);
+        let mut options = EvalOptions::default();
+        options.reference_bars.insert("sh000300".to_string(), index);
+
+        let stmts = crate::services::tdx::parse_formula(
+            "B : BETA('sh000300', 3); R : CORR('sh000300', 3); S : RS('sh000300', 4); X : RELATE(C, C * 2, 3);",
+        )
+        .unwrap();
+        let result = Evaluator::new(bars.clone(), options.clone()).evaluate(&stmts).unwrap();
+        assert!(result.outputs["B"][1].is_nan());
+        assert!((result.outputs["B"][4] - 2.0).abs() < 1e-9);
+        assert!((result.outputs["R"][4] - 1.0).abs() < 1e-9);
+        assert!(result.outputs["S"][4] > 100.0);
+        assert!((result.outputs["X"][4] - 1.0).abs() < 1e-9);
+
+        // 样本不足的预热期为未定义，比较、取反后都不会触发信号
+        let stmts = crate::services::tdx::parse_formula(
+            "W : RS('sh000300', 20) < 100; DRAWTEXT(RS('sh000300', 20) < 100, L, '弱'); DRAWTEXT(NOT(W), L, '强');",
+        )
+        .unwrap();
+        let result = Evaluator::new(bars.clone(), options.clone()).evaluate(&stmts).unwrap();
+        assert!(result.outputs["W"].iter().all(|v| v.is_nan()));
+        for signal in &result.signals {
+            assert!(!signal.triggered);
+            assert!(signal.history.iter().all(|v| v.is_nan()));
+        }
+
+        // 未准备基准数据
+        let stmts = crate::services::tdx::parse_formula("B : BETA('sz399006', 3);").unwrap();
+        let err = Evaluator::new(bars, EvalOptions::default()).evaluate(&stmts).unwrap_err();
+        assert!(err.contains("sz399006"));
+        assert_eq!(
+            crate::services::tdx::referenced_symbols(
+                "A : RS('sh000300', 5) + CORR('sh000300', 10);",
+                &HashMap::new()
+            ),
+            vec!["sh000300"]
+        );
+        // 引用的用户指标中的基准同样需要获取
+        let user = HashMap::from([
+            ("STRONG".to_string(), user_indicator("S : RS('sz399006', 5) > BASE.B;")),
+            ("BASE".to_string(), user_indicator("B : BETA('sh000300', 20);")),
+        ]);
+        let mut codes = crate::services::tdx::referenced_symbols("X : STRONG.S;", &user);
+        codes.sort();
+        assert_eq!(codes, vec!["sh000300", "sz399006"]);
+    }
+
+    #[test]
+    fn test_stale_signal() {
+        let bars = make_bars(&[10.0, 11.0, 11.0]);
+        let options = EvalOptions {
+            stale: vec![false, false, true],
+            ..Default::default()
+        };
+        let stmts = crate::services::tdx::parse_formula("DRAWTEXT(C > 10, C, 'up');").unwrap();
+        let result = Evaluator::new(bars, options).evaluate(&stmts).unwrap();
+        assert!(result.signals[0].triggered && result.signals[0].stale);
+        assert_eq!(result.stale_dates, vec!["2025-01-03"]);
+    }
+
     #[test]
     fn test_sma() {
         let bars = make_bars(&[10.0, 20.0, 30.0, 40.0, 50.0]);
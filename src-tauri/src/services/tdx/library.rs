@@ -0,0 +1,221 @@
+//! 内置指标库
+//!
+//! 公式中可通过 `名称.输出` 引用，如 `MACD.DIF`、`KDJ.J`、`BOLL.UPPER`，
+//! 并可按顺序覆盖参数：`MACD.DIF(5, 10, 3)`。
+
+/// 内置指标定义（通达信标准公式）
+pub struct BuiltinIndicator {
+    pub name: &'static str,
+    pub description: &'static str,
+    /// 参数名及默认值，公式中以变量形式使用
+    pub params: &'static [(&'static str, f64)],
+    pub source: &'static str,
+    /// 输出别名，如 BOLL.UPPER → UB
+    pub aliases: &'static [(&'static str, &'static str)],
+}
+
+pub const BUILTINS: &[BuiltinIndicator] = &[
+    BuiltinIndicator {
+        name: "MACD",
+        description: "平滑异同移动平均线",
+        params: &[("SHORT", 12.0), ("LONG", 26.0), ("MID", 9.0)],
+        source: "DIF : EMA(CLOSE, SHORT) - EMA(CLOSE, LONG);
+                 DEA : EMA(DIF, MID);
+                 MACD : (DIF - DEA) * 2;",
+        aliases: &[],
+    },
+    BuiltinIndicator {
+        name: "KDJ",
+        description: "随机指标",
+        params: &[("N", 9.0), ("M1", 3.0), ("M2", 3.0)],
+        source: "RSV := (CLOSE - LLV(LOW, N)) / (HHV(HIGH, N) - LLV(LOW, N)) * 100;
+                 K : SMA(RSV, M1, 1);
+                 D : SMA(K, M2, 1);
+                 J : 3 * K - 2 * D;",
+        aliases: &[],
+    },
+    BuiltinIndicator {
+        name: "RSI",
+        description: "相对强弱指标",
+        params: &[("N1", 6.0), ("N2", 12.0), ("N3", 24.0)],
+        source: "LC := REF(CLOSE, 1);
+                 RSI1 : SMA(MAX(CLOSE - LC, 0), N1, 1) / SMA(ABS(CLOSE - LC), N1, 1) * 100;
+                 RSI2 : SMA(MAX(CLOSE - LC, 0), N2, 1) / SMA(ABS(CLOSE - LC), N2, 1) * 100;
+                 RSI3 : SMA(MAX(CLOSE - LC, 0), N3, 1) / SMA(ABS(CLOSE - LC), N3, 1) * 100;",
+        aliases: &[("RSI", "RSI1")],
+    },
+    BuiltinIndicator {
+        name: "BOLL",
+        description: "布林带",
+        params: &[("M", 20.0)],
+        source: "BOLL : MA(CLOSE, M);
+                 UB : BOLL + 2 * STD(CLOSE, M);
+                 LB : BOLL - 2 * STD(CLOSE, M);",
+        aliases: &[("MID", "BOLL"), ("UPPER", "UB"), ("LOWER", "LB")],
+    },
+    BuiltinIndicator {
+        name: "DMI",
+        description: "趋向指标",
+        params: &[("N", 14.0), ("M", 6.0)],
+        source: "MTR := SUM(MAX(MAX(HIGH - LOW, ABS(HIGH - REF(CLOSE, 1))), ABS(REF(CLOSE, 1) - LOW)), N);
+                 HD := HIGH - REF(HIGH, 1);
+                 LD := REF(LOW, 1) - LOW;
+                 DMP := SUM(IF(HD > 0 AND HD > LD, HD, 0), N);
+                 DMM := SUM(IF(LD > 0 AND LD > HD, LD, 0), N);
+                 PDI : DMP * 100 / MTR;
+                 MDI : DMM * 100 / MTR;
+                 ADX : MA(ABS(MDI - PDI) / (MDI + PDI) * 100, M);
+                 ADXR : (ADX + REF(ADX, M)) / 2;",
+        aliases: &[],
+    },
+    BuiltinIndicator {
+        name: "OBV",
+        description: "能量潮",
+        params: &[("M", 30.0)],
+        source: "VA := IF(CLOSE > REF(CLOSE, 1), VOL, -VOL);
+                 OBV : SUM(IF(CLOSE = REF(CLOSE, 1), 0, VA), 0);
+                 MAOBV : MA(OBV, M);",
+        aliases: &[],
+    },
+    BuiltinIndicator {
+        name: "CCI",
+        description: "商品路径指标",
+        params: &[("N", 14.0)],
+        source: "TYP := (HIGH + LOW + CLOSE) / 3;
+                 CCI : (TYP - MA(TYP, N)) / (0.015 * AVEDEV(TYP, N));",
+        aliases: &[],
+    },
+    BuiltinIndicator {
+        name: "WR",
+        description: "威廉指标",
+        params: &[("N", 10.0), ("N1", 6.0)],
+        source: "WR1 : 100 * (HHV(HIGH, N) - CLOSE) / (HHV(HIGH, N) - LLV(LOW, N));
+                 WR2 : 100 * (HHV(HIGH, N1) - CLOSE) / (HHV(HIGH, N1) - LLV(LOW, N1));",
+        aliases: &[("WR", "WR1")],
+    },
+    BuiltinIndicator {
+        name: "BIAS",
+        description: "乖离率",
+        params: &[("N1", 6.0), ("N2", 12.0), ("N3", 24.0)],
+        source: "BIAS1 : (CLOSE - MA(CLOSE, N1)) / MA(CLOSE, N1) * 100;
+                 BIAS2 : (CLOSE - MA(CLOSE, N2)) / MA(CLOSE, N2) * 100;
+                 BIAS3 : (CLOSE - MA(CLOSE, N3)) / MA(CLOSE, N3) * 100;",
+        aliases: &[("BIAS", "BIAS1")],
+    },
+    BuiltinIndicator {
+        name: "VR",
+        description: "成交量变异率",
+        params: &[("N", 26.0), ("M", 6.0)],
+        source: "TH := SUM(IF(CLOSE > REF(CLOSE, 1), VOL, 0), N);
+                 TL := SUM(IF(CLOSE < REF(CLOSE, 1), VOL, 0), N);
+                 TQ := SUM(IF(CLOSE = REF(CLOSE, 1), VOL, 0), N);
+                 VR : 100 * (TH * 2 + TQ) / (TL * 2 + TQ);
+                 MAVR : MA(VR, M);",
+        aliases: &[],
+    },
+];
+
+/// 按名称查找内置指标（不区分大小写）
+pub fn find(name: &str) -> Option<&'static BuiltinIndicator> {
+    let upper = name.to_uppercase();
+    BUILTINS.iter().find(|b| b.name == upper)
+}
+
+impl BuiltinIndicator {
+    /// 解析输出别名，返回公式中实际的输出名
+    pub fn resolve_output(&self, output: &str) -> String {
+        let upper = output.to_uppercase();
+        self.aliases
+            .iter()
+            .find(|(alias, _)| *alias == upper)
+            .map(|(_, target)| target.to_string())
+            .unwrap_or(upper)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::services::kline::KlineBar;
+    use crate::services::tdx::evaluator::{EvalOptions, Evaluator, UserIndicator};
+    use crate::services::tdx::{parse_formula, requires_float_shares, validate_formula};
+    use std::collections::HashMap;
+
+    #[test]
+    fn test_all_builtins_evaluate() {
+        let bars: Vec<KlineBar> = (0..60)
+            .map(|i| {
+                let c = 10.0 + (i as f64 * 0.7).sin() * 2.0 + i as f64 * 0.05;
+                KlineBar {
+                    date: format!("2025-01-{:02}", i % 28 + 1),
+                    open: c - 0.2,
+                    close: c,
+                    high: c + 0.5,
+                    low: c - 0.5,
+                    volume: 10000.0 + i as f64 * 100.0,
+                    amount: c * 10000.0,
+                }
+            })
+            .collect();
+
+        for builtin in BUILTINS {
+            let stmts = parse_formula(builtin.source)
+                .unwrap_or_else(|e| panic!("{} 解析失败: {}", builtin.name, e));
+            let mut evaluator = Evaluator::new(bars.clone(), EvalOptions::default());
+            for (name, value) in builtin.params {
+                evaluator.set_param(name, *value);
+            }
+            let result = evaluator
+                .evaluate(&stmts)
+                .unwrap_or_else(|e| panic!("{} 计算失败: {}", builtin.name, e));
+            assert!(!result.outputs.is_empty(), "{} 没有输出", builtin.name);
+            for (_, target) in builtin.aliases {
+                assert!(result.outputs.contains_key(*target), "{} 别名指向不存在的输出", builtin.name);
+            }
+        }
+    }
+
+    #[test]
+    fn test_user_indicator_requires_float_shares() {
+        // 通过用户指标间接调用 WINNER 同样需要流通股本；循环引用不会死循环
+        let user: HashMap<String, UserIndicator> = [
+            ("PROFIT", "W : WINNER(C);"),
+            ("WRAP", "X : profit.W * 100;"),
+            ("LOOP", "Y : LOOP.Y;"),
+        ]
+        .iter()
+        .map(|(k, v)| {
+            let indicator = UserIndicator {
+                source: v.to_string(),
+                ..Default::default()
+            };
+            (k.to_string(), indicator)
+        })
+        .collect();
+        assert!(requires_float_shares("Z : WRAP.X > 50;", &user));
+        assert!(!requires_float_shares("Z : LOOP.Y + MA(C, 5);", &user));
+        assert!(!requires_float_shares("Z : WRAP.X;", &Default::default()));
+    }
+
+    #[test]
+    fn test_validate_user_indicator_reference() {
+        let user = HashMap::from([(
+            "MYIND".to_string(),
+            UserIndicator {
+                source: "A : REF(C, 30);".to_string(),
+                ..Default::default()
+            },
+        )]);
+        let result = validate_formula("X : MA(MYIND.A, 10);", &user);
+        assert!(result.valid);
+        assert_eq!(result.required_bars, Some(40));
+
+        let result = validate_formula("X : MYIND.B;", &user);
+        assert!(!result.valid);
+        assert!(result.errors[0].contains("没有输出 B"));
+
+        // 未保存的指标只给出警告，回溯需求按全部历史处理
+        let result = validate_formula("X : OTHER.A;", &user);
+        assert!(result.valid && result.lookback.unbounded);
+    }
+}
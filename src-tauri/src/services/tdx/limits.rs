@@ -0,0 +1,292 @@
+//! 公式资源限制
+//!
+//! 公式可能直接来自 LLM 输出，解析和计算前需要限制规模：
+//! 嵌套过深会让递归下降解析器栈溢出，过深的语法树会让递归求值、遍历和释放栈溢出，
+//! 超大周期会让数据需求和计算量失控。超限时返回 LimitError 而不是 panic。
+
+use super::parser::{Expr, Statement};
+use super::tokenizer::{Token, TokenWithPos};
+use serde::Serialize;
+use std::fmt;
+
+/// 公式源码最大字符数
+pub const MAX_SOURCE_CHARS: usize = 20_000;
+/// 最大语句数
+pub const MAX_STATEMENTS: usize = 200;
+/// 最大嵌套深度（括号层数 + 连续一元运算符）
+pub const MAX_DEPTH: usize = 64;
+/// 语法树最大层数：C+C+…+C 这样的二元运算链不增加括号层数，但每个运算符都多一层语法树
+pub const MAX_EXPR_DEPTH: usize = 256;
+/// 周期参数上限（约 20 年日线）
+pub const MAX_PERIOD: f64 = 5000.0;
+/// 单次求值（含引用的指标）最长耗时
+pub const MAX_EVAL_MS: u64 = 2000;
+/// 单次求值中间序列的内存上限
+pub const MAX_MEMORY_BYTES: usize = 64 * 1024 * 1024;
+
+/// 周期类函数及其周期参数的位置
+pub const PERIOD_ARGS: &[(&str, usize)] = &[
+    ("MA", 1),
+    ("EMA", 1),
+    ("SMA", 1),
+    ("REF", 1),
+    ("LLV", 1),
+    ("HHV", 1),
+    ("SUM", 1),
+    ("COUNT", 1),
+    ("EVERY", 1),
+    ("EXIST", 1),
+    ("AVEDEV", 1),
+    ("STD", 1),
+    ("SLOPE", 1),
+    ("LWINNER", 0),
+    ("PWINNER", 0),
+    ("BETA", 1),
+    ("CORR", 1),
+    ("RS", 1),
+    ("RELATE", 2),
+];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum LimitKind {
+    SourceLength,
+    Statements,
+    Depth,
+    Period,
+    Time,
+    Memory,
+}
+
+impl LimitKind {
+    fn label(self) -> &'static str {
+        match self {
+            LimitKind::SourceLength => "公式长度（字符）",
+            LimitKind::Statements => "语句数",
+            LimitKind::Depth => "嵌套深度",
+            LimitKind::Period => "周期参数",
+            LimitKind::Time => "计算耗时（毫秒）",
+            LimitKind::Memory => "内存占用（字节）",
+        }
+    }
+}
+
+/// 超出资源限制
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct LimitError {
+    pub kind: LimitKind,
+    pub limit: f64,
+    pub actual: f64,
+    /// 超限位置，如函数名
+    pub context: Option<String>,
+}
+
+impl LimitError {
+    pub fn new(kind: LimitKind, limit: f64, actual: f64) -> Self {
+        Self {
+            kind,
+            limit,
+            actual,
+            context: None,
+        }
+    }
+
+    pub fn with_context(mut self, context: impl Into<String>) -> Self {
+        self.context = Some(context.into());
+        self
+    }
+}
+
+impl fmt::Display for LimitError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "公式超出资源限制：")?;
+        if let Some(context) = &self.context {
+            write!(f, "{} 的", context)?;
+        }
+        write!(
+            f,
+            "{} {} 超过上限 {}",
+            self.kind.label(),
+            self.actual,
+            self.limit
+        )
+    }
+}
+
+/// 检查源码长度（词法分析之前）
+pub fn check_source(source: &str) -> Result<(), LimitError> {
+    let chars = source.chars().count();
+    if chars > MAX_SOURCE_CHARS {
+        return Err(LimitError::new(
+            LimitKind::SourceLength,
+            MAX_SOURCE_CHARS as f64,
+            chars as f64,
+        ));
+    }
+    Ok(())
+}
+
+/// 检查语句数和嵌套深度（语法分析之前，避免解析器递归过深）
+pub fn check_tokens(tokens: &[TokenWithPos]) -> Result<(), LimitError> {
+    let statements = tokens.iter().filter(|t| t.token == Token::Semicolon).count();
+    if statements > MAX_STATEMENTS {
+        return Err(LimitError::new(
+            LimitKind::Statements,
+            MAX_STATEMENTS as f64,
+            statements as f64,
+        ));
+    }
+
+    let mut parens = 0usize;
+    let mut unary_run = 0usize;
+    let mut prev: Option<&Token> = None;
+    for t in tokens {
+        match &t.token {
+            Token::LParen => parens += 1,
+            Token::RParen => {
+                parens = parens.saturating_sub(1);
+                unary_run = 0;
+            }
+            Token::Not => unary_run += 1,
+            // 前面不是操作数时 '-' 为一元负号
+            Token::Minus
+                if !matches!(
+                    prev,
+                    Some(Token::Number(_) | Token::Ident(_) | Token::Str(_) | Token::RParen)
+                ) =>
+            {
+                unary_run += 1
+            }
+            Token::Number(_) | Token::Ident(_) | Token::Str(_) => unary_run = 0,
+            Token::Semicolon => {
+                parens = 0;
+                unary_run = 0;
+            }
+            _ => {}
+        }
+        let depth = parens + unary_run;
+        if depth > MAX_DEPTH {
+            return Err(LimitError::new(LimitKind::Depth, MAX_DEPTH as f64, depth as f64)
+                .with_context(format!("第 {} 行第 {} 列", t.line, t.col)));
+        }
+        prev = Some(&t.token);
+    }
+    Ok(())
+}
+
+/// 表达式树的层数；用显式栈遍历，本身不会因树过深而栈溢出
+pub fn expr_depth(expr: &Expr) -> usize {
+    let mut max = 0;
+    let mut stack = vec![(expr, 1)];
+    while let Some((e, depth)) = stack.pop() {
+        max = max.max(depth);
+        match e {
+            Expr::BinaryOp { left, right, .. } => {
+                stack.push((left, depth + 1));
+                stack.push((right, depth + 1));
+            }
+            Expr::UnaryOp { operand, .. } => stack.push((operand, depth + 1)),
+            Expr::FuncCall { args, .. } | Expr::IndicatorRef { args, .. } => {
+                stack.extend(args.iter().map(|a| (a, depth + 1)));
+            }
+            Expr::Number(_) | Expr::Str(_) | Expr::Variable(_) => {}
+        }
+    }
+    max
+}
+
+/// 检查语法树层数和常量周期参数（语法分析之后；变量周期在求值时检查）。
+/// 层数先检查，之后的递归遍历才是安全的
+pub fn check_statements(stmts: &[Statement]) -> Result<(), LimitError> {
+    for (i, stmt) in stmts.iter().enumerate() {
+        let depth = stmt.exprs().into_iter().map(expr_depth).max().unwrap_or(0);
+        if depth > MAX_EXPR_DEPTH {
+            return Err(LimitError::new(LimitKind::Depth, MAX_EXPR_DEPTH as f64, depth as f64)
+                .with_context(format!("第 {} 条语句", i + 1)));
+        }
+    }
+
+    let mut result = Ok(());
+    for expr in stmts.iter().flat_map(|s| s.exprs()) {
+        expr.visit(&mut |e| {
+            if result.is_err() {
+                return;
+            }
+            if let Expr::FuncCall { name, args } = e {
+                if let Some(Expr::Number(n)) = period_arg(name).and_then(|i| args.get(i)) {
+                    result = check_period(name, *n);
+                }
+            }
+        });
+    }
+    result
+}
+
+/// 函数的周期参数位置
+pub fn period_arg(name: &str) -> Option<usize> {
+    let upper = name.to_uppercase();
+    PERIOD_ARGS
+        .iter()
+        .find(|(f, _)| *f == upper)
+        .map(|(_, idx)| *idx)
+}
+
+pub fn check_period(func: &str, period: f64) -> Result<(), LimitError> {
+    if period > MAX_PERIOD {
+        return Err(LimitError::new(LimitKind::Period, MAX_PERIOD, period)
+            .with_context(func.to_uppercase()));
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::services::tdx::tokenizer::Tokenizer;
+
+    fn tokens(source: &str) -> Vec<TokenWithPos> {
+        Tokenizer::new(source).tokenize().unwrap()
+    }
+
+    #[test]
+    fn test_depth_limit() {
+        let ok = format!("X : {}C{};", "(".repeat(MAX_DEPTH), ")".repeat(MAX_DEPTH));
+        assert!(check_tokens(&tokens(&ok)).is_ok());
+
+        let deep = format!("X : {}C{};", "(".repeat(5000), ")".repeat(5000));
+        let err = check_tokens(&tokens(&deep)).unwrap_err();
+        assert_eq!(err.kind, LimitKind::Depth);
+
+        let unary = format!("X : {}C;", "- ".repeat(MAX_DEPTH + 1));
+        assert_eq!(check_tokens(&tokens(&unary)).unwrap_err().kind, LimitKind::Depth);
+    }
+
+    #[test]
+    fn test_binary_chain_depth() {
+        use crate::services::tdx::parser::Parser;
+        let parse = |source: &str| Parser::new(tokens(source)).parse().unwrap();
+
+        // 二元运算链没有括号，词法检查放行，按语法树层数拦截
+        let short = format!("X : C{};", " - C".repeat(MAX_DEPTH + 1));
+        assert!(check_statements(&parse(&short)).is_ok());
+
+        // 源码长度上限内最长的链：约 1 万层
+        let chain = format!("X : C{};", "+C".repeat(MAX_SOURCE_CHARS / 2 - 5));
+        assert!(check_source(&chain).is_ok());
+        let stmts = parse(&chain);
+        assert_eq!(expr_depth(stmts[0].exprs()[0]), MAX_SOURCE_CHARS / 2 - 4);
+        let err = check_statements(&stmts).unwrap_err();
+        assert_eq!(err.kind, LimitKind::Depth);
+        assert!(crate::services::tdx::parse_formula(&chain).is_err());
+    }
+
+    #[test]
+    fn test_statement_and_period_limits() {
+        let many = "X := C;".repeat(MAX_STATEMENTS + 1);
+        assert_eq!(check_tokens(&tokens(&many)).unwrap_err().kind, LimitKind::Statements);
+
+        let stmts = crate::services::tdx::parse_formula("X : MA(C, 1e9);").unwrap_err();
+        assert!(stmts.contains("MA"));
+        assert!(check_period("ma", MAX_PERIOD).is_ok());
+    }
+}
@@ -0,0 +1,302 @@
+//! 数据需求分析：由 AST 推算公式需要的历史 K 线数量
+//!
+//! window 为各级窗口函数回溯的 K 线数之和，如 `MA(EMA(C,60),120)` 约为 60 + 120；
+//! warmup 为 EMA / SMA 等递推平滑需要额外预热的 K 线数（初始值的影响衰减到约 2%）。
+
+use super::evaluator::UserIndicator;
+use super::library;
+use super::parser::{BinOp, Expr, Statement, UnOp};
+use serde::Serialize;
+use std::collections::HashMap;
+
+/// 无法确定回溯长度时获取的 K 线数量
+pub const DEFAULT_BARS: usize = 300;
+/// 单次获取 K 线的上限
+pub const MAX_BARS: usize = 5000;
+/// 筹码分布需要的历史长度（约一年，让旧筹码充分换手）
+const CHIP_WARMUP: usize = 250;
+
+/// 公式的回溯需求
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize)]
+pub struct Lookback {
+    pub window: usize,
+    pub warmup: usize,
+    /// 结果依赖全部历史（BARSLAST、SUM(X,0) 等）或周期无法静态确定
+    pub unbounded: bool,
+}
+
+impl Lookback {
+    const UNBOUNDED: Lookback = Lookback {
+        window: 0,
+        warmup: 0,
+        unbounded: true,
+    };
+
+    fn max(self, other: Lookback) -> Lookback {
+        Lookback {
+            window: self.window.max(other.window),
+            warmup: self.warmup.max(other.warmup),
+            unbounded: self.unbounded || other.unbounded,
+        }
+    }
+
+    /// 在输入序列之上再回溯 window 根、预热 warmup 根
+    fn extend(self, window: usize, warmup: usize) -> Lookback {
+        Lookback {
+            window: self.window + window,
+            warmup: self.warmup + warmup,
+            unbounded: self.unbounded,
+        }
+    }
+
+    /// 得到可靠的最后一根 K 线值所需的 K 线数量，依赖全部历史时为 None
+    pub fn required_bars(&self) -> Option<usize> {
+        if self.unbounded {
+            None
+        } else {
+            Some(self.window + self.warmup + 1)
+        }
+    }
+
+    /// 实际获取的 K 线数量
+    pub fn bars_to_fetch(&self) -> usize {
+        self.required_bars()
+            .map(|n| n.min(MAX_BARS))
+            .unwrap_or(DEFAULT_BARS)
+    }
+}
+
+/// 分析公式的回溯需求：取所有输出和 DRAWTEXT 的最大值
+pub fn analyze(stmts: &[Statement], user_indicators: &HashMap<String, UserIndicator>) -> Lookback {
+    Analyzer::new(user_indicators, Vec::new()).run(stmts, None)
+}
+
+struct Analyzer<'a> {
+    /// 变量的回溯需求及常量值（用于解析周期参数）
+    vars: HashMap<String, (Lookback, Option<f64>)>,
+    user_indicators: &'a HashMap<String, UserIndicator>,
+    ref_stack: Vec<String>,
+}
+
+impl<'a> Analyzer<'a> {
+    fn new(user_indicators: &'a HashMap<String, UserIndicator>, ref_stack: Vec<String>) -> Self {
+        Self {
+            vars: HashMap::new(),
+            user_indicators,
+            ref_stack,
+        }
+    }
+
+    /// output 为 Some 时只返回该输出变量的需求
+    fn run(&mut self, stmts: &[Statement], output: Option<&str>) -> Lookback {
+        let mut result = Lookback::default();
+        for stmt in stmts {
+            match stmt {
+                Statement::Assign { name, expr } | Statement::Output { name, expr } => {
+                    let lookback = self.expr(expr);
+                    let value = self.const_value(expr);
+                    self.vars.insert(name.to_uppercase(), (lookback, value));
+                    let selected = match output {
+                        Some(target) => name.eq_ignore_ascii_case(target),
+                        None => matches!(stmt, Statement::Output { .. }),
+                    };
+                    if selected {
+                        result = result.max(lookback);
+                    }
+                }
+                Statement::DrawText {
+                    condition,
+                    price_expr,
+                    ..
+                } => {
+                    if output.is_none() {
+                        result = result.max(self.expr(condition)).max(self.expr(price_expr));
+                    }
+                }
+            }
+        }
+        result
+    }
+
+    fn expr(&self, expr: &Expr) -> Lookback {
+        match expr {
+            Expr::Number(_) | Expr::Str(_) => Lookback::default(),
+            Expr::Variable(name) => self
+                .vars
+                .get(&name.to_uppercase())
+                .map(|(lookback, _)| *lookback)
+                // 内置变量或省略括号的形态函数
+                .unwrap_or_else(|| self.func(name, &[])),
+            Expr::BinaryOp { left, right, .. } => self.expr(left).max(self.expr(right)),
+            Expr::UnaryOp { operand, .. } => self.expr(operand),
+            Expr::FuncCall { name, args } => self.func(name, args),
+            Expr::IndicatorRef {
+                indicator,
+                output,
+                args,
+            } => self.indicator_ref(indicator, output, args),
+        }
+    }
+
+    fn func(&self, name: &str, args: &[Expr]) -> Lookback {
+        let upper = name.to_uppercase();
+        let inputs = args
+            .iter()
+            .map(|a| self.expr(a))
+            .fold(Lookback::default(), Lookback::max);
+        let first = args.first().map(|a| self.expr(a)).unwrap_or_default();
+        let period = |idx: usize| args.get(idx).and_then(|a| self.const_value(a));
+
+        match upper.as_str() {
+            "MA" | "LLV" | "HHV" | "COUNT" | "EVERY" | "EXIST" | "AVEDEV" | "STD" | "SLOPE" => {
+                match period(1) {
+                    Some(n) => first.extend((n as usize).saturating_sub(1), 0),
+                    None => Lookback::UNBOUNDED,
+                }
+            }
+            "REF" => match period(1) {
+                Some(n) => first.extend(n as usize, 0),
+                None => Lookback::UNBOUNDED,
+            },
+            "SUM" => match period(1) {
+                Some(n) if n >= 1.0 => first.extend(n as usize - 1, 0),
+                _ => Lookback::UNBOUNDED,
+            },
+            // 递推平滑：α = 2/(N+1)，约 4/α 根后初始值影响衰减到 2%
+            "EMA" => match period(1) {
+                Some(n) => first.extend((n as usize).saturating_sub(1), 2 * (n as usize + 1)),
+                None => Lookback::UNBOUNDED,
+            },
+            // SMA(X, N, M)：α = M/N
+            "SMA" => match (period(1), period(2)) {
+                (Some(n), Some(m)) if m > 0.0 => {
+                    first.extend((n as usize).saturating_sub(1), (4.0 * n / m).ceil() as usize)
+                }
+                _ => Lookback::UNBOUNDED,
+            },
+            "CROSS" => inputs.extend(1, 0),
+            // 日收益需要多一根 K 线
+            "BETA" | "CORR" | "RS" => match period(1) {
+                Some(n) => inputs.extend(n as usize, 0),
+                None => Lookback::UNBOUNDED,
+            },
+            "RELATE" => match period(2) {
+                Some(n) => inputs.extend((n as usize).saturating_sub(1), 0),
+                None => Lookback::UNBOUNDED,
+            },
+            "BARSLAST" => Lookback::UNBOUNDED,
+            "COST" | "WINNER" => inputs.extend(0, CHIP_WARMUP),
+            "LWINNER" | "PWINNER" => match period(0) {
+                Some(n) => inputs.extend(n as usize, CHIP_WARMUP),
+                None => Lookback::UNBOUNDED,
+            },
+            "ENGULFING" | "吞没" | "GAP" | "跳空缺口" => inputs.extend(1, 0),
+            "MORNINGSTAR" | "早晨之星" | "THREECROWS" | "三只乌鸦" => inputs.extend(2, 0),
+            _ => inputs,
+        }
+    }
+
+    fn indicator_ref(&self, indicator: &str, output: &str, args: &[Expr]) -> Lookback {
+        let upper = indicator.to_uppercase();
+        if self.ref_stack.contains(&upper) {
+            return Lookback::UNBOUNDED;
+        }
+        let mut stack = self.ref_stack.clone();
+        stack.push(upper.clone());
+        let mut sub = Analyzer::new(self.user_indicators, stack);
+
+        let (source, target) = if let Some(builtin) = library::find(&upper) {
+            for (i, (param, default)) in builtin.params.iter().enumerate() {
+                let value = match args.get(i) {
+                    Some(arg) => match self.const_value(arg) {
+                        Some(v) => v,
+                        None => return Lookback::UNBOUNDED,
+                    },
+                    None => *default,
+                };
+                sub.vars
+                    .insert(param.to_string(), (Lookback::default(), Some(value)));
+            }
+            (builtin.source.to_string(), builtin.resolve_output(output))
+        } else if let Some(user) = self.user_indicators.get(&upper) {
+            (user.source.clone(), output.to_uppercase())
+        } else {
+            return Lookback::UNBOUNDED;
+        };
+
+        match super::parse_formula(&source) {
+            Ok(stmts) => sub.run(&stmts, Some(&target)),
+            Err(_) => Lookback::UNBOUNDED,
+        }
+    }
+
+    /// 表达式的常量值（数字、常量变量及其四则运算）
+    fn const_value(&self, expr: &Expr) -> Option<f64> {
+        match expr {
+            Expr::Number(n) => Some(*n),
+            Expr::Variable(name) => self.vars.get(&name.to_uppercase()).and_then(|(_, v)| *v),
+            Expr::UnaryOp {
+                op: UnOp::Neg,
+                operand,
+            } => self.const_value(operand).map(|v| -v),
+            Expr::BinaryOp { op, left, right } => {
+                let (l, r) = (self.const_value(left)?, self.const_value(right)?);
+                match op {
+                    BinOp::Add => Some(l + r),
+                    BinOp::Sub => Some(l - r),
+                    BinOp::Mul => Some(l * r),
+                    BinOp::Div if r != 0.0 => Some(l / r),
+                    _ => None,
+                }
+            }
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::services::tdx::parse_formula;
+
+    fn lookback(source: &str) -> Lookback {
+        analyze(&parse_formula(source).unwrap(), &HashMap::new())
+    }
+
+    #[test]
+    fn test_nested_windows() {
+        let lb = lookback("X : MA(EMA(C, 60), 120);");
+        assert_eq!(lb.window, 59 + 119);
+        assert_eq!(lb.warmup, 122);
+        assert_eq!(lb.required_bars(), Some(59 + 119 + 122 + 1));
+
+        assert_eq!(lookback("X : MA(C, 5);").required_bars(), Some(5));
+        assert_eq!(lookback("DRAWTEXT(C > REF(C, 1), C, 'up');").required_bars(), Some(2));
+        assert_eq!(
+            lookback("N := 10; M := MA(C, N * 2); DRAWTEXT(CROSS(C, M), C, 'x');").window,
+            20
+        );
+    }
+
+    #[test]
+    fn test_unbounded_and_indicator_refs() {
+        assert!(lookback("X : BARSLAST(C > 10);").unbounded);
+        assert!(lookback("X : SUM(V, 0);").unbounded);
+        assert_eq!(lookback("X : USERIND.A;").bars_to_fetch(), DEFAULT_BARS);
+
+        // BOLL.UPPER = MA(C, 20) + 2 * STD(C, 20)
+        assert_eq!(lookback("X : BOLL.UPPER;").required_bars(), Some(20));
+        assert_eq!(lookback("X : BOLL.UPPER(60);").required_bars(), Some(60));
+
+        let mut user = HashMap::new();
+        user.insert(
+            "MYIND".to_string(),
+            UserIndicator {
+                source: "A : REF(C, 30);".to_string(),
+                ..Default::default()
+            },
+        );
+        let stmts = parse_formula("X : MA(MYIND.A, 10);").unwrap();
+        assert_eq!(analyze(&stmts, &user).required_bars(), Some(40));
+    }
+}
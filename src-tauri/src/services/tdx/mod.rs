@@ -1,12 +1,32 @@
+pub mod chips;
+pub mod editor;
 pub mod evaluator;
+pub mod library;
+pub mod limits;
+pub mod lookback;
 pub mod parser;
+pub mod patterns;
+pub mod study;
 pub mod tokenizer;
+pub mod trace;
 
-use crate::services::kline::KlineBar;
-use evaluator::{EvalResult, Evaluator};
-use parser::{Parser, Statement};
+use crate::services::kline::{self, AdjustMode, KlineBar};
+use crate::services::{batch, market};
+use chips::ChipModel;
+use evaluator::{EvalOptions, EvalResult, Evaluator, UserIndicator};
+use limits::LimitError;
+use lookback::Lookback;
+use parser::{Expr, Parser, Statement};
 use serde::Serialize;
+use std::collections::HashMap;
+use std::fmt;
+use std::time::Duration;
 use tokenizer::Tokenizer;
+use trace::EvalTrace;
+
+/// 检查历史 K 线时同时进行的请求数
+const HISTORY_CONCURRENCY: usize = 8;
+const HISTORY_TIMEOUT: Duration = Duration::from_secs(10);
 
 /// 公式验证结果
 #[derive(Debug, Serialize)]
@@ -17,10 +37,44 @@ pub struct ValidationResult {
     pub output_vars: Vec<String>,
     pub assign_vars: Vec<String>,
     pub drawtext_count: usize,
+    /// 超出资源限制时的详细信息（同时记录在 errors 中）
+    pub limit: Option<LimitError>,
+    /// 公式的回溯需求
+    pub lookback: Lookback,
+    /// 得到可靠结果所需的 K 线数量，依赖全部历史时为 None
+    pub required_bars: Option<usize>,
+}
+
+/// 公式解析或计算失败；超出资源限制时 limit 为结构化的超限信息
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct FormulaError {
+    pub message: String,
+    pub limit: Option<LimitError>,
+}
+
+impl From<String> for FormulaError {
+    fn from(message: String) -> Self {
+        Self { message, limit: None }
+    }
+}
+
+impl From<LimitError> for FormulaError {
+    fn from(e: LimitError) -> Self {
+        Self {
+            message: e.to_string(),
+            limit: Some(e),
+        }
+    }
 }
 
-/// 验证 TDX 公式语法
-pub fn validate_formula(source: &str) -> ValidationResult {
+impl fmt::Display for FormulaError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.message)
+    }
+}
+
+/// 验证 TDX 公式语法；引用的用户指标在 user_indicators 中查找并计入回溯需求
+pub fn validate_formula(source: &str, user_indicators: &HashMap<String, UserIndicator>) -> ValidationResult {
     let mut result = ValidationResult {
         valid: false,
         errors: Vec::new(),
@@ -28,8 +82,17 @@ pub fn validate_formula(source: &str) -> ValidationResult {
         output_vars: Vec::new(),
         assign_vars: Vec::new(),
         drawtext_count: 0,
+        limit: None,
+        lookback: Lookback::default(),
+        required_bars: None,
     };
 
+    if let Err(e) = limits::check_source(source) {
+        result.errors.push(e.to_string());
+        result.limit = Some(e);
+        return result;
+    }
+
     // 词法分析
     let mut tokenizer = Tokenizer::new(source);
     let tokens = match tokenizer.tokenize() {
@@ -39,6 +102,11 @@ pub fn validate_formula(source: &str) -> ValidationResult {
             return result;
         }
     };
+    if let Err(e) = limits::check_tokens(&tokens) {
+        result.errors.push(e.to_string());
+        result.limit = Some(e);
+        return result;
+    }
 
     // 语法分析
     let mut parser = Parser::new(tokens);
@@ -49,6 +117,11 @@ pub fn validate_formula(source: &str) -> ValidationResult {
             return result;
         }
     };
+    if let Err(e) = limits::check_statements(&stmts) {
+        result.errors.push(e.to_string());
+        result.limit = Some(e);
+        return result;
+    }
 
     // 提取信息
     for stmt in &stmts {
@@ -65,6 +138,62 @@ pub fn validate_formula(source: &str) -> ValidationResult {
         }
     }
 
+    // 检查指标引用：内置指标和已保存的用户指标校验输出名
+    let mut refs: Vec<(String, String)> = Vec::new();
+    for expr in stmts.iter().flat_map(|s| s.exprs()) {
+        expr.visit(&mut |e| {
+            if let Expr::IndicatorRef { indicator, output, .. } = e {
+                refs.push((indicator.clone(), output.clone()));
+            }
+        });
+    }
+    for (indicator, output) in refs {
+        match library::find(&indicator) {
+            Some(builtin) => {
+                let target = builtin.resolve_output(&output);
+                let outputs = formula_outputs(builtin.source);
+                if !outputs.contains(&target) {
+                    result.errors.push(format!(
+                        "内置指标 {} 没有输出 {}，可用输出: {}",
+                        builtin.name,
+                        output,
+                        outputs.join(", ")
+                    ));
+                }
+            }
+            None => match user_indicators.get(&indicator.to_uppercase()) {
+                Some(user) => {
+                    let outputs = formula_outputs(&user.source);
+                    if !outputs.contains(&output.to_uppercase()) {
+                        result.errors.push(format!(
+                            "用户指标 {} 没有输出 {}，可用输出: {}",
+                            indicator,
+                            output,
+                            outputs.join(", ")
+                        ));
+                    }
+                }
+                None => result.warnings.push(format!(
+                    "{}.{} 不是内置指标或已保存的用户指标，计算时找不到将报错",
+                    indicator, output
+                )),
+            },
+        }
+    }
+    if !result.errors.is_empty() {
+        return result;
+    }
+
+    // 回溯需求（未找到的用户指标按依赖全部历史处理）
+    result.lookback = lookback::analyze(&stmts, user_indicators);
+    result.required_bars = result.lookback.required_bars();
+    if result.lookback.unbounded {
+        result.warnings.push(format!(
+            "无法确定公式所需的 K 线数量（使用了 BARSLAST、SUM(X,0)、非常量周期或引用了用户指标），将按默认 {} 根计算",
+            lookback::DEFAULT_BARS
+        ));
+    }
+
     if result.drawtext_count == 0 {
         result
             .warnings
@@ -75,17 +204,228 @@ pub fn validate_formula(source: &str) -> ValidationResult {
     result
 }
 
-/// 解析 TDX 公式为 AST
+/// 指标公式的输出变量名（大写）
+fn formula_outputs(source: &str) -> Vec<String> {
+    parse_formula(source)
+        .map(|stmts| {
+            stmts
+                .iter()
+                .filter_map(|s| match s {
+                    Statement::Output { name, .. } => Some(name.to_uppercase()),
+                    _ => None,
+                })
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+/// 解析 TDX 公式为 AST（含资源限制检查）
 pub fn parse_formula(source: &str) -> Result<Vec<Statement>, String> {
+    parse_checked(source).map_err(|e| e.message)
+}
+
+/// 解析 TDX 公式，超出资源限制时保留结构化的超限信息
+pub fn parse_checked(source: &str) -> Result<Vec<Statement>, FormulaError> {
+    limits::check_source(source)?;
     let mut tokenizer = Tokenizer::new(source);
     let tokens = tokenizer.tokenize()?;
+    limits::check_tokens(&tokens)?;
     let mut parser = Parser::new(tokens);
-    parser.parse()
+    let stmts = parser.parse()?;
+    limits::check_statements(&stmts)?;
+    Ok(stmts)
+}
+
+/// 公式及其引用的用户指标（含间接引用）的语句，用于扫描整条引用链
+fn statements_with_references(source: &str, user_indicators: &HashMap<String, UserIndicator>) -> Vec<Vec<Statement>> {
+    let mut all = Vec::new();
+    let mut seen: Vec<String> = Vec::new();
+    let mut pending = vec![source.to_string()];
+    while let Some(source) = pending.pop() {
+        let stmts = match parse_formula(&source) {
+            Ok(s) => s,
+            Err(_) => continue,
+        };
+        for name in user_indicator_refs(&stmts) {
+            if let Some(referenced) = user_indicators.get(&name) {
+                if !seen.contains(&name) {
+                    seen.push(name);
+                    pending.push(referenced.source.clone());
+                }
+            }
+        }
+        all.push(stmts);
+    }
+    all
+}
+
+/// 公式（含引用的用户指标）是否调用了筹码分布函数（需要额外获取流通股本）
+pub fn requires_float_shares(source: &str, user_indicators: &HashMap<String, UserIndicator>) -> bool {
+    let mut found = false;
+    for stmts in statements_with_references(source, user_indicators) {
+        for expr in stmts.iter().flat_map(|s| s.exprs()) {
+            expr.visit(&mut |e| {
+                if let Expr::FuncCall { name, .. } = e {
+                    found |= chips::FUNCTIONS.contains(&name.to_uppercase().as_str());
+                }
+            });
+        }
+    }
+    found
+}
+
+/// 公式（含引用的用户指标）中 BETA / CORR / RS 引用的基准代码
+pub fn referenced_symbols(source: &str, user_indicators: &HashMap<String, UserIndicator>) -> Vec<String> {
+    let mut codes = Vec::new();
+    for stmts in statements_with_references(source, user_indicators) {
+        for expr in stmts.iter().flat_map(|s| s.exprs()) {
+            expr.visit(&mut |e| {
+                if let Expr::FuncCall { name, args } = e {
+                    if evaluator::SYMBOL_FUNCTIONS.contains(&name.to_uppercase().as_str()) {
+                        if let Some(Expr::Str(code)) = args.first() {
+                            if !codes.contains(code) {
+                                codes.push(code.clone());
+                            }
+                        }
+                    }
+                }
+            });
+        }
+    }
+    codes
+}
+
+/// 公式中引用的非内置指标名（大写），需要从用户指标表加载
+pub fn referenced_user_indicators(source: &str) -> Vec<String> {
+    parse_formula(source)
+        .map(|stmts| user_indicator_refs(&stmts))
+        .unwrap_or_default()
+}
+
+fn user_indicator_refs(stmts: &[Statement]) -> Vec<String> {
+    let mut names = Vec::new();
+    for expr in stmts.iter().flat_map(|s| s.exprs()) {
+        expr.visit(&mut |e| {
+            if let Expr::IndicatorRef { indicator, .. } = e {
+                let upper = indicator.to_uppercase();
+                if library::find(&upper).is_none() && !names.contains(&upper) {
+                    names.push(upper);
+                }
+            }
+        });
+    }
+    names
+}
+
+/// 按公式回溯需求计算应获取的 K 线数量
+pub fn bars_to_fetch(source: &str, user_indicators: &HashMap<String, UserIndicator>) -> usize {
+    match parse_formula(source) {
+        Ok(stmts) => lookback::analyze(&stmts, user_indicators).bars_to_fetch(),
+        Err(_) => lookback::DEFAULT_BARS,
+    }
+}
+
+/// 检查各股票的历史 K 线是否满足公式需求，上市时间过短时返回警告
+pub async fn history_warnings(
+    symbols: &[String],
+    required_bars: usize,
+    adjust_mode: AdjustMode,
+) -> Vec<String> {
+    let fetches = symbols
+        .iter()
+        .cloned()
+        .map(|symbol| async move { kline::fetch_daily_klines(&symbol, required_bars, adjust_mode).await })
+        .collect();
+    let results = batch::run_bounded(fetches, HISTORY_CONCURRENCY, HISTORY_TIMEOUT).await;
+
+    let mut warnings = Vec::new();
+    for (symbol, result) in symbols.iter().zip(results) {
+        match result {
+            Ok(bars) if bars.len() < required_bars => warnings.push(format!(
+                "{} 仅有 {} 根日 K 线，少于公式所需的 {} 根，计算结果可能不可靠",
+                symbol,
+                bars.len(),
+                required_bars
+            )),
+            Ok(_) => {}
+            Err(e) => warnings.push(format!("获取 {} 的 K 线失败: {}", symbol, e)),
+        }
+    }
+    warnings
+}
+
+/// 为指定股票准备求值选项：公式用到筹码函数时从行情服务获取流通股本，
+/// 用到 BETA 等相对基准函数时获取基准自 bars 第一根起的 K 线。
+/// 引用的用户指标中用到的函数同样计入，user_indicators 原样放入求值选项
+pub async fn prepare_options(
+    symbol: &str,
+    source: &str,
+    bars: &[KlineBar],
+    adjust_mode: AdjustMode,
+    chip_model: Option<ChipModel>,
+    user_indicators: HashMap<String, UserIndicator>,
+) -> EvalOptions {
+    let float_shares = if requires_float_shares(source, &user_indicators) {
+        match market::fetch_stock_quote(symbol).await {
+            Ok(q) if q.float_shares > 0.0 => Some(q.float_shares),
+            Ok(_) => None,
+            Err(e) => {
+                eprintln!("获取 {} 流通股本失败: {}", symbol, e);
+                None
+            }
+        }
+    } else {
+        None
+    };
+
+    let mut reference_bars = HashMap::new();
+    if let Some(first) = bars.first() {
+        for code in referenced_symbols(source, &user_indicators) {
+            match kline::fetch_daily_klines_since(&code, &first.date, adjust_mode).await {
+                Ok(b) => {
+                    reference_bars.insert(code, b);
+                }
+                Err(e) => eprintln!("获取基准 {} K线失败: {}", code, e),
+            }
+        }
+    }
+
+    EvalOptions {
+        adjust_mode,
+        float_shares,
+        chip_model: chip_model.unwrap_or_default(),
+        user_indicators,
+        reference_bars,
+        ..Default::default()
+    }
 }
 
 /// 计算 TDX 公式（使用 K 线数据）
-pub fn evaluate_formula(source: &str, bars: &[KlineBar]) -> Result<EvalResult, String> {
-    let stmts = parse_formula(source)?;
-    let mut evaluator = Evaluator::new(bars.to_vec());
-    evaluator.evaluate(&stmts)
+pub fn evaluate_formula(
+    source: &str,
+    bars: &[KlineBar],
+    options: &EvalOptions,
+) -> Result<EvalResult, FormulaError> {
+    let stmts = parse_checked(source)?;
+    let mut evaluator = Evaluator::new(bars.to_vec(), options.clone());
+    evaluator.evaluate(&stmts).map_err(|message| FormulaError {
+        message,
+        limit: evaluator.take_limit(),
+    })
+}
+
+/// 追踪公式在某根 K 线上的求值过程，bar_index 为负数时从末尾倒数（-1 为最后一根）
+pub fn trace_formula(
+    source: &str,
+    bars: &[KlineBar],
+    options: &EvalOptions,
+    bar_index: i64,
+) -> Result<EvalTrace, FormulaError> {
+    let bar = trace::resolve_bar_index(bars.len(), bar_index)?;
+    let stmts = parse_checked(source)?;
+    let mut evaluator = Evaluator::new(bars.to_vec(), options.clone());
+    evaluator.trace(&stmts, bar).map_err(|message| FormulaError {
+        message,
+        limit: evaluator.take_limit(),
+    })
 }
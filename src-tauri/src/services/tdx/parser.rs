@@ -4,8 +4,10 @@
 /// - X := expr;  (中间变量赋值)
 /// - X : expr;   (输出变量)
 /// - DRAWTEXT(cond, price_expr, text);
+/// - NAME.OUTPUT / NAME.OUTPUT(args) 引用其他指标的输出
 
 use super::tokenizer::{Token, TokenWithPos};
+use std::fmt;
 
 #[derive(Debug, Clone)]
 pub enum Statement {
@@ -42,6 +44,46 @@ pub enum Expr {
         name: String,
         args: Vec<Expr>,
     },
+    /// 引用其他指标的输出，如 MACD.DIF；args 按顺序覆盖指标参数
+    IndicatorRef {
+        indicator: String,
+        output: String,
+        args: Vec<Expr>,
+    },
+}
+
+impl Statement {
+    /// 语句中包含的顶层表达式
+    pub fn exprs(&self) -> Vec<&Expr> {
+        match self {
+            Statement::Assign { expr, .. } | Statement::Output { expr, .. } => vec![expr],
+            Statement::DrawText {
+                condition,
+                price_expr,
+                ..
+            } => vec![condition, price_expr],
+        }
+    }
+}
+
+impl Expr {
+    /// 先序遍历表达式树
+    pub fn visit(&self, f: &mut dyn FnMut(&Expr)) {
+        f(self);
+        match self {
+            Expr::BinaryOp { left, right, .. } => {
+                left.visit(f);
+                right.visit(f);
+            }
+            Expr::UnaryOp { operand, .. } => operand.visit(f),
+            Expr::FuncCall { args, .. } | Expr::IndicatorRef { args, .. } => {
+                for arg in args {
+                    arg.visit(f);
+                }
+            }
+            Expr::Number(_) | Expr::Str(_) | Expr::Variable(_) => {}
+        }
+    }
 }
 
 #[derive(Debug, Clone, Copy, PartialEq)]
@@ -55,6 +97,7 @@ pub enum BinOp {
     Ge,
     Le,
     Eq,
+    Ne,
     And,
     Or,
 }
@@ -65,6 +108,116 @@ pub enum UnOp {
     Not,
 }
 
+impl UnOp {
+    pub fn symbol(self) -> &'static str {
+        match self {
+            UnOp::Neg => "-",
+            UnOp::Not => "NOT ",
+        }
+    }
+}
+
+impl BinOp {
+    pub fn symbol(self) -> &'static str {
+        match self {
+            BinOp::Add => "+",
+            BinOp::Sub => "-",
+            BinOp::Mul => "*",
+            BinOp::Div => "/",
+            BinOp::Gt => ">",
+            BinOp::Lt => "<",
+            BinOp::Ge => ">=",
+            BinOp::Le => "<=",
+            BinOp::Eq => "=",
+            BinOp::Ne => "<>",
+            BinOp::And => "AND",
+            BinOp::Or => "OR",
+        }
+    }
+
+    /// 运算符优先级，与解析顺序一致（数值越大结合越紧）
+    pub fn precedence(self) -> u8 {
+        match self {
+            BinOp::Or => 1,
+            BinOp::And => 2,
+            BinOp::Gt | BinOp::Lt | BinOp::Ge | BinOp::Le | BinOp::Eq | BinOp::Ne => 3,
+            BinOp::Add | BinOp::Sub => 4,
+            BinOp::Mul | BinOp::Div => 5,
+        }
+    }
+}
+
+impl Expr {
+    /// 表达式的结合优先级，非二元运算视为最高
+    pub fn precedence(&self) -> u8 {
+        match self {
+            Expr::BinaryOp { op, .. } => op.precedence(),
+            Expr::UnaryOp { .. } => 6,
+            _ => 7,
+        }
+    }
+
+    /// 作为 parent 的左/右操作数时是否需要加括号
+    pub fn needs_parens(&self, parent: BinOp, is_right: bool) -> bool {
+        let prec = self.precedence();
+        if is_right {
+            prec <= parent.precedence()
+        } else {
+            prec < parent.precedence()
+        }
+    }
+}
+
+/// 还原为公式源码（规范化空白和括号）
+impl fmt::Display for Expr {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Expr::Number(n) => write!(f, "{}", n),
+            Expr::Str(s) => write!(f, "'{}'", s),
+            Expr::Variable(name) => write!(f, "{}", name),
+            Expr::BinaryOp { op, left, right } => {
+                if left.needs_parens(*op, false) {
+                    write!(f, "({})", left)?;
+                } else {
+                    write!(f, "{}", left)?;
+                }
+                write!(f, " {} ", op.symbol())?;
+                if right.needs_parens(*op, true) {
+                    write!(f, "({})", right)
+                } else {
+                    write!(f, "{}", right)
+                }
+            }
+            Expr::UnaryOp { op, operand } => {
+                if operand.precedence() < 6 {
+                    write!(f, "{}({})", op.symbol(), operand)
+                } else {
+                    write!(f, "{}{}", op.symbol(), operand)
+                }
+            }
+            Expr::FuncCall { name, args } => write!(f, "{}({})", name, join_args(args)),
+            Expr::IndicatorRef {
+                indicator,
+                output,
+                args,
+            } => {
+                if args.is_empty() {
+                    write!(f, "{}.{}", indicator, output)
+                } else {
+                    write!(f, "{}.{}({})", indicator, output, join_args(args))
+                }
+            }
+        }
+    }
+}
+
+fn join_args(args: &[Expr]) -> String {
+    args.iter()
+        .map(|a| a.to_string())
+        .collect::<Vec<_>>()
+        .join(",")
+}
+
 pub struct Parser {
     tokens: Vec<TokenWithPos>,
     pos: usize,
@@ -214,6 +367,7 @@ impl Parser {
                 Token::Ge => BinOp::Ge,
                 Token::Le => BinOp::Le,
                 Token::Eq => BinOp::Eq,
+                Token::Ne => BinOp::Ne,
                 _ => break,
             };
             self.advance();
@@ -303,21 +457,23 @@ impl Parser {
                 let name = name.clone();
                 self.advance();
 
+                // 指标引用 NAME.OUTPUT，参数可选
+                if let Some((indicator, output)) = name.split_once('.') {
+                    let args = if self.check(&Token::LParen) {
+                        self.parse_args(&name)?
+                    } else {
+                        Vec::new()
+                    };
+                    return Ok(Expr::IndicatorRef {
+                        indicator: indicator.to_string(),
+                        output: output.to_string(),
+                        args,
+                    });
+                }
+
                 // 检查是否是函数调用
                 if self.check(&Token::LParen) {
-                    self.advance(); // (
-                    let mut args = Vec::new();
-
-                    if !self.check(&Token::RParen) {
-                        args.push(self.parse_expr()?);
-                        while self.check(&Token::Comma) {
-                            self.advance();
-                            args.push(self.parse_expr()?);
-                        }
-                    }
-
-                    self.expect(&Token::RParen, &format!("函数 {} 调用期望 ')'", name))?;
-
+                    let args = self.parse_args(&name)?;
                     Ok(Expr::FuncCall { name, args })
                 } else {
                     Ok(Expr::Variable(name))
@@ -336,6 +492,23 @@ impl Parser {
         }
     }
 
+    /// 解析括号内的参数列表（当前位于 '('）
+    fn parse_args(&mut self, name: &str) -> Result<Vec<Expr>, String> {
+        self.advance(); // (
+        let mut args = Vec::new();
+
+        if !self.check(&Token::RParen) {
+            args.push(self.parse_expr()?);
+            while self.check(&Token::Comma) {
+                self.advance();
+                args.push(self.parse_expr()?);
+            }
+        }
+
+        self.expect(&Token::RParen, &format!("函数 {} 调用期望 ')'", name))?;
+        Ok(args)
+    }
+
     // ── 辅助方法 ──
 
     fn peek(&self) -> &TokenWithPos {
@@ -414,6 +587,33 @@ mod tests {
         assert!(matches!(&stmts[0], Statement::Assign { .. }));
     }
 
+    #[test]
+    fn test_indicator_reference() {
+        let stmts = parse_source("X := MACD.DIF(5, 10, 3) > BOLL.UPPER;");
+        match &stmts[0] {
+            Statement::Assign { expr: Expr::BinaryOp { left, right, .. }, .. } => {
+                assert!(matches!(left.as_ref(), Expr::IndicatorRef { indicator, output, args }
+                    if indicator == "MACD" && output == "DIF" && args.len() == 3));
+                assert!(matches!(right.as_ref(), Expr::IndicatorRef { indicator, output, args }
+                    if indicator == "BOLL" && output == "UPPER" && args.is_empty()));
+            }
+            other => panic!("unexpected statement: {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_display_roundtrip() {
+        let stmts = parse_source("X := (C - REF(C,1)) / REF(C,1) > 0.05 AND NOT(A OR B) AND MACD.DIF(5,10,3) > -1;");
+        let expr = match &stmts[0] {
+            Statement::Assign { expr, .. } => expr,
+            _ => unreachable!(),
+        };
+        assert_eq!(
+            expr.to_string(),
+            "(C - REF(C,1)) / REF(C,1) > 0.05 AND NOT (A OR B) AND MACD.DIF(5,10,3) > -1"
+        );
+    }
+
     #[test]
     fn test_comparison_and_logic() {
         let stmts = parse_source("BUY := C > REF(C, 1) AND V > REF(V, 1);");
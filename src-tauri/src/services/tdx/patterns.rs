@@ -0,0 +1,193 @@
+//! K 线形态识别
+//!
+//! 每个函数返回与 K 线等长的布尔序列（1.0 / 0.0），形态在该根 K 线上完成时为 1。
+//! 容差参数均为比例（0.1 = 10%），由公式中的可选参数传入。
+
+use crate::services::kline::KlineBar;
+
+/// 十字星默认容差：实体不超过振幅的 10%
+pub const DOJI_TOLERANCE: f64 = 0.1;
+/// 锤子线默认：下影线至少为实体 2 倍，上影线不超过振幅 10%
+pub const HAMMER_RATIO: f64 = 2.0;
+pub const HAMMER_TOLERANCE: f64 = 0.1;
+/// 早晨之星默认：中间 K 线实体不超过第一根实体的 30%
+pub const MORNING_STAR_TOLERANCE: f64 = 0.3;
+/// 三只乌鸦默认：收盘价距最低价不超过振幅 30%
+pub const THREE_CROWS_TOLERANCE: f64 = 0.3;
+
+fn body(b: &KlineBar) -> f64 {
+    (b.close - b.open).abs()
+}
+
+fn range(b: &KlineBar) -> f64 {
+    b.high - b.low
+}
+
+fn is_bull(b: &KlineBar) -> bool {
+    b.close > b.open
+}
+
+fn is_bear(b: &KlineBar) -> bool {
+    b.close < b.open
+}
+
+fn to_f64(b: bool) -> f64 {
+    if b {
+        1.0
+    } else {
+        0.0
+    }
+}
+
+/// 对每根 K 线应用判定函数，前 lookback 根 K 线数据不足时为 0
+fn scan(bars: &[KlineBar], lookback: usize, f: impl Fn(usize) -> bool) -> Vec<f64> {
+    (0..bars.len())
+        .map(|i| if i < lookback { 0.0 } else { to_f64(f(i)) })
+        .collect()
+}
+
+/// 十字星：实体 <= tol × 振幅
+pub fn doji(bars: &[KlineBar], tol: f64) -> Vec<f64> {
+    scan(bars, 0, |i| {
+        let b = &bars[i];
+        range(b) > 0.0 && body(b) <= tol * range(b)
+    })
+}
+
+/// 锤子线：下影线 >= ratio × 实体，上影线 <= tol × 振幅，且下影线占振幅一半以上
+pub fn hammer(bars: &[KlineBar], ratio: f64, tol: f64) -> Vec<f64> {
+    scan(bars, 0, |i| {
+        let b = &bars[i];
+        let r = range(b);
+        let lower = b.open.min(b.close) - b.low;
+        let upper = b.high - b.open.max(b.close);
+        r > 0.0 && lower >= ratio * body(b) && upper <= tol * r && lower >= 0.5 * r
+    })
+}
+
+/// 吞没形态：direction > 0 为看涨吞没（阳包阴），< 0 为看跌吞没（阴包阳），0 为任一方向。
+/// tol 为实体边界比较时允许的价格误差比例
+pub fn engulfing(bars: &[KlineBar], direction: f64, tol: f64) -> Vec<f64> {
+    scan(bars, 1, |i| {
+        let (prev, cur) = (&bars[i - 1], &bars[i]);
+        let bullish = is_bear(prev)
+            && is_bull(cur)
+            && cur.open <= prev.close * (1.0 + tol)
+            && cur.close >= prev.open * (1.0 - tol);
+        let bearish = is_bull(prev)
+            && is_bear(cur)
+            && cur.open >= prev.close * (1.0 - tol)
+            && cur.close <= prev.open * (1.0 + tol);
+        if direction > 0.0 {
+            bullish
+        } else if direction < 0.0 {
+            bearish
+        } else {
+            bullish || bearish
+        }
+    })
+}
+
+/// 早晨之星：大阴线 → 实体 <= tol × 首根实体的星线（实体位于首根收盘价下方）→ 收盘越过首根实体中点的阳线
+pub fn morning_star(bars: &[KlineBar], tol: f64) -> Vec<f64> {
+    scan(bars, 2, |i| {
+        let (first, star, last) = (&bars[i - 2], &bars[i - 1], &bars[i]);
+        is_bear(first)
+            && body(star) <= tol * body(first)
+            && star.open.max(star.close) <= first.close
+            && is_bull(last)
+            && last.close > (first.open + first.close) / 2.0
+    })
+}
+
+/// 三只乌鸦：连续三根阴线，收盘逐级走低，开盘位于前一根实体内，收盘接近最低价（距最低价 <= tol × 振幅）
+pub fn three_crows(bars: &[KlineBar], tol: f64) -> Vec<f64> {
+    scan(bars, 2, |i| {
+        let crows = &bars[i - 2..=i];
+        let all_bear_near_low = crows
+            .iter()
+            .all(|b| is_bear(b) && b.close - b.low <= tol * range(b));
+        let stepping_down = crows.windows(2).all(|w| {
+            w[1].close < w[0].close && w[1].open <= w[0].open && w[1].open >= w[0].close
+        });
+        all_bear_near_low && stepping_down
+    })
+}
+
+/// 跳空缺口：direction >= 0 为向上缺口（最低价高于昨日最高价），< 0 为向下缺口。
+/// min_pct 为缺口的最小幅度（百分比）
+pub fn gap(bars: &[KlineBar], direction: f64, min_pct: f64) -> Vec<f64> {
+    let k = min_pct / 100.0;
+    scan(bars, 1, |i| {
+        let (prev, cur) = (&bars[i - 1], &bars[i]);
+        if direction >= 0.0 {
+            cur.low > prev.high * (1.0 + k)
+        } else {
+            cur.high < prev.low * (1.0 - k)
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn bar(open: f64, high: f64, low: f64, close: f64) -> KlineBar {
+        KlineBar {
+            date: "2025-01-01".to_string(),
+            open,
+            close,
+            high,
+            low,
+            volume: 10000.0,
+            amount: close * 10000.0,
+        }
+    }
+
+    #[test]
+    fn test_doji_and_hammer() {
+        let bars = vec![
+            bar(10.0, 10.5, 9.5, 10.02), // 十字星
+            bar(10.0, 10.3, 9.0, 10.25), // 锤子线
+            bar(10.0, 11.0, 9.9, 10.9),  // 普通阳线
+        ];
+        assert_eq!(doji(&bars, DOJI_TOLERANCE), vec![1.0, 0.0, 0.0]);
+        assert_eq!(hammer(&bars, HAMMER_RATIO, HAMMER_TOLERANCE), vec![0.0, 1.0, 0.0]);
+    }
+
+    #[test]
+    fn test_engulfing() {
+        let bars = vec![bar(10.5, 10.6, 9.9, 10.0), bar(9.9, 10.8, 9.8, 10.7)];
+        assert_eq!(engulfing(&bars, 1.0, 0.0), vec![0.0, 1.0]);
+        assert_eq!(engulfing(&bars, -1.0, 0.0), vec![0.0, 0.0]);
+        assert_eq!(engulfing(&bars, 0.0, 0.0), vec![0.0, 1.0]);
+    }
+
+    #[test]
+    fn test_morning_star() {
+        let bars = vec![
+            bar(11.0, 11.1, 9.9, 10.0),
+            bar(9.8, 9.9, 9.6, 9.75),
+            bar(9.9, 10.8, 9.85, 10.7),
+        ];
+        assert_eq!(morning_star(&bars, MORNING_STAR_TOLERANCE), vec![0.0, 0.0, 1.0]);
+    }
+
+    #[test]
+    fn test_three_crows() {
+        let bars = vec![
+            bar(12.0, 12.1, 11.4, 11.5),
+            bar(11.8, 11.9, 10.9, 11.0),
+            bar(11.2, 11.3, 10.4, 10.5),
+        ];
+        assert_eq!(three_crows(&bars, THREE_CROWS_TOLERANCE), vec![0.0, 0.0, 1.0]);
+    }
+
+    #[test]
+    fn test_gap() {
+        let bars = vec![bar(10.0, 10.2, 9.8, 10.1), bar(10.5, 10.8, 10.4, 10.7)];
+        assert_eq!(gap(&bars, 1.0, 0.0), vec![0.0, 1.0]);
+        assert_eq!(gap(&bars, 1.0, 5.0), vec![0.0, 0.0]);
+        assert_eq!(gap(&bars, -1.0, 0.0), vec![0.0, 0.0]);
+    }
+}
@@ -0,0 +1,345 @@
+//! 信号事件研究
+//!
+//! 在全部历史 K 线上找出 DRAWTEXT 条件成立的每一根 K 线（与实时提醒一致，连续成立的每根都计一次），
+//! 以该根收盘价为基准统计之后 N 个交易日的收益分布、胜率和最大不利波动，并与同期所有 K 线的无条件收益对比。
+//!
+//! 持有期按交易日历计算：停牌的日子同样计入持有期，第 N 个交易日停牌（无法卖出）的样本不计入统计。
+//! K 线按指标的停牌填充策略对齐后，填充的停牌日（EvalOptions::stale）既不能买入也不能卖出。
+//! 没有节假日数据的年份无法区分节假日和停牌，只能按 K 线日期计算。
+
+use super::evaluator::{EvalOptions, Evaluator};
+use super::FormulaError;
+use crate::services::calendar;
+use crate::services::kline::KlineBar;
+use chrono::{Datelike, NaiveDate};
+use serde::Serialize;
+use std::collections::HashMap;
+
+/// 默认统计的持有周期（交易日数）
+pub const HORIZONS: &[usize] = &[1, 3, 5, 10, 20];
+
+/// 返回的最近触发日期数量
+const RECENT_TRIGGERS: usize = 20;
+
+/// 某一持有周期的统计（收益均为百分比）
+#[derive(Debug, Clone, Serialize)]
+pub struct HorizonStats {
+    pub horizon: usize,
+    /// 有完整后续数据的触发次数
+    pub samples: usize,
+    pub mean: f64,
+    pub median: f64,
+    pub p25: f64,
+    pub p75: f64,
+    pub min: f64,
+    pub max: f64,
+    /// 收益 > 0 的比例（0~1）
+    pub hit_rate: f64,
+    /// 平均最大不利波动：持有期内最低价相对买入价的跌幅，<= 0
+    pub avg_mae: f64,
+    /// 同期任意 K 线买入的平均收益
+    pub baseline_mean: f64,
+    pub baseline_hit_rate: f64,
+    /// mean - baseline_mean
+    pub excess_mean: f64,
+}
+
+/// 单个 DRAWTEXT 信号的统计
+#[derive(Debug, Clone, Serialize)]
+pub struct SignalStudy {
+    pub text: String,
+    pub triggers: usize,
+    /// 最近的触发日期（由近及远）
+    pub recent_triggers: Vec<String>,
+    pub horizons: Vec<HorizonStats>,
+}
+
+/// 事件研究结果
+#[derive(Debug, Clone, Serialize)]
+pub struct EventStudy {
+    pub bars: usize,
+    pub start_date: String,
+    pub end_date: String,
+    pub signals: Vec<SignalStudy>,
+}
+
+/// 计算公式中每个 DRAWTEXT 信号的历史表现；超出资源限制时错误中带有超限信息
+pub fn event_study(
+    source: &str,
+    bars: &[KlineBar],
+    options: &EvalOptions,
+    horizons: &[usize],
+) -> Result<EventStudy, FormulaError> {
+    let stmts = super::parse_checked(source)?;
+    let mut evaluator = Evaluator::new(bars.to_vec(), options.clone());
+    let result = evaluator.evaluate(&stmts).map_err(|message| FormulaError {
+        message,
+        limit: evaluator.take_limit(),
+    })?;
+    if result.signals.is_empty() {
+        return Err("公式中没有 DRAWTEXT 信号，无法统计".to_string().into());
+    }
+
+    let days = trading_calendar(bars);
+    let signals = result
+        .signals
+        .iter()
+        .map(|signal| {
+            let triggers: Vec<usize> = signal
+                .history
+                .iter()
+                .enumerate()
+                .filter(|(_, v)| **v > 0.5)
+                .map(|(i, _)| i)
+                .collect();
+            SignalStudy {
+                text: signal.text.clone(),
+                triggers: triggers.len(),
+                recent_triggers: triggers
+                    .iter()
+                    .rev()
+                    .take(RECENT_TRIGGERS)
+                    .map(|&i| bars[i].date.clone())
+                    .collect(),
+                horizons: horizons
+                    .iter()
+                    .map(|&h| horizon_stats(bars, &options.stale, &triggers, h, &exit_indices(bars, &days, h)))
+                    .collect(),
+            }
+        })
+        .collect();
+
+    Ok(EventStudy {
+        bars: bars.len(),
+        start_date: bars.first().map(|b| b.date.clone()).unwrap_or_default(),
+        end_date: bars.last().map(|b| b.date.clone()).unwrap_or_default(),
+        signals,
+    })
+}
+
+/// bars 覆盖区间内的交易日：有节假日数据的年份取交易日历，其余年份取 K 线日期
+fn trading_calendar(bars: &[KlineBar]) -> Vec<NaiveDate> {
+    let dates: Vec<NaiveDate> = bars
+        .iter()
+        .filter_map(|b| NaiveDate::parse_from_str(&b.date, "%Y-%m-%d").ok())
+        .collect();
+    let (Some(&start), Some(&end)) = (dates.first(), dates.last()) else {
+        return Vec::new();
+    };
+    let covered = calendar::covered_years();
+    let mut days: Vec<NaiveDate> = calendar::trading_days(start, end)
+        .into_iter()
+        .filter(|d| covered.contains(&d.year()))
+        .chain(dates.into_iter().filter(|d| !covered.contains(&d.year())))
+        .collect();
+    days.sort();
+    days.dedup();
+    days
+}
+
+/// 每根 K 线持有 horizon 个交易日后卖出的 K 线下标；该日停牌或超出数据范围时为 None
+fn exit_indices(bars: &[KlineBar], days: &[NaiveDate], horizon: usize) -> Vec<Option<usize>> {
+    let position: HashMap<NaiveDate, usize> = days.iter().enumerate().map(|(i, d)| (*d, i)).collect();
+    let day_of = |bar: &KlineBar| {
+        NaiveDate::parse_from_str(&bar.date, "%Y-%m-%d")
+            .ok()
+            .and_then(|d| position.get(&d).copied())
+    };
+    let bar_on_day: HashMap<usize, usize> = bars
+        .iter()
+        .enumerate()
+        .filter_map(|(i, b)| day_of(b).map(|d| (d, i)))
+        .collect();
+    bars.iter()
+        .map(|b| day_of(b).and_then(|d| bar_on_day.get(&(d + horizon)).copied()))
+        .collect()
+}
+
+fn horizon_stats(
+    bars: &[KlineBar],
+    stale: &[bool],
+    triggers: &[usize],
+    horizon: usize,
+    exits: &[Option<usize>],
+) -> HorizonStats {
+    let tradable = |i: usize| !stale.get(i).copied().unwrap_or(false);
+    let exit = |i: usize| exits[i].filter(|&j| bars[i].close > 0.0 && tradable(i) && tradable(j));
+
+    let mut returns: Vec<f64> = triggers
+        .iter()
+        .filter_map(|&i| exit(i).map(|j| forward_return(bars, i, j)))
+        .collect();
+    let maes: Vec<f64> = triggers
+        .iter()
+        .filter_map(|&i| exit(i).map(|j| adverse_excursion(bars, i, j)))
+        .collect();
+    let baseline: Vec<f64> = (0..bars.len())
+        .filter_map(|i| exit(i).map(|j| forward_return(bars, i, j)))
+        .collect();
+
+    returns.sort_by(|a, b| a.total_cmp(b));
+    let avg = mean(&returns);
+    let baseline_mean = mean(&baseline);
+
+    HorizonStats {
+        horizon,
+        samples: returns.len(),
+        mean: avg,
+        median: percentile(&returns, 0.5),
+        p25: percentile(&returns, 0.25),
+        p75: percentile(&returns, 0.75),
+        min: returns.first().copied().unwrap_or(0.0),
+        max: returns.last().copied().unwrap_or(0.0),
+        hit_rate: hit_rate(&returns),
+        avg_mae: mean(&maes),
+        baseline_mean,
+        baseline_hit_rate: hit_rate(&baseline),
+        excess_mean: avg - baseline_mean,
+    }
+}
+
+/// 第 i 根收盘买入、第 exit 根收盘卖出的收益（%）
+fn forward_return(bars: &[KlineBar], i: usize, exit: usize) -> f64 {
+    (bars[exit].close / bars[i].close - 1.0) * 100.0
+}
+
+/// 持有期内最低价相对买入价的最大跌幅（%），未跌破买入价为 0
+fn adverse_excursion(bars: &[KlineBar], i: usize, exit: usize) -> f64 {
+    let low = bars[i + 1..=exit]
+        .iter()
+        .map(|b| b.low)
+        .fold(f64::MAX, f64::min);
+    ((low / bars[i].close - 1.0) * 100.0).min(0.0)
+}
+
+fn mean(values: &[f64]) -> f64 {
+    if values.is_empty() {
+        0.0
+    } else {
+        values.iter().sum::<f64>() / values.len() as f64
+    }
+}
+
+fn hit_rate(values: &[f64]) -> f64 {
+    if values.is_empty() {
+        0.0
+    } else {
+        values.iter().filter(|v| **v > 0.0).count() as f64 / values.len() as f64
+    }
+}
+
+/// 已排序数据的分位数（线性插值）
+fn percentile(sorted: &[f64], q: f64) -> f64 {
+    if sorted.is_empty() {
+        return 0.0;
+    }
+    let pos = q * (sorted.len() - 1) as f64;
+    let lo = pos.floor() as usize;
+    let hi = pos.ceil() as usize;
+    sorted[lo] + (sorted[hi] - sorted[lo]) * (pos - lo as f64)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// 从 2025-01-02 起连续交易日的 K 线
+    fn make_bars(closes: &[f64]) -> Vec<KlineBar> {
+        let days = calendar::trading_days(
+            NaiveDate::from_ymd_opt(2025, 1, 2).unwrap(),
+            NaiveDate::from_ymd_opt(2025, 3, 31).unwrap(),
+        );
+        closes
+            .iter()
+            .zip(days)
+            .map(|(&c, day)| KlineBar {
+                date: day.format("%Y-%m-%d").to_string(),
+                open: c,
+                close: c,
+                high: c + 0.5,
+                low: c - 0.5,
+                volume: 10000.0,
+                amount: c * 10000.0,
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_event_study() {
+        // 收盘价为 10 时触发，之后一根均上涨
+        let bars = make_bars(&[10.0, 11.0, 10.0, 11.0, 10.0, 10.5]);
+        let study = event_study(
+            "DRAWTEXT(C = 10, C, '低点');",
+            &bars,
+            &EvalOptions::default(),
+            &[1, 2],
+        )
+        .unwrap();
+
+        let signal = &study.signals[0];
+        assert_eq!(signal.triggers, 3);
+        assert_eq!(signal.recent_triggers, vec!["2025-01-08", "2025-01-06", "2025-01-02"]);
+
+        let h1 = &signal.horizons[0];
+        assert_eq!(h1.samples, 3);
+        assert!((h1.mean - 25.0 / 3.0).abs() < 1e-9);
+        assert_eq!(h1.hit_rate, 1.0);
+        assert_eq!(h1.avg_mae, 0.0);
+        assert!(h1.baseline_mean < h1.mean);
+        assert!(h1.excess_mean > 0.0);
+
+        // 持有 2 根：第 5 根之后数据不足
+        let h2 = &signal.horizons[1];
+        assert_eq!(h2.samples, 2);
+        assert_eq!(h2.median, 0.0);
+        assert!((h2.avg_mae + 5.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_suspension_counts_as_holding_days() {
+        // 1 月 7、8 日停牌：6 日买入持有 2 个交易日在停牌中，无法卖出；持有 3 个交易日在 9 日卖出
+        let mut bars = make_bars(&[10.0, 10.0, 10.0, 99.0, 99.0, 12.0, 13.0]);
+        bars.retain(|b| b.close != 99.0);
+        let study = event_study(
+            "DRAWTEXT(DATE = 1250106, C, '买入');",
+            &bars,
+            &EvalOptions::default(),
+            &[1, 2, 3],
+        )
+        .unwrap();
+        let horizons = &study.signals[0].horizons;
+        assert_eq!(horizons[0].samples, 0);
+        assert_eq!(horizons[1].samples, 0);
+        assert_eq!(horizons[2].samples, 1);
+        assert!((horizons[2].mean - 20.0).abs() < 1e-9);
+
+        // 前值填充的停牌日同样不能卖出
+        let calendar: Vec<String> = make_bars(&[0.0; 7]).into_iter().map(|b| b.date).collect();
+        let aligned = crate::services::align::align(&bars, &calendar, crate::services::align::FillPolicy::ForwardFill);
+        let options = EvalOptions {
+            stale: aligned.stale.clone(),
+            ..Default::default()
+        };
+        let study = event_study("DRAWTEXT(DATE = 1250106, C, '买入');", &aligned.bars, &options, &[1, 2, 3]).unwrap();
+        let horizons = &study.signals[0].horizons;
+        assert_eq!(horizons[1].samples, 0);
+        assert_eq!(horizons[2].samples, 1);
+    }
+
+    #[test]
+    fn test_requires_drawtext() {
+        let bars = make_bars(&[10.0, 11.0]);
+        let err = event_study("X : C;", &bars, &EvalOptions::default(), HORIZONS).unwrap_err();
+        assert!(err.limit.is_none());
+
+        // 超出资源限制时带回结构化的超限信息
+        let err = event_study(
+            "N := 1e9; DRAWTEXT(MA(C, N) > 0, L, '买');",
+            &bars,
+            &EvalOptions::default(),
+            HORIZONS,
+        )
+        .unwrap_err();
+        assert!(err.limit.is_some());
+    }
+}
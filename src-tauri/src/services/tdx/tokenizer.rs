@@ -1,6 +1,6 @@
 /// TDX 公式词法分析器
 ///
-/// 支持：数字、标识符（含中文）、字符串、运算符、括号、分号、冒号赋值
+/// 支持：数字（含科学计数法）、标识符（含中文）、字符串、运算符、括号、分号、冒号赋值
 /// 忽略：COLOR*、LINETHICK*、{} 注释
 
 #[derive(Debug, Clone, PartialEq)]
@@ -17,6 +17,7 @@ pub enum Token {
     Ge,                  // >=
     Le,                  // <=
     Eq,                  // =（比较）
+    Ne,                  // <> 或 !=
     And,
     Or,
     Not,
@@ -105,10 +106,18 @@ impl Tokenizer {
                     if self.pos < self.chars.len() && self.chars[self.pos] == '=' {
                         self.advance();
                         tokens.push(TokenWithPos { token: Token::Le, line, col });
+                    } else if self.pos < self.chars.len() && self.chars[self.pos] == '>' {
+                        self.advance();
+                        tokens.push(TokenWithPos { token: Token::Ne, line, col });
                     } else {
                         tokens.push(TokenWithPos { token: Token::Lt, line, col });
                     }
                 }
+                '!' if self.pos + 1 < self.chars.len() && self.chars[self.pos + 1] == '=' => {
+                    self.advance();
+                    self.advance();
+                    tokens.push(TokenWithPos { token: Token::Ne, line, col });
+                }
                 '=' => {
                     tokens.push(TokenWithPos { token: Token::Eq, line, col });
                     self.advance();
@@ -225,6 +234,16 @@ impl Tokenizer {
             } else if ch == '.' && !has_dot {
                 has_dot = true;
                 self.advance();
+            } else if (ch == 'e' || ch == 'E') && self.exponent_follows() {
+                // 科学计数法: 1e9, 2.5E-3
+                self.advance();
+                if matches!(self.chars[self.pos], '+' | '-') {
+                    self.advance();
+                }
+                while self.pos < self.chars.len() && self.chars[self.pos].is_ascii_digit() {
+                    self.advance();
+                }
+                break;
             } else {
                 break;
             }
@@ -235,11 +254,30 @@ impl Tokenizer {
             .map_err(|_| format!("第 {} 行第 {} 列: 无效数字 '{}'", line, col, s))
     }
 
+    /// 当前位置的 e/E 后是否跟着合法指数（可带符号的数字）
+    fn exponent_follows(&self) -> bool {
+        let mut i = self.pos + 1;
+        if i < self.chars.len() && matches!(self.chars[i], '+' | '-') {
+            i += 1;
+        }
+        i < self.chars.len() && self.chars[i].is_ascii_digit()
+    }
+
     fn read_ident(&mut self) -> String {
         let start = self.pos;
         while self.pos < self.chars.len() && is_ident_char(self.chars[self.pos]) {
             self.advance();
         }
+        // 指标引用 NAME.OUTPUT（如 MACD.DIF）作为一个标识符
+        if self.pos + 1 < self.chars.len()
+            && self.chars[self.pos] == '.'
+            && is_ident_start(self.chars[self.pos + 1])
+        {
+            self.advance();
+            while self.pos < self.chars.len() && is_ident_char(self.chars[self.pos]) {
+                self.advance();
+            }
+        }
         self.chars[start..self.pos].iter().collect()
     }
 
@@ -307,6 +345,25 @@ mod tests {
         assert!(!tokens.iter().any(|t| matches!(&t.token, Token::Ident(s) if s.starts_with("LINETHICK"))));
     }
 
+    #[test]
+    fn test_scientific_and_not_equal() {
+        let mut t = Tokenizer::new("AMOUNT > 1e9 AND WEEKDAY <> 5 AND V != 2.5E-3;");
+        let tokens = t.tokenize().unwrap();
+        assert_eq!(tokens[2].token, Token::Number(1e9));
+        assert_eq!(tokens[5].token, Token::Ne);
+        assert_eq!(tokens[9].token, Token::Ne);
+        assert_eq!(tokens[10].token, Token::Number(2.5e-3));
+    }
+
+    #[test]
+    fn test_indicator_reference() {
+        let mut t = Tokenizer::new("X := MACD.DIF > KDJ.J AND C > 0.5;");
+        let tokens = t.tokenize().unwrap();
+        assert_eq!(tokens[2].token, Token::Ident("MACD.DIF".into()));
+        assert_eq!(tokens[4].token, Token::Ident("KDJ.J".into()));
+        assert_eq!(tokens[8].token, Token::Number(0.5));
+    }
+
     #[test]
     fn test_comment() {
         let mut t = Tokenizer::new("{这是注释} MA5 := MA(CLOSE, 5);");
@@ -0,0 +1,251 @@
+//! 求值追踪（解释信号）
+//!
+//! 在指定 K 线上记录每条语句及其各级子表达式的取值，
+//! 并生成带取值标注的表达式，如 `C(12.3) > BBI(12.1) AND REF(C,1)(11.9) < REF(BBI,1)(12)`。
+
+use super::evaluator::{Evaluator, Series};
+use super::parser::{Expr, Statement};
+use serde::Serialize;
+
+/// 表达式节点在追踪 K 线上的取值
+#[derive(Debug, Clone, Serialize)]
+pub struct TraceNode {
+    /// 表达式源码
+    pub expr: String,
+    pub value: f64,
+    pub children: Vec<TraceNode>,
+}
+
+/// 单条语句的追踪结果
+#[derive(Debug, Clone, Serialize)]
+pub struct StatementTrace {
+    /// assign / output / drawtext
+    pub kind: &'static str,
+    /// 变量名；DRAWTEXT 为信号文字
+    pub name: String,
+    /// 变量取值；DRAWTEXT 为价格表达式的值
+    pub value: f64,
+    /// DRAWTEXT 条件是否成立，其余语句为 None
+    pub triggered: Option<bool>,
+    /// 带取值标注的表达式（DRAWTEXT 为条件表达式）
+    pub explain: String,
+    pub tree: TraceNode,
+}
+
+/// 整个公式在某根 K 线上的追踪结果
+#[derive(Debug, Clone, Serialize)]
+pub struct EvalTrace {
+    pub bar_index: usize,
+    pub date: String,
+    pub statements: Vec<StatementTrace>,
+}
+
+/// 将追踪位置换算为 K 线下标，负数从末尾倒数（-1 为最后一根）
+pub fn resolve_bar_index(len: usize, index: i64) -> Result<usize, String> {
+    let resolved = if index < 0 {
+        len as i64 + index
+    } else {
+        index
+    };
+    if resolved < 0 || resolved >= len as i64 {
+        return Err(format!("K线索引 {} 超出范围（共 {} 根）", index, len));
+    }
+    Ok(resolved as usize)
+}
+
+impl Evaluator {
+    /// 逐条执行语句，并记录第 bar 根 K 线上各表达式的取值
+    pub fn trace(&mut self, stmts: &[Statement], bar: usize) -> Result<EvalTrace, String> {
+        if self.len == 0 {
+            return Err("K线数据为空".to_string());
+        }
+        if bar >= self.len {
+            return Err(format!("K线索引 {} 超出范围（共 {} 根）", bar, self.len));
+        }
+
+        self.init_builtin_vars();
+
+        let mut statements = Vec::with_capacity(stmts.len());
+        for stmt in stmts {
+            match stmt {
+                Statement::Assign { name, expr } | Statement::Output { name, expr } => {
+                    let (series, tree, explain) = self.trace_expr(expr, bar)?;
+                    let kind = if matches!(stmt, Statement::Assign { .. }) {
+                        "assign"
+                    } else {
+                        "output"
+                    };
+                    statements.push(StatementTrace {
+                        kind,
+                        name: name.clone(),
+                        value: series[bar],
+                        triggered: None,
+                        explain,
+                        tree,
+                    });
+                    self.vars.insert(name.clone(), series);
+                }
+                Statement::DrawText {
+                    condition,
+                    price_expr,
+                    text,
+                } => {
+                    let (cond, tree, explain) = self.trace_expr(condition, bar)?;
+                    let price = self.eval_expr(price_expr)?;
+                    statements.push(StatementTrace {
+                        kind: "drawtext",
+                        name: text.clone(),
+                        value: price[bar],
+                        triggered: Some(cond[bar] > 0.5),
+                        explain,
+                        tree,
+                    });
+                }
+            }
+        }
+
+        Ok(EvalTrace {
+            bar_index: bar,
+            date: self.bars[bar].date.clone(),
+            statements,
+        })
+    }
+
+    /// 返回表达式的完整序列、取值树和带标注的表达式。
+    /// 变量、函数调用和指标引用标注取值，运算符只展开其操作数
+    fn trace_expr(&self, expr: &Expr, bar: usize) -> Result<(Series, TraceNode, String), String> {
+        let series = self.eval_expr(expr)?;
+        let value = series[bar];
+
+        let (children, explain) = match expr {
+            Expr::Number(_) | Expr::Str(_) => (Vec::new(), expr.to_string()),
+            Expr::Variable(name) => (Vec::new(), format!("{}({})", name, format_value(value))),
+            Expr::BinaryOp { op, left, right } => {
+                let (_, left_node, left_text) = self.trace_expr(left, bar)?;
+                let (_, right_node, right_text) = self.trace_expr(right, bar)?;
+                let left_text = if left.needs_parens(*op, false) {
+                    format!("({})", left_text)
+                } else {
+                    left_text
+                };
+                let right_text = if right.needs_parens(*op, true) {
+                    format!("({})", right_text)
+                } else {
+                    right_text
+                };
+                (
+                    vec![left_node, right_node],
+                    format!("{} {} {}", left_text, op.symbol(), right_text),
+                )
+            }
+            Expr::UnaryOp { op, operand } => {
+                let (_, node, text) = self.trace_expr(operand, bar)?;
+                let text = if operand.precedence() < 6 {
+                    format!("{}({})", op.symbol(), text)
+                } else {
+                    format!("{}{}", op.symbol(), text)
+                };
+                (vec![node], text)
+            }
+            Expr::FuncCall { args, .. } | Expr::IndicatorRef { args, .. } => {
+                // 常量参数（周期、文字）不单独展开
+                let mut children = Vec::new();
+                for arg in args {
+                    if !matches!(arg, Expr::Number(_) | Expr::Str(_)) {
+                        children.push(self.trace_expr(arg, bar)?.1);
+                    }
+                }
+                (children, format!("{}({})", expr, format_value(value)))
+            }
+        };
+
+        let node = TraceNode {
+            expr: expr.to_string(),
+            value,
+            children,
+        };
+        Ok((series, node, explain))
+    }
+}
+
+/// 最多保留 4 位小数并去掉末尾的 0
+fn format_value(v: f64) -> String {
+    if !v.is_finite() {
+        return v.to_string();
+    }
+    let s = format!("{:.4}", v);
+    let s = s.trim_end_matches('0').trim_end_matches('.');
+    if s == "-0" {
+        "0".to_string()
+    } else {
+        s.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::services::kline::KlineBar;
+    use crate::services::tdx::evaluator::EvalOptions;
+    use crate::services::tdx::parse_formula;
+
+    fn make_bars(closes: &[f64]) -> Vec<KlineBar> {
+        closes
+            .iter()
+            .enumerate()
+            .map(|(i, &c)| KlineBar {
+                date: format!("2025-01-{:02}", i + 1),
+                open: c - 0.5,
+                close: c,
+                high: c + 1.0,
+                low: c - 1.0,
+                volume: 10000.0,
+                amount: c * 10000.0,
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_trace_explains_signal() {
+        let bars = make_bars(&[10.0, 11.0, 12.0, 12.5]);
+        let stmts = parse_formula(
+            "M := MA(C, 2);
+             UP : C > M AND REF(C, 1) > 10;
+             DRAWTEXT(UP, L, '突破');",
+        )
+        .unwrap();
+        let mut e = Evaluator::new(bars, EvalOptions::default());
+        let trace = e.trace(&stmts, 3).unwrap();
+
+        assert_eq!(trace.date, "2025-01-04");
+        assert_eq!(trace.statements.len(), 3);
+        assert_eq!(trace.statements[0].explain, "MA(C,2)(12.25)");
+        assert_eq!(trace.statements[0].tree.children[0].expr, "C");
+        assert_eq!(
+            trace.statements[1].explain,
+            "C(12.5) > M(12.25) AND REF(C,1)(12) > 10"
+        );
+        assert_eq!(trace.statements[1].value, 1.0);
+
+        let signal = &trace.statements[2];
+        assert_eq!(signal.kind, "drawtext");
+        assert_eq!(signal.triggered, Some(true));
+        assert_eq!(signal.value, 11.5);
+        assert_eq!(signal.tree.children.len(), 0);
+    }
+
+    #[test]
+    fn test_trace_bar_index() {
+        assert_eq!(resolve_bar_index(5, -1).unwrap(), 4);
+        assert_eq!(resolve_bar_index(5, 0).unwrap(), 0);
+        assert!(resolve_bar_index(5, 5).is_err());
+        assert!(resolve_bar_index(5, -6).is_err());
+
+        let stmts = parse_formula("X : (C - 1) * 2;").unwrap();
+        let mut e = Evaluator::new(make_bars(&[10.0, 20.0]), EvalOptions::default());
+        let trace = e.trace(&stmts, 0).unwrap();
+        assert_eq!(trace.statements[0].explain, "(C(10) - 1) * 2");
+        assert_eq!(trace.statements[0].value, 18.0);
+        assert!(e.trace(&stmts, 2).is_err());
+    }
+}
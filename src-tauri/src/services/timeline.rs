@@ -0,0 +1,226 @@
+//! 分时数据
+//!
+//! 每分钟一个点：价格、成交量、成交额和均价（VWAP，当日累计成交额 / 累计成交量）。
+//! 数据源返回当日和之前若干交易日的分时。股票、ETF 和 B 股的均价在这里按成交额和成交量重新累计，
+//! 不依赖数据源的均价字段，成交量单位（手或股）由成交额与价格推出。
+//! 指数的成交量、成交额是成分股的合计，换算出的是市场平均股价而不是点位，均价线取数据源的值。
+//!
+//! 提醒和 Agent Plan 条件可以使用分时指标：
+//! - vwap：当日均价
+//! - vwap_deviation：现价相对均价的偏离（%）
+//! - minutes_below_vwap / minutes_above_vwap：截至最新一分钟，价格连续在均价线下方 / 上方的分钟数
+
+use crate::services::{calendar, provider, security};
+use serde::{Deserialize, Serialize};
+
+/// 最多请求的天数（当日 + 之前 4 个交易日）
+pub const MAX_DAYS: usize = 5;
+
+/// 可在 Agent Plan 条件中使用的分时字段
+pub const CONDITION_FIELDS: &[&str] = &["vwap", "vwap_deviation", "minutes_below_vwap", "minutes_above_vwap"];
+
+/// 分时的一分钟
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TimelinePoint {
+    /// HH:MM
+    pub time: String,
+    pub price: f64,
+    /// 手
+    pub volume: f64,
+    /// 元
+    pub amount: f64,
+    /// 截至该分钟的均价
+    pub avg_price: f64,
+}
+
+/// 一个交易日的分时
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Timeline {
+    /// YYYY-MM-DD
+    pub date: String,
+    pub prev_close: f64,
+    pub points: Vec<TimelinePoint>,
+}
+
+/// 分时指标
+#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
+pub struct TimelineStats {
+    pub vwap: f64,
+    pub vwap_deviation: f64,
+    pub minutes_below_vwap: u32,
+    pub minutes_above_vwap: u32,
+}
+
+impl Timeline {
+    /// 按每分钟的成交额、成交量累计均价；没有成交量的分钟（停牌）保留原均价，原均价为 0 时取现价
+    pub fn fill_vwap(&mut self) {
+        let shares = self.shares_per_volume();
+        let (mut amount, mut volume) = (0.0, 0.0);
+        for p in &mut self.points {
+            amount += p.amount;
+            volume += p.volume;
+            if volume > 0.0 && amount > 0.0 {
+                p.avg_price = amount / (volume * shares);
+            } else if p.avg_price <= 0.0 {
+                p.avg_price = p.price;
+            }
+        }
+    }
+
+    /// 指数保留数据源的均价，缺失时取现价
+    pub fn fill_index_avg(&mut self) {
+        for p in &mut self.points {
+            if p.avg_price <= 0.0 {
+                p.avg_price = p.price;
+            }
+        }
+    }
+
+    /// 每单位成交量的股数：成交额 /（成交量 × 价格）最接近的 10 的幂取中位数（按手为 100，按股为 1），
+    /// 没有成交时按手
+    fn shares_per_volume(&self) -> f64 {
+        let mut exponents: Vec<i32> = self
+            .points
+            .iter()
+            .filter(|p| p.volume > 0.0 && p.amount > 0.0 && p.price > 0.0)
+            .map(|p| (p.amount / (p.volume * p.price)).log10().round() as i32)
+            .collect();
+        if exponents.is_empty() {
+            return 100.0;
+        }
+        exponents.sort_unstable();
+        10f64.powi(exponents[exponents.len() / 2])
+    }
+
+    /// 最新一分钟的分时指标，没有数据时为 None
+    pub fn stats(&self) -> Option<TimelineStats> {
+        let last = self.points.last()?;
+        let run = |below: bool| {
+            self.points
+                .iter()
+                .rev()
+                .take_while(|p| if below { p.price < p.avg_price } else { p.price > p.avg_price })
+                .count() as u32
+        };
+        Some(TimelineStats {
+            vwap: last.avg_price,
+            vwap_deviation: if last.avg_price > 0.0 {
+                (last.price - last.avg_price) / last.avg_price * 100.0
+            } else {
+                0.0
+            },
+            minutes_below_vwap: run(true),
+            minutes_above_vwap: run(false),
+        })
+    }
+}
+
+/// 最近 days 个交易日的分时（按日期先后，最后一个为当日或最近一个交易日）
+pub async fn fetch_timeline(symbol: &str, days: usize) -> Result<Vec<Timeline>, String> {
+    let days = days.clamp(1, MAX_DAYS);
+    let mut timelines = provider::current().timeline(symbol, days).await?;
+    let index = is_index(symbol);
+    for t in &mut timelines {
+        if index {
+            t.fill_index_avg();
+        } else {
+            t.fill_vwap();
+        }
+    }
+    Ok(timelines)
+}
+
+/// 是否指数：按证券主表判断，主表中没有时按带前缀的指数号段（sh000、sz399、bj899）判断
+fn is_index(symbol: &str) -> bool {
+    match security::lookup(symbol) {
+        Some(s) => s.sec_type == "index",
+        None => {
+            let lower = symbol.trim().to_lowercase();
+            ["sh000", "sz399", "bj899"].iter().any(|p| lower.starts_with(p))
+        }
+    }
+}
+
+/// 当日的分时指标；数据源返回的最近一天不是今天（非交易日、停牌或尚未开盘）时返回错误
+pub async fn fetch_stats(symbol: &str) -> Result<TimelineStats, String> {
+    let timelines = fetch_timeline(symbol, 1).await?;
+    let today = calendar::today().format("%Y-%m-%d").to_string();
+    let timeline = timelines.last().ok_or_else(|| format!("{} 没有分时数据", symbol))?;
+    if timeline.date != today {
+        return Err(format!("{} 没有 {} 的分时数据（最近为 {}）", symbol, today, timeline.date));
+    }
+    timeline.stats().ok_or_else(|| format!("{} 没有分时数据", symbol))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_vwap_and_runs() {
+        let point = |time: &str, price: f64, volume: f64| TimelinePoint {
+            time: time.to_string(),
+            price,
+            volume,
+            amount: price * volume * 100.0,
+            avg_price: 0.0,
+        };
+        let mut timeline = Timeline {
+            date: "2025-06-30".into(),
+            prev_close: 10.0,
+            points: vec![
+                point("09:30", 10.0, 300.0),
+                point("09:31", 10.6, 100.0),
+                point("09:32", 10.0, 0.0),
+                point("09:33", 9.9, 100.0),
+            ],
+        };
+        timeline.fill_vwap();
+        // (3000 + 1060) / 400 = 10.15
+        assert!((timeline.points[1].avg_price - 10.15).abs() < 1e-9);
+        // 没有成交的分钟均价不变
+        assert_eq!(timeline.points[2].avg_price, timeline.points[1].avg_price);
+
+        let stats = timeline.stats().unwrap();
+        // 均价 (4060 + 990) / 500 = 10.1
+        assert!((stats.vwap - 10.1).abs() < 1e-9);
+        assert_eq!((stats.minutes_below_vwap, stats.minutes_above_vwap), (2, 0));
+        assert!((stats.vwap_deviation - (9.9 - 10.1) / 10.1 * 100.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_volume_units_and_index() {
+        let point = |price: f64, volume: f64, amount: f64, avg_price: f64| TimelinePoint {
+            time: "09:30".into(),
+            price,
+            volume,
+            amount,
+            avg_price,
+        };
+        let timeline = |points| Timeline {
+            date: "2025-06-30".into(),
+            prev_close: 0.0,
+            points,
+        };
+
+        // ETF 按手：3.861 元成交 1000 手
+        let mut etf = timeline(vec![point(3.861, 1000.0, 386_100.0, 0.0)]);
+        etf.fill_vwap();
+        assert!((etf.points[0].avg_price - 3.861).abs() < 1e-9);
+        // 按股计量的成交量
+        let mut shares = timeline(vec![point(0.457, 20_000.0, 9_140.0, 0.0)]);
+        shares.fill_vwap();
+        assert!((shares.points[0].avg_price - 0.457).abs() < 1e-9);
+
+        // 上证指数：成交量、成交额是成分股合计，保留数据源的均价线
+        let mut index = timeline(vec![
+            point(3400.0, 3.0e6, 3.0e9, 3398.5),
+            point(3401.0, 2.0e6, 2.0e9, 0.0),
+        ]);
+        index.fill_index_avg();
+        assert_eq!((index.points[0].avg_price, index.points[1].avg_price), (3398.5, 3401.0));
+        assert!(is_index("sh000001"));
+        assert!(is_index("sz399006"));
+        assert!(!is_index("000001"));
+    }
+}
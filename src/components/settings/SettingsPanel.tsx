@@ -1,10 +1,19 @@
 import { X, Eye, EyeOff, Check } from 'lucide-react';
 import { useState } from 'react';
 import { useSettingsStore } from '../../stores/settingsStore';
+import type { MarketDataConfig, MarketDataProviderName } from '../../types/market';
 
 export function SettingsPanel() {
-  const { modelConfig, updateModelConfig, setSettingsOpen } = useSettingsStore();
+  const { modelConfig, updateModelConfig, marketDataConfig, updateMarketDataConfig, setSettingsOpen } =
+    useSettingsStore();
   const [showKey, setShowKey] = useState(false);
+  const [fixturePath, setFixturePath] = useState(marketDataConfig.fixture_path ?? '');
+  const [providerError, setProviderError] = useState<string | null>(null);
+
+  const applyMarketData = (updates: Partial<MarketDataConfig>) => {
+    setProviderError(null);
+    updateMarketDataConfig(updates).catch((e) => setProviderError(String(e)));
+  };
 
   return (
     <div
@@ -161,6 +170,60 @@ export function SettingsPanel() {
               天眼 · 多模态（图片识别）
             </span>
           </label>
+
+          {/* Market data provider */}
+          <div className="separator-ornate mb-3 pt-2">
+            <span
+              className="text-[10px] tracking-[0.15em]"
+              style={{ color: 'var(--text-dim)', fontFamily: 'var(--font-display)' }}
+            >
+              行情源
+            </span>
+          </div>
+
+          <div>
+            <label
+              className="text-[11px] mb-1.5 block"
+              style={{ color: 'var(--text-dim)', fontFamily: 'var(--font-display)' }}
+            >
+              数据源 · Provider
+            </label>
+            <select
+              value={marketDataConfig.provider}
+              onChange={(e) =>
+                applyMarketData({ provider: e.target.value as MarketDataProviderName })
+              }
+              className="input-realm w-full rounded-lg px-3 py-2.5 text-sm"
+            >
+              <option value="eastmoney">东方财富（实时）</option>
+              <option value="fixture">离线 Fixture（测试）</option>
+            </select>
+          </div>
+
+          {marketDataConfig.provider === 'fixture' && (
+            <div>
+              <label
+                className="text-[11px] mb-1.5 block"
+                style={{ color: 'var(--text-dim)', fontFamily: 'var(--font-display)' }}
+              >
+                数据文件 · Fixture JSON（留空使用生成数据）
+              </label>
+              <input
+                type="text"
+                value={fixturePath}
+                onChange={(e) => setFixturePath(e.target.value)}
+                onBlur={() => applyMarketData({ fixture_path: fixturePath.trim() || null })}
+                placeholder="/path/to/fixture.json"
+                className="input-realm w-full rounded-lg px-3 py-2.5 text-sm"
+              />
+            </div>
+          )}
+
+          {providerError && (
+            <p className="text-[11px]" style={{ color: 'var(--cinnabar-400)' }}>
+              {providerError}
+            </p>
+          )}
         </div>
 
         {/* Footer */}
@@ -21,11 +21,13 @@ interface AlertTriggeredPayload {
 }
 
 interface IndicatorSignalPayload {
+  signal_id: string;
   indicator_id: string;
   indicator_name: string;
   symbol: string;
   signal_text: string;
-  signal_value: number;
+  /** 价格表达式不是有限值时为 null */
+  signal_value: number | null;
   task_id: string | null;
   date: string;
 }
@@ -272,9 +274,10 @@ async function handleAgentPlanVision(payload: AgentPlanVisionPayload) {
 
 async function handleIndicatorSignal(payload: IndicatorSignalPayload) {
   const chatStore = useChatStore.getState();
-  const { indicator_name, symbol, signal_text, signal_value, task_id, date } = payload;
+  const { signal_id, indicator_name, symbol, signal_text, signal_value, task_id, date } = payload;
+  const price = signal_value == null ? '-' : signal_value.toFixed(2);
 
-  const content = `📊 **指标信号触发**\n\n指标: ${indicator_name}\n股票: ${symbol}\n信号: ${signal_text}\n价位: ${signal_value.toFixed(2)}\n日期: ${date}`;
+  const content = `📊 **指标信号触发**\n\n指标: ${indicator_name}\n股票: ${symbol}\n信号: ${signal_text}\n价位: ${price}\n日期: ${date}`;
 
   if (task_id) {
     await chatStore.addMessage(task_id, 'assistant', content, undefined, 'indicator-signal');
@@ -283,6 +286,9 @@ async function handleIndicatorSignal(payload: IndicatorSignalPayload) {
     const task = await chatStore.createTask(`指标信号: ${indicator_name} - ${symbol}`, 'monitor');
     await chatStore.addMessage(task.id, 'assistant', content, undefined, 'indicator-signal');
   }
+  await invoke('cmd_mark_indicator_signal_delivered', { id: signal_id }).catch((e) => {
+    console.error('标记信号送达失败:', e);
+  });
 
   // 如有 API Key，调用 AI 分析
   const { modelConfig } = useSettingsStore.getState();
@@ -291,7 +297,7 @@ async function handleIndicatorSignal(payload: IndicatorSignalPayload) {
   const targetTaskId = task_id || chatStore.tasks[chatStore.tasks.length - 1]?.id;
   if (!targetTaskId) return;
 
-  const userContent = `[指标信号自动触发] 指标 "${indicator_name}" 在股票 ${symbol} 上触发了 "${signal_text}" 信号（价位 ${signal_value.toFixed(2)}）。请获取该股票最新行情并给出分析建议。`;
+  const userContent = `[指标信号自动触发] 指标 "${indicator_name}" 在股票 ${symbol} 上触发了 "${signal_text}" 信号（价位 ${price}）。请获取该股票最新行情并给出分析建议。`;
   await chatStore.addMessage(targetTaskId, 'user', userContent, undefined, 'indicator-signal');
 
   const messages: ChatMessage[] = [{ role: 'user', content: userContent }];
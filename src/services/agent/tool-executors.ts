@@ -38,10 +38,10 @@ export function registerAllTools() {
 
   toolRegistry.register(
     'search_stocks',
-    def('search_stocks', '根据关键词搜索股票，支持名称或代码模糊搜索，返回匹配的A股列表', {
+    def('search_stocks', '根据关键词搜索股票、ETF 和指数，支持名称、代码前缀和拼音首字母模糊搜索（本地离线搜索）。指数代码带交易所前缀，如 sh000001', {
       type: 'object',
       properties: {
-        keyword: { type: 'string', description: '搜索关键词，如"茅台"、"宁德"、"600"' },
+        keyword: { type: 'string', description: '搜索关键词，如"茅台"、"gzmt"、"600"、"hs300"' },
       },
       required: ['keyword'],
     }),
@@ -54,7 +54,7 @@ export function registerAllTools() {
 
   toolRegistry.register(
     'fetch_batch_quotes',
-    def('fetch_batch_quotes', '批量获取多只股票的实时行情数据', {
+    def('fetch_batch_quotes', '批量获取多只股票的实时行情数据（一次请求）。返回 quotes 和 errors，errors 中为获取失败的代码及原因', {
       type: 'object',
       properties: {
         symbols: { type: 'array', items: { type: 'string' }, description: '股票代码数组，如 ["600519", "000001"]' },
@@ -68,6 +68,117 @@ export function registerAllTools() {
     ['market-query'],
   );
 
+  toolRegistry.register(
+    'get_security_info',
+    def('get_security_info', '查询证券主表：交易所（SH/SZ/BJ）、板块（主板/创业板/科创板/北交所）、类型（stock/b_share/etf/index）、是否 ST、上市日期、拼音首字母', {
+      type: 'object',
+      properties: {
+        symbol: { type: 'string', description: '证券代码，可带 sh/sz/bj 前缀区分同代码的股票和指数，如 "sh000001"' },
+      },
+      required: ['symbol'],
+    }),
+    async (args) => {
+      const security = await invoke('cmd_get_security', { symbol: args.symbol as string });
+      return JSON.stringify(security ?? { error: `证券主表中没有 ${args.symbol}` });
+    },
+    ['market-query'],
+  );
+
+  toolRegistry.register(
+    'fetch_order_book',
+    def('fetch_order_book', '获取五档盘口：买一到买五（bids）、卖一到卖五（asks）的价格和挂单量（手）。涨停股的买一量即封单量', {
+      type: 'object',
+      properties: {
+        symbol: { type: 'string', description: '股票代码' },
+      },
+      required: ['symbol'],
+    }),
+    async (args) => {
+      const book = await invoke('cmd_fetch_order_book', { symbol: args.symbol as string });
+      return JSON.stringify(book);
+    },
+    ['market-query'],
+  );
+
+  toolRegistry.register(
+    'fetch_trade_ticks',
+    def('fetch_trade_ticks', '获取最近的分笔成交：时间、价格、量（手）、方向（buy 主动买 / sell 主动卖 / neutral 集合竞价）', {
+      type: 'object',
+      properties: {
+        symbol: { type: 'string', description: '股票代码' },
+        count: { type: 'number', description: '笔数，默认 50，最多 1000' },
+      },
+      required: ['symbol'],
+    }),
+    async (args) => {
+      const ticks = await invoke('cmd_fetch_trade_ticks', {
+        symbol: args.symbol as string,
+        count: args.count as number | undefined,
+      });
+      return JSON.stringify(ticks);
+    },
+    ['market-query'],
+  );
+
+  toolRegistry.register(
+    'fetch_intraday_timeline',
+    def('fetch_intraday_timeline', '获取分时走势：每 5 分钟一个点的价格、成交量（手）和均价（VWAP），以及最新的分时指标（vwap 均价、vwap_deviation 偏离均价%、minutes_below_vwap / minutes_above_vwap 连续在均价线下方/上方的分钟数）', {
+      type: 'object',
+      properties: {
+        symbol: { type: 'string', description: '股票代码' },
+        days: { type: 'number', description: '天数，1=当日（默认），2=当日和前一交易日，最多 5' },
+      },
+      required: ['symbol'],
+    }),
+    async (args) => {
+      const symbol = args.symbol as string;
+      const [timelines, stats] = await Promise.all([
+        invoke<{ date: string; prev_close: number; points: unknown[] }[]>('cmd_fetch_timeline', {
+          symbol,
+          days: args.days as number | undefined,
+        }),
+        invoke('cmd_fetch_timeline_stats', { symbol }),
+      ]);
+      // 241 个分钟点对模型太长，每 5 分钟取一个并保留最后一分钟
+      const sampled = timelines.map((t) => ({
+        ...t,
+        points: t.points.filter((_, i) => i % 5 === 0 || i === t.points.length - 1),
+      }));
+      return JSON.stringify({ stats, timelines: sampled });
+    },
+    ['market-query'],
+  );
+
+  toolRegistry.register(
+    'get_market_status',
+    def('get_market_status', '查询A股当前交易时段（closed 非交易日 / pre_market 盘前 / opening_auction 开盘集合竞价 / pre_open 等待开盘 / continuous 连续竞价 / lunch_break 午休 / closing_auction 收盘集合竞价 / after_hours 收盘后）、今天是否交易日、节假日名称和前后交易日', {
+      type: 'object',
+      properties: {},
+    }),
+    async () => {
+      const status = await invoke('cmd_get_market_status');
+      return JSON.stringify(status);
+    },
+    ['market-query'],
+  );
+
+  toolRegistry.register(
+    'get_trading_days',
+    def('get_trading_days', '查询日期范围内的A股交易日（已排除周末和节假日）', {
+      type: 'object',
+      properties: {
+        start: { type: 'string', description: '开始日期 YYYY-MM-DD' },
+        end: { type: 'string', description: '结束日期 YYYY-MM-DD' },
+      },
+      required: ['start', 'end'],
+    }),
+    async (args) => {
+      const days = await invoke('cmd_get_trading_days', { start: args.start as string, end: args.end as string });
+      return JSON.stringify(days);
+    },
+    ['market-query'],
+  );
+
   // ── 自选股管理 (skill: watchlist) ──
 
   toolRegistry.register(
@@ -129,8 +240,8 @@ export function registerAllTools() {
         stock_symbol: { type: 'string', description: '股票代码' },
         alert_type: {
           type: 'string',
-          enum: ['price_above', 'price_below', 'change_above', 'change_below', 'volume_ratio'],
-          description: '提醒类型：price_above=价格高于, price_below=价格低于, change_above=涨幅超过, change_below=跌幅超过, volume_ratio=量比异常',
+          enum: ['price_above', 'price_below', 'change_above', 'change_below', 'volume_ratio', 'limit_up', 'limit_down', 'near_limit_up', 'limit_break', 'big_sell_order', 'bid1_collapse', 'below_vwap', 'above_vwap'],
+          description: '提醒类型：price_above=价格高于, price_below=价格低于, change_above=涨幅超过, change_below=跌幅超过, volume_ratio=量比异常, limit_up=涨停, limit_down=跌停, near_limit_up=距涨停价不超过阈值(%), limit_break=炸板(触及涨停后打开), big_sell_order=连续竞价时段最近一分钟出现单笔不少于阈值(手)的主动卖单, bid1_collapse=买一价位不变时买一量比上次检查(约 10 秒前)减少超过阈值(%), below_vwap/above_vwap=价格连续阈值分钟在分时均价线下方/上方。涨跌停按板块和 ST 规则精确计算，limit_up/limit_down/limit_break 的阈值填 0',
         },
         threshold: { type: 'number', description: '触发阈值' },
         message: { type: 'string', description: '提醒消息内容' },
@@ -293,6 +404,19 @@ export function registerAllTools() {
     ['limit-stocks'],
   );
 
+  toolRegistry.register(
+    'fetch_limit_ladder',
+    def('fetch_limit_ladder', '获取最近一个交易日的连板天梯（非交易日和开盘前为上一个交易日，date 字段为股池日期）：按连板数分级的涨停股（首次/最后封板时间、炸板次数、封单金额、行业）、炸板未回封的股票、封板数、炸板数和炸板率', {
+      type: 'object',
+      properties: {},
+    }),
+    async () => {
+      const ladder = await invoke('cmd_fetch_limit_ladder');
+      return JSON.stringify(ladder);
+    },
+    ['limit-stocks'],
+  );
+
   // ── 记忆工具 (skill: memory) ──
 
   toolRegistry.register(
@@ -408,7 +532,7 @@ export function registerAllTools() {
             type: { type: 'string', enum: ['interval', 'daily', 'once'], description: '调度类型' },
             interval_minutes: { type: 'number', description: 'interval 类型的间隔分钟数' },
             trigger_time: { type: 'string', description: 'daily 类型的触发时间 HH:MM' },
-            market_hours_only: { type: 'boolean', description: '是否仅交易时间执行，默认 true' },
+            market_hours_only: { type: 'boolean', description: '是否仅交易时间执行（集合竞价和连续竞价时段，节假日和午休不执行），默认 true' },
           },
           required: ['type'],
         },
@@ -807,15 +931,45 @@ ${config.tools.map((t) => `- ${t.name}: ${t.description}`).join('\n')}
 
   toolRegistry.register(
     'validate_tdx_formula',
-    def('validate_tdx_formula', '验证通达信（TDX）公式语法是否正确，返回输出变量、DRAWTEXT 数量和错误信息', {
+    def('validate_tdx_formula', '验证通达信（TDX）公式语法是否正确，返回输出变量、DRAWTEXT 数量、所需 K 线数量和错误信息', {
       type: 'object',
       properties: {
         source: { type: 'string', description: '通达信公式源代码' },
+        symbols: {
+          type: 'array',
+          items: { type: 'string' },
+          description: '可选：要监控的股票代码，会检查其上市历史是否满足公式所需的 K 线数量',
+        },
       },
       required: ['source'],
     }),
     async (args) => {
-      const result = await invoke('cmd_validate_tdx_formula', { source: args.source as string });
+      const result = await invoke('cmd_validate_tdx_formula', {
+        source: args.source as string,
+        symbols: (args.symbols as string[]) || null,
+        adjustMode: null,
+      });
+      return JSON.stringify(result);
+    },
+    ['tdx-indicator'],
+  );
+
+  toolRegistry.register(
+    'tdx_formula_assist',
+    def('tdx_formula_assist', '查询 TDX 公式引擎在光标处支持的补全候选（函数签名、内置变量、已定义变量、内置指标输出）、悬停说明和参数提示', {
+      type: 'object',
+      properties: {
+        source: { type: 'string', description: '通达信公式源代码（可以是未写完的公式）' },
+        offset: { type: 'number', description: '光标位置（字符偏移），默认为公式末尾' },
+      },
+      required: ['source'],
+    }),
+    async (args) => {
+      const source = args.source as string;
+      const result = await invoke('cmd_formula_assist', {
+        source,
+        offset: (args.offset as number) ?? source.length,
+      });
       return JSON.stringify(result);
     },
     ['tdx-indicator'],
@@ -828,11 +982,30 @@ ${config.tools.map((t) => `- ${t.name}: ${t.description}`).join('\n')}
       properties: {
         name: { type: 'string', description: '指标名称，如 "BBI金叉"' },
         formula_source: { type: 'string', description: '通达信公式源代码（必须包含 DRAWTEXT）' },
-        stock_symbols: { type: 'array', items: { type: 'string' }, description: '监控的股票代码列表' },
+        stock_symbols: { type: 'array', items: { type: 'string' }, description: '监控的股票代码列表（设置了 symbol_source 时可省略）' },
+        symbol_source: {
+          type: 'object',
+          description: '动态股票来源，每个检查周期重新解析并与 stock_symbols 合并（最多 200 只）',
+          properties: {
+            type: { type: 'string', enum: ['watchlist', 'limit_up', 'limit_down', 'board'], description: 'watchlist 全部自选股、limit_up 今日涨停、limit_down 今日跌停、board 板块成分股' },
+            board: { type: 'string', description: 'type 为 board 时必填：板块名称（如 "半导体"）或东方财富板块代码（如 BK0477）' },
+          },
+          required: ['type'],
+        },
         check_interval_secs: { type: 'number', description: '检查间隔秒数，默认 60' },
-        market_hours_only: { type: 'boolean', description: '是否仅交易时间检查，默认 true' },
+        market_hours_only: { type: 'boolean', description: '是否仅交易时间检查（集合竞价和连续竞价时段，节假日和午休不检查），默认 true' },
+        adjust_mode: { type: 'string', enum: ['forward', 'backward', 'none'], description: 'K 线复权方式：forward 前复权（默认）、backward 后复权、none 不复权' },
+        fill_policy: { type: 'string', enum: ['drop', 'forward_fill', 'undefined'], description: '停牌日处理：drop 跳过（默认）、forward_fill 沿用停牌前收盘价、undefined 价格未定义' },
+        chip_model: {
+          type: 'object',
+          description: '筹码分布模型（仅 COST/WINNER/LWINNER/PWINNER 使用）',
+          properties: {
+            distribution: { type: 'string', enum: ['triangular', 'uniform'], description: '当日筹码分布：triangular 三角分布（默认）、uniform 均匀分布' },
+            decay: { type: 'number', description: '历史换手衰减系数，默认 1.0' },
+          },
+        },
       },
-      required: ['name', 'formula_source', 'stock_symbols'],
+      required: ['name', 'formula_source'],
     }),
     async (args) => {
       const taskId = useChatStore.getState().activeTaskId;
@@ -840,10 +1013,14 @@ ${config.tools.map((t) => `- ${t.name}: ${t.description}`).join('\n')}
         request: {
           name: args.name as string,
           formula_source: args.formula_source as string,
-          stock_symbols: args.stock_symbols as string[],
+          stock_symbols: (args.stock_symbols as string[]) || [],
           task_id: taskId || null,
           check_interval_secs: (args.check_interval_secs as number) || 60,
           market_hours_only: args.market_hours_only !== false,
+          adjust_mode: (args.adjust_mode as string) || null,
+          chip_model: args.chip_model || null,
+          fill_policy: (args.fill_policy as string) || null,
+          symbol_source: args.symbol_source || null,
         },
       });
       return JSON.stringify(indicator);
@@ -873,9 +1050,28 @@ ${config.tools.map((t) => `- ${t.name}: ${t.description}`).join('\n')}
         name: { type: 'string', description: '新名称' },
         formula_source: { type: 'string', description: '新公式' },
         stock_symbols: { type: 'array', items: { type: 'string' }, description: '新股票列表' },
+        symbol_source: {
+          type: 'object',
+          description: '新的动态股票来源；传 null 取消来源，只监控 stock_symbols',
+          properties: {
+            type: { type: 'string', enum: ['watchlist', 'limit_up', 'limit_down', 'board'], description: 'watchlist 全部自选股、limit_up 今日涨停、limit_down 今日跌停、board 板块成分股' },
+            board: { type: 'string', description: 'type 为 board 时必填：板块名称（如 "半导体"）或东方财富板块代码（如 BK0477）' },
+          },
+          required: ['type'],
+        },
         is_active: { type: 'boolean', description: '是否启用' },
         check_interval_secs: { type: 'number', description: '检查间隔秒数' },
         market_hours_only: { type: 'boolean', description: '是否仅交易时间检查' },
+        adjust_mode: { type: 'string', enum: ['forward', 'backward', 'none'], description: 'K 线复权方式' },
+        fill_policy: { type: 'string', enum: ['drop', 'forward_fill', 'undefined'], description: '停牌日处理方式' },
+        chip_model: {
+          type: 'object',
+          description: '筹码分布模型（仅 COST/WINNER/LWINNER/PWINNER 使用）；传 null 恢复默认模型',
+          properties: {
+            distribution: { type: 'string', enum: ['triangular', 'uniform'], description: '当日筹码分布：triangular 三角分布（默认）、uniform 均匀分布' },
+            decay: { type: 'number', description: '历史换手衰减系数，默认 1.0' },
+          },
+        },
       },
       required: ['id'],
     }),
@@ -909,16 +1105,124 @@ ${config.tools.map((t) => `- ${t.name}: ${t.description}`).join('\n')}
       type: 'object',
       properties: {
         id: { type: 'string', description: '指标 ID' },
+        trace_bar: {
+          type: 'number',
+          description: '解释模式：返回该根 K 线上每个变量和子表达式的取值，负数从末尾倒数（-1 为最新一根）',
+        },
       },
       required: ['id'],
     }),
     async (args) => {
-      const result = await invoke('cmd_evaluate_indicator', { id: args.id as string });
+      const result = await invoke('cmd_evaluate_indicator', {
+        id: args.id as string,
+        traceBar: (args.trace_bar as number) ?? null,
+      });
+      return JSON.stringify(result);
+    },
+    ['tdx-indicator'],
+  );
+
+  toolRegistry.register(
+    'study_tdx_signal',
+    def('study_tdx_signal', '统计 TDX 公式 DRAWTEXT 信号的历史表现：每次触发后 1/3/5/10/20 个交易日（停牌日计入持有期，到期停牌的样本不计）的收益分布、胜率、平均最大不利波动，以及与无条件基准的对比', {
+      type: 'object',
+      properties: {
+        id: { type: 'string', description: '已保存的指标 ID（与 formula 二选一）' },
+        formula: { type: 'string', description: '未保存的公式源代码' },
+        symbols: { type: 'array', items: { type: 'string' }, description: '股票代码；传入 id 时默认使用指标绑定的股票' },
+        adjust_mode: { type: 'string', enum: ['forward', 'backward', 'none'], description: '复权方式，默认前复权' },
+        fill_policy: { type: 'string', enum: ['drop', 'forward_fill', 'undefined'], description: '停牌日处理方式；传入 id 时默认使用指标的设置' },
+        bars: { type: 'number', description: '统计的历史 K 线数量，默认 1000' },
+      },
+    }),
+    async (args) => {
+      const result = await invoke('cmd_indicator_event_study', {
+        id: (args.id as string) || null,
+        formula: (args.formula as string) || null,
+        symbols: (args.symbols as string[]) || null,
+        adjustMode: (args.adjust_mode as string) || null,
+        fillPolicy: (args.fill_policy as string) || null,
+        bars: (args.bars as number) ?? null,
+      });
+      return JSON.stringify(result);
+    },
+    ['tdx-indicator'],
+  );
+
+  toolRegistry.register(
+    'list_indicator_signals',
+    def('list_indicator_signals', '查询 TDX 指标的历史触发信号（指标、股票、信号文本、价位、K 线日期、是否已确认），按日期倒序', {
+      type: 'object',
+      properties: {
+        indicator_id: { type: 'string', description: '只查询该指标' },
+        symbol: { type: 'string', description: '只查询该股票' },
+        since: { type: 'string', description: 'K 线日期下限（含），如 2025-01-06' },
+        until: { type: 'string', description: 'K 线日期上限（含）' },
+        unacknowledged_only: { type: 'boolean', description: '只返回未确认的信号' },
+        limit: { type: 'number', description: '最多返回条数，默认 100' },
+      },
+    }),
+    async (args) => {
+      const signals = await invoke('cmd_list_indicator_signals', {
+        query: {
+          indicator_id: (args.indicator_id as string) || null,
+          symbol: (args.symbol as string) || null,
+          since: (args.since as string) || null,
+          until: (args.until as string) || null,
+          unacknowledged_only: (args.unacknowledged_only as boolean) ?? null,
+          limit: (args.limit as number) ?? null,
+        },
+      });
+      return JSON.stringify(signals);
+    },
+    ['tdx-indicator'],
+  );
+
+  toolRegistry.register(
+    'acknowledge_indicator_signals',
+    def('acknowledge_indicator_signals', '确认（标记已处理）指标信号：传入 ids 确认指定信号，否则确认某指标或全部未确认的信号', {
+      type: 'object',
+      properties: {
+        ids: { type: 'array', items: { type: 'string' }, description: '信号 ID 列表' },
+        indicator_id: { type: 'string', description: '确认该指标下全部未确认信号（未传 ids 时生效）' },
+      },
+    }),
+    async (args) => {
+      const result = await invoke('cmd_acknowledge_indicator_signals', {
+        ids: (args.ids as string[]) || null,
+        indicatorId: (args.indicator_id as string) || null,
+      });
       return JSON.stringify(result);
     },
     ['tdx-indicator'],
   );
 
+  toolRegistry.register(
+    'fetch_relative_strength',
+    def('fetch_relative_strength', '计算个股相对基准（指数或其他股票）的滚动贝塔、日收益相关系数和相对强弱（RS > 100 表示跑赢基准），停牌日期单独列出', {
+      type: 'object',
+      properties: {
+        symbols: { type: 'array', items: { type: 'string' }, description: '股票代码数组' },
+        benchmark: { type: 'string', description: '基准代码，指数需带市场前缀，如 sh000300（沪深300）、sh000001（上证指数）、sz399006（创业板指）' },
+        window: { type: 'number', description: '滚动窗口（K 线根数），默认 20' },
+        bars: { type: 'number', description: '获取的历史 K 线数量，默认 250' },
+        adjust_mode: { type: 'string', enum: ['forward', 'backward', 'none'], description: '复权方式，默认前复权' },
+      },
+      required: ['symbols', 'benchmark'],
+    }),
+    async (args) => {
+      const result = await invoke('cmd_relative_strength', {
+        symbols: args.symbols as string[],
+        benchmark: args.benchmark as string,
+        window: (args.window as number) ?? null,
+        bars: (args.bars as number) ?? null,
+        adjustMode: (args.adjust_mode as string) || null,
+      });
+      return JSON.stringify(result);
+    },
+    ['tdx-indicator', 'stock-analysis'],
+  );
+
   // ── 内嵌浏览器 (skill: web-browser) ──
 
   toolRegistry.register(
@@ -1,8 +1,10 @@
 import { load } from '@tauri-apps/plugin-store';
 import type { ModelConfig } from '../types/ai';
+import type { MarketDataConfig } from '../types/market';
 
 const STORE_NAME = 'settings.json';
 const MODEL_CONFIG_KEY = 'model_config';
+const MARKET_DATA_CONFIG_KEY = 'market_data_config';
 
 let storeInstance: Awaited<ReturnType<typeof load>> | null = null;
 
@@ -22,3 +24,13 @@ export async function loadModelConfig(): Promise<ModelConfig | null> {
   const store = await getStore();
   return (await store.get<ModelConfig>(MODEL_CONFIG_KEY)) ?? null;
 }
+
+export async function saveMarketDataConfig(config: MarketDataConfig): Promise<void> {
+  const store = await getStore();
+  await store.set(MARKET_DATA_CONFIG_KEY, config);
+}
+
+export async function loadMarketDataConfig(): Promise<MarketDataConfig | null> {
+  const store = await getStore();
+  return (await store.get<MarketDataConfig>(MARKET_DATA_CONFIG_KEY)) ?? null;
+}
@@ -1,14 +1,23 @@
 import { create } from 'zustand';
+import { invoke } from '@tauri-apps/api/core';
 import type { ModelConfig } from '../types/ai';
-import { saveModelConfig, loadModelConfig } from '../services/settingsStorage';
+import type { MarketDataConfig } from '../types/market';
+import {
+  saveModelConfig,
+  loadModelConfig,
+  saveMarketDataConfig,
+  loadMarketDataConfig,
+} from '../services/settingsStorage';
 
 interface SettingsState {
   modelConfig: ModelConfig;
+  marketDataConfig: MarketDataConfig;
   isSettingsOpen: boolean;
   initialized: boolean;
 
   initSettings: () => Promise<void>;
   updateModelConfig: (updates: Partial<ModelConfig>) => void;
+  updateMarketDataConfig: (updates: Partial<MarketDataConfig>) => Promise<void>;
   toggleSettings: () => void;
   setSettingsOpen: (open: boolean) => void;
 }
@@ -20,8 +29,14 @@ const DEFAULT_CONFIG: ModelConfig = {
   supportsVision: true,
 };
 
-export const useSettingsStore = create<SettingsState>((set) => ({
+const DEFAULT_MARKET_DATA_CONFIG: MarketDataConfig = {
+  provider: 'eastmoney',
+  fixture_path: null,
+};
+
+export const useSettingsStore = create<SettingsState>((set, get) => ({
   modelConfig: DEFAULT_CONFIG,
+  marketDataConfig: DEFAULT_MARKET_DATA_CONFIG,
   isSettingsOpen: false,
   initialized: false,
 
@@ -31,6 +46,12 @@ export const useSettingsStore = create<SettingsState>((set) => ({
       if (saved) {
         set({ modelConfig: saved });
       }
+      const marketData = await loadMarketDataConfig();
+      if (marketData) {
+        // 后端启动时已从数据库恢复数据源；这里同步前端保存的设置（兼容只保存在前端的旧版本）
+        await invoke('cmd_set_market_provider', { config: marketData });
+        set({ marketDataConfig: marketData });
+      }
       set({ initialized: true });
     } catch (error) {
       console.error('Failed to load settings:', error);
@@ -46,6 +67,14 @@ export const useSettingsStore = create<SettingsState>((set) => ({
     });
   },
 
+  updateMarketDataConfig: async (updates) => {
+    const marketDataConfig = { ...get().marketDataConfig, ...updates };
+    // 后端切换失败（如 fixture 文件无法读取）时保留原配置
+    await invoke('cmd_set_market_provider', { config: marketDataConfig });
+    set({ marketDataConfig });
+    await saveMarketDataConfig(marketDataConfig);
+  },
+
   toggleSettings: () => set((state) => ({ isSettingsOpen: !state.isSettingsOpen })),
 
   setSettingsOpen: (open) => set({ isSettingsOpen: open }),
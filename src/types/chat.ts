@@ -30,7 +30,14 @@ export interface Message {
 // ── Agent Plan Types ──
 
 export interface PlanCondition {
-  field: 'price' | 'change_percent' | 'volume_ratio';
+  field:
+    | 'price'
+    | 'change_percent'
+    | 'volume_ratio'
+    | 'vwap'
+    | 'vwap_deviation'
+    | 'minutes_below_vwap'
+    | 'minutes_above_vwap';
   operator: 'gt' | 'lt' | 'gte' | 'lte' | 'eq';
   value: number;
   symbol: string;
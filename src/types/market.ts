@@ -18,3 +18,12 @@ export interface WatchlistItem {
   exchange: string | null;
   added_at: string;
 }
+
+/** 行情数据源：eastmoney 为东方财富，fixture 为离线确定数据（测试用） */
+export type MarketDataProviderName = 'eastmoney' | 'fixture';
+
+export interface MarketDataConfig {
+  provider: MarketDataProviderName;
+  /** fixture 数据文件（JSON），为空时使用生成的数据 */
+  fixture_path: string | null;
+}